@@ -0,0 +1,111 @@
+use alloc::string::String;
+
+use crate::{markers::Marker, ChapterInfo};
+
+/// Reads `第X卷`, `第X话`, `X巻` and the like, numbers written with digits or
+/// with Chinese numerals, and returns the title without them.
+pub fn parse(text: &str, info: &mut ChapterInfo) -> String {
+	let chars: alloc::vec::Vec<char> = text.chars().collect();
+	let mut rest = String::new();
+	let mut index = 0;
+	while index < chars.len() {
+		let start = index;
+		let prefixed = chars[index] == '第';
+		if prefixed {
+			index += 1;
+		}
+		let number_start = index;
+		while index < chars.len() && is_number_char(chars[index]) {
+			index += 1;
+		}
+		let number: String = chars[number_start..index].iter().collect();
+		let marker = chars.get(index).copied().and_then(unit);
+		// `一回目` is "the first time", not chapter 1, and the `一章` of
+		// `统一章程` is part of a word
+		let ordinal = chars.get(index + 1) == Some(&'目');
+		let in_word = !prefixed
+			&& !number
+				.chars()
+				.any(|c| c.is_ascii_digit() || ('０'..='９').contains(&c))
+			&& start > 0
+			&& chars[start - 1].is_alphabetic();
+
+		match (marker, read_number(&number)) {
+			(Some(marker), Some(value))
+				if !ordinal && !in_word && info.set(marker, value, None) =>
+			{
+				index += 1;
+				rest.push(' ');
+			}
+			_ => {
+				// not a number with a unit, keep the text as is
+				index = start;
+				rest.push(chars[index]);
+				index += 1;
+			}
+		}
+	}
+	rest
+}
+
+fn unit(c: char) -> Option<Marker> {
+	match c {
+		'卷' | '巻' | '册' | '冊' => Some(Marker::Volume),
+		'话' | '話' | '章' | '回' | '集' => Some(Marker::Chapter),
+		'部' => Some(Marker::Part),
+		_ => None,
+	}
+}
+
+fn is_number_char(c: char) -> bool {
+	c.is_ascii_digit() || c == '.' || ('０'..='９').contains(&c) || numeral(c).is_some()
+}
+
+fn numeral(c: char) -> Option<u32> {
+	Some(match c {
+		'〇' | '零' => 0,
+		'一' => 1,
+		'二' | '两' | '兩' => 2,
+		'三' => 3,
+		'四' => 4,
+		'五' => 5,
+		'六' => 6,
+		'七' => 7,
+		'八' => 8,
+		'九' => 9,
+		'十' => 10,
+		'百' => 100,
+		'千' => 1000,
+		_ => return None,
+	})
+}
+
+fn read_number(text: &str) -> Option<f32> {
+	if text.is_empty() {
+		return None;
+	}
+	let digits: String = text
+		.chars()
+		.map(|c| match c {
+			'０'..='９' => char::from_u32(c as u32 - '０' as u32 + '0' as u32).unwrap_or(c),
+			_ => c,
+		})
+		.collect();
+	if let Ok(value) = digits.parse::<f32>() {
+		return Some(value);
+	}
+
+	// 二十三, 一百零五
+	let mut total = 0;
+	let mut digit = 0;
+	for c in text.chars() {
+		match numeral(c)? {
+			unit @ (10 | 100 | 1000) => {
+				total += if digit == 0 { 1 } else { digit } * unit;
+				digit = 0;
+			}
+			value => digit = value,
+		}
+	}
+	Some((total + digit) as f32)
+}
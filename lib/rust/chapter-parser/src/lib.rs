@@ -0,0 +1,158 @@
+//! Chapter title parsing shared by sources whose sites only give volume and
+//! chapter numbers as part of a free-form title.
+//!
+//! Titles like `Vol.2 Ch.10.5: The End`, `[Season 1] Episode 3`,
+//! `Том 1 Глава 10`, `Chương 12 - Phần 2` or `第3卷 第10话` are split into
+//! their numbers and whatever is left as the chapter title. Everything is
+//! `no_std` and never panics, a title without numbers just comes back as is.
+#![no_std]
+extern crate alloc;
+
+mod cjk;
+mod markers;
+mod number;
+
+use alloc::{string::String, vec::Vec};
+
+use markers::{Marker, EXTRAS};
+
+/// The numbers and title read from a chapter title. Numbers that aren't in
+/// the title are `-1.0`, like the fields of an Aidoku `Chapter`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChapterInfo {
+	pub volume: f32,
+	pub chapter: f32,
+	/// The last chapter of a range like `Ch. 10-12`, whose first one is
+	/// `chapter`.
+	pub chapter_end: f32,
+	pub part: f32,
+	pub title: String,
+	/// Whether the chapter is an extra, omake or special.
+	pub extra: bool,
+}
+
+impl Default for ChapterInfo {
+	fn default() -> ChapterInfo {
+		ChapterInfo {
+			volume: -1.0,
+			chapter: -1.0,
+			chapter_end: -1.0,
+			part: -1.0,
+			title: String::new(),
+			extra: false,
+		}
+	}
+}
+
+impl ChapterInfo {
+	fn set(&mut self, marker: Marker, value: f32, range_end: Option<f32>) -> bool {
+		let field = match marker {
+			Marker::Volume => &mut self.volume,
+			Marker::Chapter => &mut self.chapter,
+			Marker::Part => &mut self.part,
+		};
+		// the first number wins, later ones are part of the title
+		if *field >= 0.0 {
+			return false;
+		}
+		*field = value;
+		if let (Marker::Chapter, Some(range_end)) = (marker, range_end) {
+			self.chapter_end = range_end;
+		}
+		true
+	}
+}
+
+const BRACKETS: &[char] = &[
+	'(', ')', '[', ']', '{', '}', '【', '】', '「', '」', '（', '）',
+];
+const SEPARATORS: &[&str] = &["-", "–", "—", ":", "|", "~", "/", "·", "."];
+
+/// Parses a chapter title. Ranges like `Ch. 10-12` give their first number
+/// as the chapter and their last as `chapter_end`.
+///
+/// ```
+/// let info = chapter_parser::parse("Vol.2 Ch.10.5 - The End");
+/// assert_eq!(info.volume, 2.0);
+/// assert_eq!(info.chapter, 10.5);
+/// assert_eq!(info.title, "The End");
+///
+/// let info = chapter_parser::parse("第3卷 第十二话 标题");
+/// assert_eq!((info.volume, info.chapter), (3.0, 12.0));
+/// assert_eq!(info.title, "标题");
+/// ```
+pub fn parse(text: &str) -> ChapterInfo {
+	let mut info = ChapterInfo::default();
+	let text = cjk::parse(text, &mut info);
+
+	let tokens: Vec<&str> = text.split_whitespace().collect();
+	let mut rest: Vec<&str> = Vec::new();
+	let mut index = 0;
+	while index < tokens.len() {
+		let word = tokens[index].trim_matches(BRACKETS).to_lowercase();
+		if let Some((marker, number)) = markers::find(&word) {
+			match number {
+				Some((number, range_end)) if info.set(marker, number, range_end) => {
+					index += 1;
+					continue;
+				}
+				// `Chapter 10`, the number is the next word
+				None => {
+					let number = tokens
+						.get(index + 1)
+						.and_then(|next| number::parse(next.trim_matches(BRACKETS)));
+					if let Some((number, range_end)) = number {
+						if info.set(marker, number, range_end) {
+							index += 2;
+							continue;
+						}
+					}
+				}
+				_ => {}
+			}
+		}
+		rest.push(tokens[index]);
+		index += 1;
+	}
+
+	let lowercase = text.to_lowercase();
+	info.extra = EXTRAS.iter().any(|extra| lowercase.contains(extra));
+
+	// Titles that are just `10 - Title` or `Title 10`, unless the number is
+	// the one of an extra
+	if info.chapter < 0.0 && !info.extra {
+		let position = match (rest.first(), rest.last()) {
+			(Some(first), _) if number::parse_exact(first).is_some() => Some(0),
+			(_, Some(last)) if number::parse_exact(last).is_some() => Some(rest.len() - 1),
+			_ => None,
+		};
+		if let Some(position) = position {
+			info.chapter = number::parse_exact(rest.remove(position)).unwrap_or(-1.0);
+		}
+	}
+
+	info.title = clean_title(&rest);
+	info
+}
+
+/// Joins what's left of a title, without the separators and empty brackets
+/// the numbers were wrapped in.
+fn clean_title(words: &[&str]) -> String {
+	let is_filler = |word: &&str| {
+		let word = word.trim_matches(BRACKETS);
+		word.is_empty() || SEPARATORS.contains(&word)
+	};
+	let start = words.iter().position(|word| !is_filler(word));
+	let end = words.iter().rposition(|word| !is_filler(word));
+	match (start, end) {
+		(Some(start), Some(end)) => {
+			let title = words[start..=end].join(" ");
+			String::from(
+				title
+					.trim_start_matches(|c: char| SEPARATORS.iter().any(|s| s.starts_with(c)))
+					.trim(),
+			)
+		}
+		_ => String::new(),
+	}
+}
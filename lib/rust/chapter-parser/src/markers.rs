@@ -0,0 +1,106 @@
+use crate::number::{self, Number};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Marker {
+	Volume,
+	Chapter,
+	Part,
+}
+
+/// Words that come before a number, all lowercase. Single letters only
+/// count when the number is right after them, like `S1` or `#12`.
+pub const MARKERS: &[(&str, Marker)] = &[
+	// English
+	("volume", Marker::Volume),
+	("vol.", Marker::Volume),
+	("vol", Marker::Volume),
+	("season", Marker::Volume),
+	("chapter", Marker::Chapter),
+	("chap.", Marker::Chapter),
+	("chap", Marker::Chapter),
+	("ch.", Marker::Chapter),
+	("ch", Marker::Chapter),
+	("episode", Marker::Chapter),
+	("ep.", Marker::Chapter),
+	("ep", Marker::Chapter),
+	("part", Marker::Part),
+	("pt.", Marker::Part),
+	// Spanish
+	("volumen", Marker::Volume),
+	("tomo", Marker::Volume),
+	("temporada", Marker::Volume),
+	("capítulo", Marker::Chapter),
+	("capitulo", Marker::Chapter),
+	("cap.", Marker::Chapter),
+	("cap", Marker::Chapter),
+	("episodio", Marker::Chapter),
+	("parte", Marker::Part),
+	// French
+	("tome", Marker::Volume),
+	("saison", Marker::Volume),
+	("chapitre", Marker::Chapter),
+	("épisode", Marker::Chapter),
+	("partie", Marker::Part),
+	// Indonesian
+	("jilid", Marker::Volume),
+	("bab", Marker::Chapter),
+	("bagian", Marker::Part),
+	// Vietnamese
+	("quyển", Marker::Volume),
+	("tập", Marker::Volume),
+	("chương", Marker::Chapter),
+	("chuong", Marker::Chapter),
+	("phần", Marker::Part),
+	// Russian and Ukrainian
+	("том", Marker::Volume),
+	("т.", Marker::Volume),
+	("сезон", Marker::Volume),
+	("глава", Marker::Chapter),
+	("гл.", Marker::Chapter),
+	("розділ", Marker::Chapter),
+	("эпизод", Marker::Chapter),
+	("епізод", Marker::Chapter),
+	("часть", Marker::Part),
+	("частина", Marker::Part),
+	// Single letters, only glued to their number
+	("s", Marker::Volume),
+	("t", Marker::Volume),
+	("v", Marker::Volume),
+	("v.", Marker::Volume),
+	("c", Marker::Chapter),
+	("#", Marker::Chapter),
+];
+
+/// Words that mark a chapter as an extra.
+pub const EXTRAS: &[&str] = &[
+	"extra",
+	"omake",
+	"special",
+	"bonus",
+	"side story",
+	"especial",
+	"spécial",
+	"экстра",
+	"екстра",
+	"спешл",
+	"ngoại truyện",
+	"番外",
+	"おまけ",
+	"特别篇",
+	"特別編",
+];
+
+/// Matches a lowercase word against the markers. The number is `None` when
+/// the word is only the marker and the number should be the next word.
+pub fn find(word: &str) -> Option<(Marker, Option<Number>)> {
+	MARKERS.iter().find_map(|(name, marker)| {
+		let rest = word.strip_prefix(name)?;
+		if rest.is_empty() {
+			if name.chars().count() == 1 || *name == "v." {
+				return None;
+			}
+			return Some((*marker, None));
+		}
+		Some((*marker, Some(number::parse(rest)?)))
+	})
+}
@@ -0,0 +1,38 @@
+/// A number, and the end of its range if it starts one.
+pub type Number = (f32, Option<f32>);
+
+/// Reads the number a word starts with, e.g. `10`, `10.5` or `10,5:`, and
+/// the end of the range if the word is one, like `10-12`.
+pub fn parse(word: &str) -> Option<Number> {
+	let word = word.trim_start_matches('#');
+	let end = word
+		.char_indices()
+		.find(|(_, c)| !c.is_ascii_digit() && *c != '.' && *c != ',')
+		.map(|(index, _)| index)
+		.unwrap_or(word.len());
+	let number = word[..end].trim_end_matches(['.', ',']).replace(',', ".");
+	if !number.starts_with(|c: char| c.is_ascii_digit()) {
+		return None;
+	}
+
+	// Anything after the number has to end the word or start a range
+	let rest = word[end..].trim_end_matches([':', ')', ']', '.', ',']);
+	if !rest.is_empty() && !rest.starts_with(['-', '~', '–', '+']) {
+		return None;
+	}
+	let start = number.parse::<f32>().ok()?;
+	let range_end = rest
+		.strip_prefix(['-', '~', '–'])
+		.and_then(parse_exact)
+		.filter(|range_end| *range_end > start);
+	Some((start, range_end))
+}
+
+/// Reads a word that is nothing but a number.
+pub fn parse_exact(word: &str) -> Option<f32> {
+	let word = word.trim_matches(|c: char| !c.is_ascii_digit());
+	if word.is_empty() || !word.chars().all(|c| c.is_ascii_digit() || c == '.') {
+		return None;
+	}
+	word.parse::<f32>().ok()
+}
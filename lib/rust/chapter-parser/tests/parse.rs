@@ -0,0 +1,171 @@
+use chapter_parser::{parse, ChapterInfo};
+
+/// A title and what it should be read as: volume, chapter, part and the
+/// title that's left.
+type Case = (&'static str, f32, f32, f32, &'static str);
+
+fn check(cases: &[Case]) {
+	for (text, volume, chapter, part, title) in cases {
+		let info = parse(text);
+		assert_eq!(
+			(info.volume, info.chapter, info.part, info.title.as_str()),
+			(*volume, *chapter, *part, *title),
+			"{text}"
+		);
+	}
+}
+
+#[test]
+fn english() {
+	check(&[
+		("Chapter 10", -1.0, 10.0, -1.0, ""),
+		(
+			"Chapter 10: The Beginning",
+			-1.0,
+			10.0,
+			-1.0,
+			"The Beginning",
+		),
+		("Vol.2 Ch.10.5 - The End", 2.0, 10.5, -1.0, "The End"),
+		("Volume 3 Chapter 21", 3.0, 21.0, -1.0, ""),
+		("Ch. 7 Part 2", -1.0, 7.0, 2.0, ""),
+		("Episode 4 - Rain", -1.0, 4.0, -1.0, "Rain"),
+		("Ep.12", -1.0, 12.0, -1.0, ""),
+		("(S1) Ep. 3 - Hello", 1.0, 3.0, -1.0, "Hello"),
+		("[Season 2] Episode 1", 2.0, 1.0, -1.0, ""),
+		("#15 Homecoming", -1.0, 15.0, -1.0, "Homecoming"),
+		("12 - A New Day", -1.0, 12.0, -1.0, "A New Day"),
+		("A New Day 12", -1.0, 12.0, -1.0, "A New Day"),
+		("Chapter 10,5", -1.0, 10.5, -1.0, ""),
+	]);
+}
+
+#[test]
+fn spanish_and_portuguese() {
+	check(&[
+		("Capítulo 45: El regreso", -1.0, 45.0, -1.0, "El regreso"),
+		("Tomo 2 Capitulo 8", 2.0, 8.0, -1.0, ""),
+		("Cap. 3 - Parte 2", -1.0, 3.0, 2.0, ""),
+		("Temporada 1 Episodio 6", 1.0, 6.0, -1.0, ""),
+	]);
+}
+
+#[test]
+fn french() {
+	check(&[
+		("Chapitre 101", -1.0, 101.0, -1.0, ""),
+		("Tome 4 Chapitre 30 : La fin", 4.0, 30.0, -1.0, "La fin"),
+		("Saison 2 Épisode 5", 2.0, 5.0, -1.0, ""),
+	]);
+}
+
+#[test]
+fn indonesian() {
+	check(&[
+		("Bab 17", -1.0, 17.0, -1.0, ""),
+		("Jilid 2 Bab 9 - Pertemuan", 2.0, 9.0, -1.0, "Pertemuan"),
+		("Chapter 3 Bagian 1", -1.0, 3.0, 1.0, ""),
+	]);
+}
+
+#[test]
+fn vietnamese() {
+	check(&[
+		("Chương 12", -1.0, 12.0, -1.0, ""),
+		("Chương 12 - Phần 2", -1.0, 12.0, 2.0, ""),
+		("Tập 3 Chương 40: Trở về", 3.0, 40.0, -1.0, "Trở về"),
+		("Chuong 7", -1.0, 7.0, -1.0, ""),
+	]);
+}
+
+#[test]
+fn russian_and_ukrainian() {
+	check(&[
+		("Том 1 Глава 10", 1.0, 10.0, -1.0, ""),
+		("Глава 5 - Начало", -1.0, 5.0, -1.0, "Начало"),
+		("Т.2 Гл.15", 2.0, 15.0, -1.0, ""),
+		("Розділ 8", -1.0, 8.0, -1.0, ""),
+		("Глава 3 Часть 2", -1.0, 3.0, 2.0, ""),
+	]);
+}
+
+#[test]
+fn chinese() {
+	check(&[
+		("第3卷 第10话", 3.0, 10.0, -1.0, ""),
+		("第3卷 第十二话 标题", 3.0, 12.0, -1.0, "标题"),
+		("第一百零五話", -1.0, 105.0, -1.0, ""),
+		("第２５话", -1.0, 25.0, -1.0, ""),
+		("12话 重逢", -1.0, 12.0, -1.0, "重逢"),
+		("第二十三章 终章", -1.0, 23.0, -1.0, "终章"),
+		("第1部 第5回", -1.0, 5.0, 1.0, ""),
+		// a numeral inside a word isn't a chapter
+		("统一章程", -1.0, -1.0, -1.0, "统一章程"),
+	]);
+}
+
+#[test]
+fn japanese() {
+	check(&[
+		("3巻 第12話", 3.0, 12.0, -1.0, ""),
+		("第五話 約束", -1.0, 5.0, -1.0, "約束"),
+		// an ordinal, "the first confession", not chapter 1
+		("一回目の告白", -1.0, -1.0, -1.0, "一回目の告白"),
+		("二回目のデート", -1.0, -1.0, -1.0, "二回目のデート"),
+	]);
+}
+
+#[test]
+fn ranges() {
+	let cases = [
+		("Ch. 10-12", 10.0, 12.0),
+		("Chapter 5~6", 5.0, 6.0),
+		("Ch.20-21: Finale", 20.0, 21.0),
+		("Chapter 7-2", 7.0, -1.0),
+		("Chapter 10+", 10.0, -1.0),
+		("Chapter 10", 10.0, -1.0),
+	];
+	for (text, chapter, chapter_end) in cases {
+		let info = parse(text);
+		assert_eq!(
+			(info.chapter, info.chapter_end),
+			(chapter, chapter_end),
+			"{text}"
+		);
+	}
+}
+
+#[test]
+fn extras() {
+	let cases = [
+		("Extra Chapter", true),
+		("Side Story 2", true),
+		("番外 1", true),
+		("Omake", true),
+		("Chapter 2", false),
+	];
+	for (text, extra) in cases {
+		assert_eq!(parse(text).extra, extra, "{text}");
+	}
+	// the number of an extra isn't a chapter number
+	assert_eq!(parse("Side Story 2").chapter, -1.0);
+}
+
+#[test]
+fn without_numbers() {
+	for text in ["", "Prologue", "The Last Goodbye", "Chapter", "Vol. ?"] {
+		let info = parse(text);
+		assert_eq!(
+			(info.volume, info.chapter, info.part),
+			(-1.0, -1.0, -1.0),
+			"{text}"
+		);
+	}
+	assert_eq!(
+		parse("Prologue"),
+		ChapterInfo {
+			title: String::from("Prologue"),
+			..Default::default()
+		}
+	);
+}
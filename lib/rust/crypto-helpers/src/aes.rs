@@ -0,0 +1,74 @@
+//! Raw AES with PKCS#7 padding, picking AES-128/192/256 from the key length.
+use crate::{
+	encoding::{base64_decode, hex_decode},
+	Error, Result,
+};
+use aes::{
+	cipher::{block_padding::Pkcs7, BlockCipher, BlockDecryptMut, KeyInit, KeyIvInit},
+	Aes128, Aes192, Aes256,
+};
+use alloc::vec::Vec;
+
+/// Decrypts AES-CBC `data` with PKCS#7 padding.
+pub fn cbc_decrypt(key: &[u8], iv: &[u8], data: &[u8]) -> Result<Vec<u8>> {
+	if iv.len() != 16 {
+		return Err(Error::InvalidIvLength);
+	}
+
+	match key.len() {
+		16 => cbc_decrypt_with::<Aes128>(key, iv, data),
+		24 => cbc_decrypt_with::<Aes192>(key, iv, data),
+		32 => cbc_decrypt_with::<Aes256>(key, iv, data),
+		_ => Err(Error::InvalidKeyLength),
+	}
+}
+
+/// [`cbc_decrypt`] for a hex-encoded ciphertext.
+pub fn cbc_decrypt_hex(key: &[u8], iv: &[u8], data: &str) -> Result<Vec<u8>> {
+	cbc_decrypt(key, iv, &hex_decode(data)?)
+}
+
+/// [`cbc_decrypt`] for a base64-encoded ciphertext.
+pub fn cbc_decrypt_base64(key: &[u8], iv: &[u8], data: &str) -> Result<Vec<u8>> {
+	cbc_decrypt(key, iv, &base64_decode(data)?)
+}
+
+/// Decrypts AES-ECB `data` with PKCS#7 padding.
+pub fn ecb_decrypt(key: &[u8], data: &[u8]) -> Result<Vec<u8>> {
+	match key.len() {
+		16 => ecb_decrypt_with::<Aes128>(key, data),
+		24 => ecb_decrypt_with::<Aes192>(key, data),
+		32 => ecb_decrypt_with::<Aes256>(key, data),
+		_ => Err(Error::InvalidKeyLength),
+	}
+}
+
+/// [`ecb_decrypt`] for a hex-encoded ciphertext.
+pub fn ecb_decrypt_hex(key: &[u8], data: &str) -> Result<Vec<u8>> {
+	ecb_decrypt(key, &hex_decode(data)?)
+}
+
+/// [`ecb_decrypt`] for a base64-encoded ciphertext.
+pub fn ecb_decrypt_base64(key: &[u8], data: &str) -> Result<Vec<u8>> {
+	ecb_decrypt(key, &base64_decode(data)?)
+}
+
+fn cbc_decrypt_with<C>(key: &[u8], iv: &[u8], data: &[u8]) -> Result<Vec<u8>>
+where
+	C: BlockCipher + BlockDecryptMut + KeyInit,
+{
+	cbc::Decryptor::<C>::new_from_slices(key, iv)
+		.map_err(|_| Error::InvalidKeyLength)?
+		.decrypt_padded_vec_mut::<Pkcs7>(data)
+		.map_err(|_| Error::Unpad)
+}
+
+fn ecb_decrypt_with<C>(key: &[u8], data: &[u8]) -> Result<Vec<u8>>
+where
+	C: BlockCipher + BlockDecryptMut + KeyInit,
+{
+	ecb::Decryptor::<C>::new_from_slice(key)
+		.map_err(|_| Error::InvalidKeyLength)?
+		.decrypt_padded_vec_mut::<Pkcs7>(data)
+		.map_err(|_| Error::Unpad)
+}
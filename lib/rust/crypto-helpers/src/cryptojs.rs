@@ -0,0 +1,66 @@
+//! Compatibility with `CryptoJS.AES.decrypt(ciphertext, passphrase)`.
+//!
+//! When CryptoJS is given a passphrase instead of a key, it emits OpenSSL's
+//! `Salted__{salt}{ciphertext}` format and derives the AES-256 key and IV with
+//! `EVP_BytesToKey` (MD5, one iteration).
+use crate::{aes::cbc_decrypt, encoding::base64_decode, Result};
+use alloc::vec::Vec;
+use md5::{Digest, Md5};
+
+const SALT_HEADER: &[u8] = b"Salted__";
+
+/// OpenSSL's `EVP_BytesToKey`, filling `output` with key material derived
+/// from `password` and `salt`.
+pub fn evpkdf(password: &[u8], salt: &[u8], iterations: usize, output: &mut [u8]) {
+	let mut hasher = Md5::new();
+	let mut derived = Vec::with_capacity(output.len() + 16);
+	let mut block = Vec::new();
+
+	while derived.len() < output.len() {
+		if !block.is_empty() {
+			hasher.update(&block);
+		}
+		hasher.update(password);
+		hasher.update(salt);
+		block = hasher.finalize_reset().to_vec();
+
+		for _ in 1..iterations {
+			hasher.update(&block);
+			block = hasher.finalize_reset().to_vec();
+		}
+
+		derived.extend_from_slice(&block);
+	}
+
+	output.copy_from_slice(&derived[..output.len()]);
+}
+
+/// Decrypts raw CryptoJS output with a passphrase.
+///
+/// Unsalted messages are derived with an empty salt, which is what sites that
+/// strip the OpenSSL header rely on.
+pub fn decrypt(message: &[u8], passphrase: &[u8]) -> Result<Vec<u8>> {
+	let (salt, ciphertext) = match message.strip_prefix(SALT_HEADER) {
+		Some(rest) if rest.len() >= 8 => rest.split_at(8),
+		_ => (&[][..], message),
+	};
+	decrypt_with_salt(ciphertext, salt, passphrase)
+}
+
+/// Decrypts a ciphertext whose salt was sent separately, like the
+/// `{"ct": …, "iv": …, "s": …}` JSON that some sites format CryptoJS output
+/// as (base64 `ct`, hex `s`). The IV is derived from the passphrase again, so
+/// `iv` isn't needed.
+pub fn decrypt_with_salt(ciphertext: &[u8], salt: &[u8], passphrase: &[u8]) -> Result<Vec<u8>> {
+	let mut key_iv = [0; 48];
+	evpkdf(passphrase, salt, 1, &mut key_iv);
+	let (key, iv) = key_iv.split_at(32);
+
+	cbc_decrypt(key, iv, ciphertext)
+}
+
+/// [`decrypt`] for the base64 string `CryptoJS.AES.encrypt(...).toString()`
+/// produces.
+pub fn decrypt_base64(message: &str, passphrase: &[u8]) -> Result<Vec<u8>> {
+	decrypt(&base64_decode(message)?, passphrase)
+}
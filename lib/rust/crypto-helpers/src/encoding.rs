@@ -0,0 +1,62 @@
+use crate::{Error, Result};
+use alloc::{string::String, vec::Vec};
+use base64::{
+	alphabet,
+	engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig},
+	Engine,
+};
+
+/// Accepts input with or without trailing `=`, which sites are inconsistent
+/// about.
+const STANDARD: GeneralPurpose = GeneralPurpose::new(
+	&alphabet::STANDARD,
+	GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+const URL_SAFE: GeneralPurpose = GeneralPurpose::new(
+	&alphabet::URL_SAFE,
+	GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+/// Decodes standard base64, ignoring any whitespace and missing padding.
+pub fn base64_decode<T: AsRef<[u8]>>(input: T) -> Result<Vec<u8>> {
+	STANDARD
+		.decode(strip_whitespace(input.as_ref()))
+		.map_err(|_| Error::Base64)
+}
+
+/// Decodes URL-safe base64 (`-` and `_`), ignoring any whitespace and missing
+/// padding.
+pub fn base64_url_decode<T: AsRef<[u8]>>(input: T) -> Result<Vec<u8>> {
+	URL_SAFE
+		.decode(strip_whitespace(input.as_ref()))
+		.map_err(|_| Error::Base64)
+}
+
+/// Encodes to padded standard base64.
+pub fn base64_encode<T: AsRef<[u8]>>(input: T) -> String {
+	base64::engine::general_purpose::STANDARD.encode(input)
+}
+
+/// Decodes a hex string, ignoring case.
+pub fn hex_decode<T: AsRef<[u8]>>(input: T) -> Result<Vec<u8>> {
+	hex::decode(input).map_err(|_| Error::Hex)
+}
+
+/// Encodes to a lowercase hex string.
+pub fn hex_encode<T: AsRef<[u8]>>(input: T) -> String {
+	hex::encode(input)
+}
+
+/// Converts decrypted bytes into a `String`.
+pub fn to_utf8(bytes: Vec<u8>) -> Result<String> {
+	String::from_utf8(bytes).map_err(|_| Error::Utf8)
+}
+
+fn strip_whitespace(input: &[u8]) -> Vec<u8> {
+	input
+		.iter()
+		.copied()
+		.filter(|byte| !byte.is_ascii_whitespace())
+		.collect()
+}
@@ -0,0 +1,47 @@
+use core::fmt::Display;
+
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+	/// The key is not 16, 24 or 32 bytes long.
+	InvalidKeyLength,
+
+	/// The IV is not 16 bytes long.
+	InvalidIvLength,
+
+	/// The plaintext does not end with valid PKCS#7 padding, which usually
+	/// means the key or IV is wrong.
+	Unpad,
+
+	/// The input is not valid base64.
+	Base64,
+
+	/// The input is not valid hexadecimal.
+	Hex,
+
+	/// The RSA private key could not be parsed.
+	InvalidPrivateKey,
+
+	/// RSA decryption of a block failed.
+	Rsa,
+
+	/// The plaintext is not valid UTF-8.
+	Utf8,
+}
+
+impl Display for Error {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		let message = match self {
+			Self::InvalidKeyLength => "invalid AES key length",
+			Self::InvalidIvLength => "invalid AES IV length",
+			Self::Unpad => "invalid padding, the key is probably wrong",
+			Self::Base64 => "invalid base64",
+			Self::Hex => "invalid hex",
+			Self::InvalidPrivateKey => "invalid RSA private key",
+			Self::Rsa => "RSA decryption failed",
+			Self::Utf8 => "plaintext is not valid UTF-8",
+		};
+		write!(f, "{}", message)
+	}
+}
@@ -0,0 +1,15 @@
+//! Crypto helpers shared by sources whose sites obfuscate their data.
+//!
+//! Everything here is `no_std`, allocates through `alloc`, and reports
+//! failures through [`Error`] instead of panicking, so that sources can turn
+//! a rotated key or a malformed payload into a regular Aidoku error.
+#![no_std]
+extern crate alloc;
+
+pub mod aes;
+pub mod cryptojs;
+pub mod encoding;
+mod error;
+pub mod rsa;
+
+pub use error::{Error, Result};
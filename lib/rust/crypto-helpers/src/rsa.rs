@@ -0,0 +1,30 @@
+//! RSA PKCS#1 v1.5 decryption for payloads longer than a single block.
+use crate::{encoding::base64_decode, Error, Result};
+use alloc::vec::Vec;
+use rsa::{pkcs8::DecodePrivateKey, PaddingScheme, PublicKeyParts};
+
+pub use rsa::RsaPrivateKey;
+
+/// Parses a base64-encoded PKCS#8 DER private key, i.e. a PEM body without
+/// the header and footer lines.
+pub fn private_key_from_base64(key: &str) -> Result<RsaPrivateKey> {
+	RsaPrivateKey::from_pkcs8_der(&base64_decode(key)?).map_err(|_| Error::InvalidPrivateKey)
+}
+
+/// Decrypts `data` block by block, where each block is as long as the key's
+/// modulus, and concatenates the plaintexts.
+pub fn decrypt_chunked(key: &RsaPrivateKey, data: &[u8]) -> Result<Vec<u8>> {
+	let mut plaintext = Vec::with_capacity(data.len());
+	for block in data.chunks(key.size()) {
+		let decrypted = key
+			.decrypt(PaddingScheme::PKCS1v15Encrypt, block)
+			.map_err(|_| Error::Rsa)?;
+		plaintext.extend_from_slice(&decrypted);
+	}
+	Ok(plaintext)
+}
+
+/// [`decrypt_chunked`] for base64-encoded ciphertext.
+pub fn decrypt_chunked_base64(key: &RsaPrivateKey, data: &str) -> Result<Vec<u8>> {
+	decrypt_chunked(key, &base64_decode(data)?)
+}
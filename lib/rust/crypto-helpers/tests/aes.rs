@@ -0,0 +1,52 @@
+//! CopyManga's encrypted `results`, which are AES-128-CBC with the site's key,
+//! sent as a 16-character IV followed by the hex ciphertext.
+//!
+//! `COPYMANGA_RESULTS` isn't a captured response: it's a made-up page list
+//! encrypted in that format with the key the source uses, and it decrypts the
+//! same with OpenSSL:
+//!
+//! ```sh
+//! echo -n "${RESULTS:16}" | xxd -r -p | openssl enc -d -aes-128-cbc \
+//!     -K "$(echo -n xxxmanga.woo.key | xxd -p)" -iv "$(echo -n "${RESULTS:0:16}" | xxd -p)"
+//! ```
+use crypto_helpers::{
+	aes::{cbc_decrypt_hex, ecb_decrypt_hex},
+	encoding::to_utf8,
+	Error,
+};
+
+const COPYMANGA_KEY: &str = "xxxmanga.woo.key";
+const COPYMANGA_RESULTS: &str = "a1b2c3d4e5f6a7b838817dd39e0253a43d9688cfe45c7c5ba724f66bdbc569dcc881fa13f1556083a3d96c536d981760137aab5dae5c4650c159447cdd2e540e61ce623cb04f299381c623c49b2e7a9cbd77592b73ea04a31869bd6fc13445083f8c2dfd199b4ddc804aacf1e2cfa369ad55a540f7954efa127dacbcc122f50fe0b193f741f520c3";
+const COPYMANGA_PLAINTEXT: &str = r#"[{"url":"https://example.com/comic/1/01.jpg.c800x.webp"},{"url":"https://example.com/comic/1/02.jpg.c800x.webp"}]"#;
+
+#[test]
+fn decrypts_copymanga_results() {
+	let (iv, ciphertext) = COPYMANGA_RESULTS.split_at(16);
+	let plaintext =
+		cbc_decrypt_hex(COPYMANGA_KEY.as_bytes(), iv.as_bytes(), ciphertext).and_then(to_utf8);
+	assert_eq!(plaintext.as_deref(), Ok(COPYMANGA_PLAINTEXT));
+}
+
+#[test]
+fn rejects_copymanga_results_with_old_key() {
+	let (iv, ciphertext) = COPYMANGA_RESULTS.split_at(16);
+	let plaintext = cbc_decrypt_hex(b"oooomanga.key.01", iv.as_bytes(), ciphertext);
+	assert!(plaintext.is_err());
+}
+
+#[test]
+fn rejects_bad_lengths() {
+	let (iv, ciphertext) = COPYMANGA_RESULTS.split_at(16);
+	assert_eq!(
+		cbc_decrypt_hex(b"short", iv.as_bytes(), ciphertext),
+		Err(Error::InvalidKeyLength)
+	);
+	assert_eq!(
+		cbc_decrypt_hex(COPYMANGA_KEY.as_bytes(), b"short", ciphertext),
+		Err(Error::InvalidIvLength)
+	);
+	assert_eq!(
+		ecb_decrypt_hex(COPYMANGA_KEY.as_bytes(), "not hex"),
+		Err(Error::Hex)
+	);
+}
@@ -0,0 +1,43 @@
+//! DMZJ's v4 API responses, which are protobuf messages encrypted block by
+//! block with the public half of the key the app ships with.
+//!
+//! `DMZJ_KEY` is the key `zh.dmzj` uses, but `DMZJ_RESPONSE` isn't a captured
+//! response: it's a made-up `ComicDetailResponse` encrypted with that key.
+//! Each 128-byte block decrypts the same with
+//! `openssl pkeyutl -decrypt -inkey key.pem -pkeyopt rsa_padding_mode:pkcs1`.
+use crypto_helpers::{
+	encoding::{base64_decode, hex_encode},
+	rsa::{decrypt_chunked, decrypt_chunked_base64, private_key_from_base64},
+	Error,
+};
+
+const DMZJ_KEY: &str = "MIICeAIBADANBgkqhkiG9w0BAQEFAASCAmIwggJeAgEAAoGBAK8nNR1lTnIfIes6oRWJNj3mB6OssDGx0uGMpgpbVCpf6+VwnuI2stmhZNoQcM417Iz7WqlPzbUmu9R4dEKmLGEEqOhOdVaeh9Xk2IPPjqIu5TbkLZRxkY3dJM1htbz57d/roesJLkZXqssfG5EJauNc+RcABTfLb4IiFjSMlTsnAgMBAAECgYEAiz/pi2hKOJKlvcTL4jpHJGjn8+lL3wZX+LeAHkXDoTjHa47g0knYYQteCbv+YwMeAGupBWiLy5RyyhXFoGNKbbnvftMYK56hH+iqxjtDLnjSDKWnhcB7089sNKaEM9Ilil6uxWMrMMBH9v2PLdYsqMBHqPutKu/SigeGPeiB7VECQQDizVlNv67go99QAIv2n/ga4e0wLizVuaNBXE88AdOnaZ0LOTeniVEqvPtgUk63zbjl0P/pzQzyjitwe6HoCAIpAkEAxbOtnCm1uKEp5HsNaXEJTwE7WQf7PrLD4+BpGtNKkgja6f6F4ld4QZ2TQ6qvsCizSGJrjOpNdjVGJ7bgYMcczwJBALvJWPLmDi7ToFfGTB0EsNHZVKE66kZ/8Stx+ezueke4S556XplqOflQBjbnj2PigwBN/0afT+QZUOBOjWzoDJkCQClzo+oDQMvGVs9GEajS/32mJ3hiWQZrWvEzgzYRqSf3XVcEe7PaXSd8z3y3lACeeACsShqQoc8wGlaHXIJOHTcCQQCZw5127ZGs8ZDTSrogrH73Kw/HvX55wGAeirKYcv28eauveCG7iyFR0PFB/P/EDZnyb+ifvyEFlucPUI0+Y87F";
+
+/// A made-up `ComicDetailResponse`, long enough to take two blocks.
+const DMZJ_RESPONSE: &str = "Nf+WAzlpyPtTliQlFp5FfmB3APFd7GqopMl2AjBkcfwGkWPjK3x0sy1gnbqaUQbRjEn9eR2HAvL56rSoHKetXBA7sxDqmcOx9+7CUE1VayouLmUDByWZPPiDKuc6pwuRWd037/66UjeAJrWIype2HYTMLqzDaTl9TTEL+ZJPjPKF0YDIm+7Eqs/SFPbI94J4y/LQ0/eZQIYXtKIXZZW0XC3oDZQIVNks4MIIupi4/zP+9YWcRfc1M95hWDRXimM2LKmC2OGob6WXnFgJ9GWXkQbTRrtrrwun5oSjhnV4mZE1z4elCHYq0Y9tk7N23ixFrN0G1Zy3NTzPRULh48qYFg==";
+const DMZJ_PROTOBUF: &str = "08001a9e0108b960120ce6b58be8af95e6bcabe794bb1800322368747470733a2f2f6578616d706c652e636f6d2f636f7665722f31323334352e6a70673a5e4120636f6d6963207573656420746f20636865636b207468617420726573706f6e736573206c6f6e676572207468616e206f6e652052534120626c6f636b20636f6d65206f75742077686f6c652c20626c6f636b20627920626c6f636b2e4080e2cfaa06";
+
+#[test]
+fn decrypts_dmzj_response() {
+	let key = private_key_from_base64(DMZJ_KEY).unwrap();
+	let plaintext = decrypt_chunked_base64(&key, DMZJ_RESPONSE).map(hex_encode);
+	assert_eq!(plaintext.as_deref(), Ok(DMZJ_PROTOBUF));
+}
+
+#[test]
+fn rejects_truncated_dmzj_response() {
+	let key = private_key_from_base64(DMZJ_KEY).unwrap();
+	let response = base64_decode(DMZJ_RESPONSE).unwrap();
+	assert_eq!(
+		decrypt_chunked(&key, &response[..response.len() - 1]),
+		Err(Error::Rsa)
+	);
+}
+
+#[test]
+fn rejects_bad_key() {
+	assert_eq!(
+		private_key_from_base64("AAAA").err(),
+		Some(Error::InvalidPrivateKey)
+	);
+}
@@ -0,0 +1,135 @@
+//! Page filtering shared by templates whose readers are a plain list of
+//! `<img>` tags, which often includes tracking pixels, ad and Discord
+//! banners, and the scanlators' credit or recruitment pages.
+//!
+//! Blocklists are regular expressions matched against the page URLs. Only
+//! the subset of regular expressions in [`regex`] is supported, since the
+//! `regex` crate needs `std`.
+#![no_std]
+extern crate alloc;
+
+pub mod regex;
+
+use alloc::{string::String, vec::Vec};
+
+pub use regex::Regex;
+
+/// Images no reader should show: inline placeholders, tracking pixels and
+/// ad networks.
+pub const DEFAULT_BLOCKLIST: &[&str] = &[
+	r"^data:",
+	r"/(1x1|pixel|spacer|blank)\.(gif|png)",
+	r"(doubleclick|googlesyndication|googleadservices)\.",
+	r"/(ads?|banners?)/",
+];
+
+/// Credit, recruitment and donation pages, matched against file names only
+/// so that chapter paths don't trip them.
+pub const CREDIT_PAGES: &[&str] = &[
+	r"credits?",
+	r"recruit",
+	r"discord",
+	r"join[-_ ]?us",
+	r"patreon",
+	r"ko-?fi",
+	r"donat(e|ion)",
+	r"support[-_ ]?us",
+];
+
+pub struct PageFilter {
+	blocklist: Vec<Regex>,
+	credits: Vec<Regex>,
+	drop_credits: bool,
+}
+
+impl Default for PageFilter {
+	fn default() -> PageFilter {
+		PageFilter::new()
+	}
+}
+
+impl PageFilter {
+	/// A filter with the [`DEFAULT_BLOCKLIST`].
+	pub fn new() -> PageFilter {
+		PageFilter {
+			blocklist: Vec::new(),
+			credits: compile(CREDIT_PAGES),
+			drop_credits: false,
+		}
+		.block(DEFAULT_BLOCKLIST)
+	}
+
+	/// A source's filter: the shared blocklist and the source's own, dropping
+	/// credit pages if the user's `dropCreditPages` setting says so, or if
+	/// the source does so by default when it has no such setting.
+	pub fn for_source(
+		blocklist: &[&str],
+		drop_credit_pages: bool,
+		setting: Option<bool>,
+	) -> PageFilter {
+		PageFilter::new()
+			.block(blocklist)
+			.drop_credits(setting.unwrap_or(drop_credit_pages))
+	}
+
+	/// Adds patterns to the blocklist. Invalid patterns are skipped.
+	pub fn block(mut self, patterns: &[&str]) -> PageFilter {
+		self.blocklist.extend(compile(patterns));
+		self
+	}
+
+	/// Whether credit and recruitment pages are dropped too.
+	pub fn drop_credits(mut self, drop_credits: bool) -> PageFilter {
+		self.drop_credits = drop_credits;
+		self
+	}
+
+	pub fn is_blocked(&self, url: &str) -> bool {
+		let url = url.trim().to_lowercase();
+		if url.is_empty() || self.blocklist.iter().any(|regex| regex.is_match(&url)) {
+			return true;
+		}
+		if self.drop_credits {
+			let file_name = url
+				.split(['?', '#'])
+				.next()
+				.and_then(|path| path.rsplit('/').next())
+				.unwrap_or_default();
+			return self.credits.iter().any(|regex| regex.is_match(file_name));
+		}
+		false
+	}
+
+	/// Keeps the URLs that aren't blocked, in order.
+	///
+	/// ```
+	/// use page_filter::PageFilter;
+	///
+	/// let filter = PageFilter::new().block(&[r"\.webp$"]).drop_credits(true);
+	/// let pages = filter.filter([
+	///     "https://example.com/recruit-me/01.jpg",
+	///     "https://example.com/recruit-me/02_credits.png",
+	///     "https://example.com/recruit-me/03.webp",
+	///     "https://example.com/ads/banner.jpg",
+	///     "data:image/gif;base64,R0lGOD",
+	/// ]);
+	/// assert_eq!(pages, ["https://example.com/recruit-me/01.jpg"]);
+	/// ```
+	pub fn filter<I, S>(&self, urls: I) -> Vec<String>
+	where
+		I: IntoIterator<Item = S>,
+		S: Into<String>,
+	{
+		urls.into_iter()
+			.map(Into::into)
+			.filter(|url| !self.is_blocked(url))
+			.collect()
+	}
+}
+
+fn compile(patterns: &[&str]) -> Vec<Regex> {
+	patterns
+		.iter()
+		.filter_map(|pattern| Regex::new(pattern))
+		.collect()
+}
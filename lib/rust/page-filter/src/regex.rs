@@ -0,0 +1,274 @@
+//! A small backtracking regular expression matcher.
+//!
+//! Supported: literals, `.`, classes like `[a-z_]` and `[^/]`, the escapes
+//! `\d`, `\w`, `\s`, their negations `\D`, `\W` and `\S` (outside of
+//! classes), `\t`, `\n`, `\r` and escaped punctuation, groups with `|`, the
+//! anchors `^` and `$`, and the quantifiers `*`, `+`, `?`, `{n}`, `{n,}` and
+//! `{n,m}`. Any other escape, such as `\b`, makes the pattern invalid rather
+//! than matching something else. Matching ignores case and finds the pattern
+//! anywhere in the text unless it's anchored.
+
+use alloc::{boxed::Box, string::String, vec::Vec};
+
+#[derive(Debug)]
+enum Node {
+	Char(char),
+	Any,
+	Class(Vec<(char, char)>, bool),
+	Start,
+	End,
+	Group(Vec<Vec<Item>>),
+}
+
+#[derive(Debug)]
+struct Item {
+	node: Node,
+	min: usize,
+	max: usize,
+}
+
+#[derive(Debug)]
+pub struct Regex {
+	alternatives: Vec<Vec<Item>>,
+}
+
+impl Regex {
+	/// Compiles a pattern, or returns `None` if it isn't valid.
+	pub fn new(pattern: &str) -> Option<Regex> {
+		let pattern: Vec<char> = pattern.chars().collect();
+		let mut parser = Parser {
+			chars: &pattern,
+			pos: 0,
+		};
+		let alternatives = parser.alternatives()?;
+		if parser.pos != pattern.len() {
+			return None;
+		}
+		Some(Regex { alternatives })
+	}
+
+	pub fn is_match(&self, text: &str) -> bool {
+		let text: Vec<char> = text.to_lowercase().chars().collect();
+		let matcher = Matcher { text: &text };
+		(0..=text.len()).any(|start| {
+			self.alternatives
+				.iter()
+				.any(|sequence| matcher.sequence(sequence, start, &|_| true))
+		})
+	}
+}
+
+struct Parser<'a> {
+	chars: &'a [char],
+	pos: usize,
+}
+
+impl<'a> Parser<'a> {
+	fn peek(&self) -> Option<char> {
+		self.chars.get(self.pos).copied()
+	}
+
+	fn next(&mut self) -> Option<char> {
+		let c = self.peek()?;
+		self.pos += 1;
+		Some(c)
+	}
+
+	fn alternatives(&mut self) -> Option<Vec<Vec<Item>>> {
+		let mut alternatives = Vec::from([self.sequence()?]);
+		while self.peek() == Some('|') {
+			self.pos += 1;
+			alternatives.push(self.sequence()?);
+		}
+		Some(alternatives)
+	}
+
+	fn sequence(&mut self) -> Option<Vec<Item>> {
+		let mut items = Vec::new();
+		while let Some(c) = self.peek() {
+			if c == '|' || c == ')' {
+				break;
+			}
+			let node = self.atom()?;
+			let (min, max) = self.quantifier()?;
+			items.push(Item { node, min, max });
+		}
+		Some(items)
+	}
+
+	fn atom(&mut self) -> Option<Node> {
+		Some(match self.next()? {
+			'(' => {
+				// non-capturing groups are the same thing here
+				if self.chars[self.pos..].starts_with(&['?', ':']) {
+					self.pos += 2;
+				}
+				let alternatives = self.alternatives()?;
+				if self.next()? != ')' {
+					return None;
+				}
+				Node::Group(alternatives)
+			}
+			'[' => self.class()?,
+			'.' => Node::Any,
+			'^' => Node::Start,
+			'$' => Node::End,
+			'\\' => escape(self.next()?)?,
+			'*' | '+' | '?' | '{' => return None,
+			c => Node::Char(lowercase(c)),
+		})
+	}
+
+	fn class(&mut self) -> Option<Node> {
+		let negated = self.peek() == Some('^');
+		if negated {
+			self.pos += 1;
+		}
+		let mut ranges = Vec::new();
+		let mut first = true;
+		loop {
+			let c = self.next()?;
+			if c == ']' && !first {
+				break;
+			}
+			first = false;
+			let start = if c == '\\' {
+				match escape(self.next()?)? {
+					Node::Char(c) => c,
+					Node::Class(class, false) => {
+						ranges.extend(class);
+						continue;
+					}
+					// a negated class can't be merged into this one
+					_ => return None,
+				}
+			} else {
+				lowercase(c)
+			};
+			if self.peek() == Some('-') && self.chars.get(self.pos + 1) != Some(&']') {
+				self.pos += 1;
+				let end = lowercase(self.next()?);
+				if end < start {
+					return None;
+				}
+				ranges.push((start, end));
+			} else {
+				ranges.push((start, start));
+			}
+		}
+		Some(Node::Class(ranges, negated))
+	}
+
+	fn quantifier(&mut self) -> Option<(usize, usize)> {
+		Some(match self.peek() {
+			Some('*') => {
+				self.pos += 1;
+				(0, usize::MAX)
+			}
+			Some('+') => {
+				self.pos += 1;
+				(1, usize::MAX)
+			}
+			Some('?') => {
+				self.pos += 1;
+				(0, 1)
+			}
+			Some('{') => {
+				self.pos += 1;
+				let min = self.number()?;
+				let max = match self.next()? {
+					'}' => return Some((min, min)),
+					',' if self.peek() == Some('}') => usize::MAX,
+					',' => self.number()?,
+					_ => return None,
+				};
+				if self.next()? != '}' || max < min {
+					return None;
+				}
+				(min, max)
+			}
+			_ => (1, 1),
+		})
+	}
+
+	fn number(&mut self) -> Option<usize> {
+		let start = self.pos;
+		while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+			self.pos += 1;
+		}
+		self.chars[start..self.pos]
+			.iter()
+			.collect::<String>()
+			.parse()
+			.ok()
+	}
+}
+
+fn escape(c: char) -> Option<Node> {
+	let class = match c.to_ascii_lowercase() {
+		'd' => Vec::from([('0', '9')]),
+		'w' => Vec::from([('a', 'z'), ('0', '9'), ('_', '_')]),
+		's' => Vec::from([(' ', ' '), ('\t', '\t'), ('\n', '\n'), ('\r', '\r')]),
+		_ => {
+			return match c {
+				't' => Some(Node::Char('\t')),
+				'n' => Some(Node::Char('\n')),
+				'r' => Some(Node::Char('\r')),
+				c if c.is_ascii_punctuation() => Some(Node::Char(c)),
+				_ => None,
+			}
+		}
+	};
+	Some(Node::Class(class, c.is_ascii_uppercase()))
+}
+
+/// Patterns are lowercased as they're parsed rather than up front, so that
+/// escapes keep their case.
+fn lowercase(c: char) -> char {
+	c.to_lowercase().next().unwrap_or(c)
+}
+
+struct Matcher<'a> {
+	text: &'a [char],
+}
+
+impl<'a> Matcher<'a> {
+	fn sequence(&self, items: &[Item], pos: usize, then: &dyn Fn(usize) -> bool) -> bool {
+		match items.split_first() {
+			None => then(pos),
+			Some((item, rest)) => self.item(item, 0, pos, &|pos| self.sequence(rest, pos, then)),
+		}
+	}
+
+	/// Greedily matches one more repetition of `item` before handing over
+	/// to `then`, backtracking to fewer when the rest doesn't match.
+	fn item(&self, item: &Item, count: usize, pos: usize, then: &dyn Fn(usize) -> bool) -> bool {
+		let more: Box<dyn Fn(usize) -> bool> = Box::new(|next| {
+			// an empty repetition would never end
+			(next != pos || count < item.min) && self.item(item, count + 1, next, then)
+		});
+		(count < item.max && self.node(&item.node, pos, &*more)) || (count >= item.min && then(pos))
+	}
+
+	fn node(&self, node: &Node, pos: usize, then: &dyn Fn(usize) -> bool) -> bool {
+		let current = self.text.get(pos).copied();
+		match node {
+			Node::Char(c) => current == Some(*c) && then(pos + 1),
+			Node::Any => current.is_some() && then(pos + 1),
+			Node::Class(ranges, negated) => match current {
+				Some(c) => {
+					let found = ranges
+						.iter()
+						.any(|(start, end)| (*start..=*end).contains(&c));
+					found != *negated && then(pos + 1)
+				}
+				None => false,
+			},
+			Node::Start => pos == 0 && then(pos),
+			Node::End => pos == self.text.len() && then(pos),
+			Node::Group(alternatives) => alternatives
+				.iter()
+				.any(|sequence| self.sequence(sequence, pos, then)),
+		}
+	}
+}
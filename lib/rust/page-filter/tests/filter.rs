@@ -0,0 +1,91 @@
+use page_filter::PageFilter;
+
+#[test]
+fn default_blocklist() {
+	let filter = PageFilter::new();
+	let cases = [
+		("https://example.com/chapter-1/01.jpg", false),
+		("data:image/svg+xml;base64,PHN2Zw==", true),
+		("https://example.com/wp-content/1x1.gif", true),
+		("https://example.com/images/pixel.png", true),
+		("https://pagead2.googlesyndication.com/a.jpg", true),
+		("https://example.com/ads/top.jpg", true),
+		("https://example.com/uploads/adsorption.jpg", false),
+		("", true),
+		("   ", true),
+	];
+	for (url, blocked) in cases {
+		assert_eq!(filter.is_blocked(url), blocked, "{url}");
+	}
+}
+
+#[test]
+fn source_blocklist() {
+	let filter = PageFilter::new().block(&[r"/end[-_]?page\.", r"\.webp$", "(invalid"]);
+	let cases = [
+		("https://example.com/c1/END-PAGE.jpg", true),
+		("https://example.com/c1/endpage.png", true),
+		("https://example.com/c1/01.webp", true),
+		("https://example.com/c1/01.jpg", false),
+	];
+	for (url, blocked) in cases {
+		assert_eq!(filter.is_blocked(url), blocked, "{url}");
+	}
+}
+
+#[test]
+fn credit_pages_by_file_name() {
+	let filter = PageFilter::new().drop_credits(true);
+	let cases = [
+		("https://example.com/c1/99_credits.png", true),
+		("https://example.com/c1/Recruitment.jpg", true),
+		("https://example.com/c1/join-us.jpg", true),
+		("https://example.com/c1/discord.png?v=3", true),
+		("https://example.com/c1/ko-fi.png#top", true),
+		// only the file name counts, not the folders or the query
+		("https://example.com/credits/c1/01.jpg", false),
+		("https://discord-scans.com/c1/01.jpg", false),
+		("https://example.com/c1/01.jpg?from=patreon", false),
+		("https://example.com/c1/01.jpg", false),
+	];
+	for (url, blocked) in cases {
+		assert_eq!(filter.is_blocked(url), blocked, "{url}");
+	}
+
+	// and they're kept unless asked for
+	assert!(!PageFilter::new().is_blocked("https://example.com/c1/99_credits.png"));
+}
+
+#[test]
+fn setting_overrides_source_default() {
+	let credits = "https://example.com/c1/credits.png";
+	let cases = [
+		(false, None, false),
+		(true, None, true),
+		(true, Some(false), false),
+		(false, Some(true), true),
+	];
+	for (default, setting, blocked) in cases {
+		let filter = PageFilter::for_source(&[], default, setting);
+		assert_eq!(filter.is_blocked(credits), blocked, "{default} {setting:?}");
+	}
+}
+
+#[test]
+fn keeps_order() {
+	let pages = PageFilter::new().drop_credits(true).filter([
+		"https://example.com/c1/01.jpg",
+		"https://example.com/c1/pixel.gif",
+		"https://example.com/c1/02.jpg",
+		"https://example.com/c1/credits.jpg",
+		"https://example.com/c1/03.jpg",
+	]);
+	assert_eq!(
+		pages,
+		[
+			"https://example.com/c1/01.jpg",
+			"https://example.com/c1/02.jpg",
+			"https://example.com/c1/03.jpg",
+		]
+	);
+}
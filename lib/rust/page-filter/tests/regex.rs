@@ -0,0 +1,134 @@
+use page_filter::Regex;
+
+fn matches(pattern: &str, text: &str) -> bool {
+	Regex::new(pattern)
+		.unwrap_or_else(|| panic!("{pattern} should compile"))
+		.is_match(text)
+}
+
+fn check(cases: &[(&str, &str, bool)]) {
+	for (pattern, text, expected) in cases {
+		assert_eq!(matches(pattern, text), *expected, "{pattern} on {text}");
+	}
+}
+
+#[test]
+fn literals() {
+	check(&[
+		("credit", "page_credits.png", true),
+		("credit", "page_01.png", false),
+		// case is ignored on both sides
+		("Credit", "PAGE_CREDITS.PNG", true),
+		(r"a\.png", "a.png", true),
+		(r"a\.png", "axpng", false),
+		("a.png", "axpng", true),
+		(r"\(1\)", "image (1).jpg", true),
+		(r"a\tb", "a\tb", true),
+	]);
+}
+
+#[test]
+fn classes() {
+	check(&[
+		("[a-c]x", "bx", true),
+		("[a-c]x", "dx", false),
+		("[A-C]x", "bx", true),
+		("[_-]", "a-b", true),
+		("[^/]+$", "a/b", true),
+		("^[^/]+$", "a/b", false),
+		(r"[\d_]+", "_", true),
+		(r"^\d+$", "0123", true),
+		(r"^\d+$", "01a3", false),
+		(r"^\w+$", "page_01", true),
+		(r"^\w+$", "page-01", false),
+		(r"a\sb", "a b", true),
+		(r"a\sb", "a_b", false),
+	]);
+}
+
+#[test]
+fn negated_classes() {
+	check(&[
+		(r"^\D+$", "page", true),
+		(r"^\D+$", "page1", false),
+		(r"^\W$", "-", true),
+		(r"^\W$", "a", false),
+		(r"^\S+$", "a_b", true),
+		(r"^\S+$", "a b", false),
+		("^[^a-z]+$", "0123", true),
+		("^[^a-z]+$", "01b3", false),
+	]);
+}
+
+#[test]
+fn quantifiers() {
+	check(&[
+		("^ab*c$", "ac", true),
+		("^ab*c$", "abbbc", true),
+		("^ab+c$", "ac", false),
+		("^ab+c$", "abc", true),
+		("^ab?c$", "abbc", false),
+		("^colou?r$", "color", true),
+		(r"^\d{3}$", "012", true),
+		(r"^\d{3}$", "0123", false),
+		(r"^\d{2,}$", "0", false),
+		(r"^\d{2,}$", "01234", true),
+		(r"^\d{2,3}$", "01", true),
+		(r"^\d{2,3}$", "0123", false),
+		// backtracking out of a greedy repetition
+		(r"^.*\.png$", "a.png.png", true),
+		("^(ab)+$", "ababab", true),
+		("^(ab)+$", "ababa", false),
+		("^(a*)*$", "aaaa", true),
+	]);
+}
+
+#[test]
+fn alternation() {
+	check(&[
+		("cat|dog", "hotdog", true),
+		("cat|dog", "bird", false),
+		("^(1x1|pixel)\\.gif$", "pixel.gif", true),
+		("^(1x1|pixel)\\.gif$", "pixel.png", false),
+		("^(?:a|b)c$", "bc", true),
+		("^(a|)b$", "b", true),
+	]);
+}
+
+#[test]
+fn anchors() {
+	check(&[
+		("^data:", "data:image/gif", true),
+		("^data:", "https://a.com/data:", false),
+		(r"\.webp$", "a.webp", true),
+		(r"\.webp$", "a.webp?v=2", false),
+		("^$", "", true),
+		("^$", "a", false),
+	]);
+}
+
+#[test]
+fn invalid_patterns() {
+	for pattern in [
+		"(ab",
+		"ab)",
+		"[ab",
+		"*a",
+		"a**",
+		"+",
+		"a{3,1}",
+		"a{,2}",
+		"a{2",
+		"[z-a]",
+		// escapes that aren't supported rather than matching a letter
+		r"\b",
+		r"\Bcredit",
+		r"\p{L}",
+		r"\1",
+		r"a\",
+		// a negated class inside a class
+		r"[\D_]",
+	] {
+		assert!(Regex::new(pattern).is_none(), "{pattern} should be invalid");
+	}
+}
@@ -0,0 +1,190 @@
+//! Relative date parsing shared by sources whose sites show when a chapter
+//! came out as `3 hours ago` instead of a date.
+//!
+//! Phrases like `yesterday`, `2 giờ trước`, `5 дней назад`, `hace una
+//! semana`, `il y a 3 j`, `منذ ساعتين` or `3小时前` are read into a time
+//! before a given `now`. Months are 30 days and years 365. Anything that
+//! isn't a relative date, like an absolute date with several numbers, comes
+//! back as `None` so that sources can fall back to their own format.
+#![no_std]
+extern crate alloc;
+
+mod words;
+
+use alloc::{string::String, vec::Vec};
+
+use words::*;
+
+/// Reads a relative date, returning seconds since the epoch like
+/// `aidoku::std::current_date()`, which is what `now` usually is.
+pub fn parse(text: &str, now: f64) -> Option<f64> {
+	let text = normalize(text);
+	if text.is_empty() {
+		return None;
+	}
+
+	if let Some((_, days)) = DAYS.iter().find(|(phrase, _)| contains_word(&text, phrase)) {
+		return Some(now - days * DAY);
+	}
+
+	if !AGO.iter().any(|marker| contains_word(&text, marker)) {
+		return None;
+	}
+
+	let tokens = tokenize(&text);
+	let mut numbers = tokens.iter().filter_map(|token| match token {
+		Token::Number(number) => Some(*number),
+		Token::Word(_) => None,
+	});
+	let number = numbers.next();
+	if numbers.next().is_some() {
+		return None;
+	}
+
+	let (unit, count) = find_unit(&tokens)?;
+	Some(now - number.unwrap_or(count) * unit)
+}
+
+enum Token<'a> {
+	Number(f64),
+	Word(&'a str),
+}
+
+fn normalize(text: &str) -> String {
+	text.trim()
+		.to_lowercase()
+		.replace('\u{a0}', " ")
+		.replace('’', "'")
+}
+
+/// Splits the text into numbers and runs of letters, dropping everything
+/// else. Letters and digits are split apart, so `5m` is a number and a word.
+fn tokenize(text: &str) -> Vec<Token<'_>> {
+	let mut tokens = Vec::new();
+	let mut word_start = None;
+	let mut number: Option<f64> = None;
+
+	for (i, c) in text.char_indices() {
+		if let Some(d) = digit(c) {
+			if let Some(start) = word_start.take() {
+				tokens.push(Token::Word(&text[start..i]));
+			}
+			number = Some(number.unwrap_or(0.0) * 10.0 + f64::from(d));
+			continue;
+		}
+		if let Some(n) = number.take() {
+			tokens.push(Token::Number(n));
+		}
+		if c.is_alphabetic() {
+			word_start.get_or_insert(i);
+		} else if let Some(start) = word_start.take() {
+			tokens.push(Token::Word(&text[start..i]));
+		}
+	}
+	if let Some(n) = number {
+		tokens.push(Token::Number(n));
+	}
+	if let Some(start) = word_start {
+		tokens.push(Token::Word(&text[start..]));
+	}
+	tokens
+}
+
+/// ASCII, Arabic-Indic and fullwidth digits.
+fn digit(c: char) -> Option<u32> {
+	let zero = match c {
+		'0'..='9' => '0',
+		'\u{660}'..='\u{669}' => '\u{660}',
+		'\u{6f0}'..='\u{6f9}' => '\u{6f0}',
+		'\u{ff10}'..='\u{ff19}' => '\u{ff10}',
+		_ => return None,
+	};
+	Some(c as u32 - zero as u32)
+}
+
+/// The length of the unit, and how many of it there are when the text has
+/// no number.
+fn find_unit(tokens: &[Token]) -> Option<(f64, f64)> {
+	let words = || {
+		tokens
+			.iter()
+			.enumerate()
+			.filter_map(|(i, token)| match token {
+				Token::Word(word) => Some((i, *word)),
+				Token::Number(_) => None,
+			})
+	};
+
+	for (_, word) in words() {
+		if let Some(unit) = lookup(UNITS, word) {
+			return Some((unit, 1.0));
+		}
+		if let Some(unit) = lookup(DUAL_UNITS, word) {
+			return Some((unit, 2.0));
+		}
+		if let Some((stem, unit)) = UNIT_STEMS.iter().find(|(stem, _)| word.starts_with(stem)) {
+			// `год` is a year in Russian but short for hours in Ukrainian
+			if *stem == "год" && word == "год" && words().any(|(_, word)| word == "тому")
+			{
+				return Some((HOUR, 1.0));
+			}
+			return Some((*unit, 1.0));
+		}
+		if word.chars().any(is_cjk) {
+			if let Some((_, unit)) = CJK_UNITS.iter().find(|(unit, _)| word.contains(unit)) {
+				return Some((*unit, 1.0));
+			}
+		}
+	}
+
+	for (i, word) in words() {
+		let after_count = i
+			.checked_sub(1)
+			.map(|previous| match tokens[previous] {
+				Token::Number(_) => true,
+				Token::Word(previous) => ONE.contains(&previous),
+			})
+			.unwrap_or(false);
+		if after_count {
+			if let Some(unit) = lookup(SHORT_UNITS, word) {
+				return Some((unit, 1.0));
+			}
+		}
+	}
+	None
+}
+
+fn lookup(table: &[(&str, f64)], word: &str) -> Option<f64> {
+	table
+		.iter()
+		.find(|(name, _)| *name == word)
+		.map(|(_, unit)| *unit)
+}
+
+/// Whether `needle` is in `text` and not part of a longer word. Scripts
+/// written without spaces match anywhere.
+fn contains_word(text: &str, needle: &str) -> bool {
+	let is_word_char = |c: char| c.is_alphanumeric() && !is_cjk(c);
+	let check_start = needle.chars().next().is_some_and(is_word_char);
+	let check_end = needle.chars().next_back().is_some_and(is_word_char);
+
+	text.match_indices(needle).any(|(i, _)| {
+		let before = text[..i].chars().next_back();
+		let after = text[i + needle.len()..].chars().next();
+		let joined_before = check_start && before.is_some_and(is_word_char);
+		let joined_after = check_end && after.is_some_and(is_word_char);
+		!joined_before && !joined_after
+	})
+}
+
+/// Han, kana and halfwidth katakana.
+fn is_cjk(c: char) -> bool {
+	matches!(
+		c,
+		'\u{3040}'..='\u{30ff}'
+			| '\u{3400}'..='\u{4dbf}'
+			| '\u{4e00}'..='\u{9fff}'
+			| '\u{f900}'..='\u{faff}'
+			| '\u{ff66}'..='\u{ff9f}'
+	)
+}
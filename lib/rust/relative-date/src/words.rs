@@ -0,0 +1,356 @@
+//! The words for units and relative days, lowercased.
+
+pub const SECOND: f64 = 1.0;
+pub const MINUTE: f64 = 60.0 * SECOND;
+pub const HOUR: f64 = 60.0 * MINUTE;
+pub const DAY: f64 = 24.0 * HOUR;
+pub const WEEK: f64 = 7.0 * DAY;
+pub const MONTH: f64 = 30.0 * DAY;
+pub const YEAR: f64 = 365.0 * DAY;
+
+/// Units written out or abbreviated, matched against whole words.
+pub const UNITS: &[(&str, f64)] = &[
+	// en
+	("sec", SECOND),
+	("secs", SECOND),
+	("second", SECOND),
+	("seconds", SECOND),
+	("min", MINUTE),
+	("mins", MINUTE),
+	("minute", MINUTE),
+	("minutes", MINUTE),
+	("hr", HOUR),
+	("hrs", HOUR),
+	("hour", HOUR),
+	("hours", HOUR),
+	("day", DAY),
+	("days", DAY),
+	("wk", WEEK),
+	("wks", WEEK),
+	("week", WEEK),
+	("weeks", WEEK),
+	("mos", MONTH),
+	("mth", MONTH),
+	("mths", MONTH),
+	("month", MONTH),
+	("months", MONTH),
+	("yr", YEAR),
+	("yrs", YEAR),
+	("year", YEAR),
+	("years", YEAR),
+	// es, pt
+	("seg", SECOND),
+	("segundo", SECOND),
+	("segundos", SECOND),
+	("minuto", MINUTE),
+	("minutos", MINUTE),
+	("hora", HOUR),
+	("horas", HOUR),
+	("día", DAY),
+	("días", DAY),
+	("dia", DAY),
+	("dias", DAY),
+	("semana", WEEK),
+	("semanas", WEEK),
+	("mes", MONTH),
+	("meses", MONTH),
+	("mês", MONTH),
+	("año", YEAR),
+	("años", YEAR),
+	("ano", YEAR),
+	("anos", YEAR),
+	// fr
+	("seconde", SECOND),
+	("secondes", SECOND),
+	("mn", MINUTE),
+	("heure", HOUR),
+	("heures", HOUR),
+	("jour", DAY),
+	("jours", DAY),
+	("semaine", WEEK),
+	("semaines", WEEK),
+	("mois", MONTH),
+	("ans", YEAR),
+	("année", YEAR),
+	("années", YEAR),
+	// de, "Protokoll" is how some sites machine translate minutes
+	("sekunde", SECOND),
+	("sekunden", SECOND),
+	("minuten", MINUTE),
+	("protokoll", MINUTE),
+	("std", HOUR),
+	("stunde", HOUR),
+	("stunden", HOUR),
+	("tag", DAY),
+	("tage", DAY),
+	("tagen", DAY),
+	("woche", WEEK),
+	("wochen", WEEK),
+	("monat", MONTH),
+	("monate", MONTH),
+	("monaten", MONTH),
+	("jahr", YEAR),
+	("jahre", YEAR),
+	("jahren", YEAR),
+	// it
+	("secondo", SECOND),
+	("secondi", SECOND),
+	("minuti", MINUTE),
+	("ora", HOUR),
+	("ore", HOUR),
+	("giorno", DAY),
+	("giorni", DAY),
+	("settimana", WEEK),
+	("settimane", WEEK),
+	("mese", MONTH),
+	("mesi", MONTH),
+	("anno", YEAR),
+	("anni", YEAR),
+	// id, where "se-" means one
+	("detik", SECOND),
+	("sedetik", SECOND),
+	("semenit", MINUTE),
+	("sejam", HOUR),
+	("sehari", DAY),
+	("seminggu", WEEK),
+	("sebulan", MONTH),
+	("setahun", YEAR),
+	("menit", MINUTE),
+	("jam", HOUR),
+	("hari", DAY),
+	("minggu", WEEK),
+	("bulan", MONTH),
+	("tahun", YEAR),
+	// vi
+	("giây", SECOND),
+	("phút", MINUTE),
+	("giờ", HOUR),
+	("tiếng", HOUR),
+	("ngày", DAY),
+	("tuần", WEEK),
+	("tháng", MONTH),
+	("năm", YEAR),
+	// ru, uk
+	("сек", SECOND),
+	("мин", MINUTE),
+	("хв", MINUTE),
+	("день", DAY),
+	("дня", DAY),
+	("дней", DAY),
+	("дн", DAY),
+	("дні", DAY),
+	("днів", DAY),
+	("нед", WEEK),
+	("тиждень", WEEK),
+	("мес", MONTH),
+	("міс", MONTH),
+	("лет", YEAR),
+	("рік", YEAR),
+	("роки", YEAR),
+	("років", YEAR),
+	// tr
+	("saniye", SECOND),
+	("dakika", MINUTE),
+	("saat", HOUR),
+	("gün", DAY),
+	("hafta", WEEK),
+	("yıl", YEAR),
+	// ar
+	("ثانية", SECOND),
+	("ثوان", SECOND),
+	("ثواني", SECOND),
+	("دقيقة", MINUTE),
+	("دقائق", MINUTE),
+	("ساعة", HOUR),
+	("ساعات", HOUR),
+	("يوم", DAY),
+	("أيام", DAY),
+	("ايام", DAY),
+	("أسبوع", WEEK),
+	("اسبوع", WEEK),
+	("أسابيع", WEEK),
+	("اسابيع", WEEK),
+	("شهر", MONTH),
+	("أشهر", MONTH),
+	("اشهر", MONTH),
+	("شهور", MONTH),
+	("سنة", YEAR),
+	("سنوات", YEAR),
+	("عام", YEAR),
+	("أعوام", YEAR),
+];
+
+/// Single letters and other abbreviations that are only units right after
+/// a number, as in `5m` or `2 d`.
+pub const SHORT_UNITS: &[(&str, f64)] = &[
+	("s", SECOND),
+	("m", MINUTE),
+	("h", HOUR),
+	("d", DAY),
+	("j", DAY),
+	("w", WEEK),
+	("y", YEAR),
+	("an", YEAR),
+	("sn", SECOND),
+	("dk", MINUTE),
+	("sa", HOUR),
+	("ay", MONTH),
+	("mo", MONTH),
+	("sem", WEEK),
+	("с", SECOND),
+	("ч", HOUR),
+	("г", YEAR),
+];
+
+/// Words for one, after which a short unit is read like after a number
+/// (`il y a un an`).
+pub const ONE: &[&str] = &[
+	"a", "an", "one", "un", "une", "uno", "una", "um", "uma", "ein", "eine", "einem", "einer",
+	"bir", "một", "один", "одна", "одну", "одно",
+];
+
+/// Word stems for languages that inflect their units.
+pub const UNIT_STEMS: &[(&str, f64)] = &[
+	("секунд", SECOND),
+	("минут", MINUTE),
+	("хвилин", MINUTE),
+	("час", HOUR),
+	("годин", HOUR),
+	("недел", WEEK),
+	("тижн", WEEK),
+	("месяц", MONTH),
+	("місяц", MONTH),
+	("год", YEAR),
+];
+
+/// Arabic duals, which stand for two of a unit without a number.
+pub const DUAL_UNITS: &[(&str, f64)] = &[
+	("ثانيتين", SECOND),
+	("دقيقتين", MINUTE),
+	("ساعتين", HOUR),
+	("يومين", DAY),
+	("أسبوعين", WEEK),
+	("اسبوعين", WEEK),
+	("شهرين", MONTH),
+	("سنتين", YEAR),
+	("عامين", YEAR),
+];
+
+/// Units in languages written without spaces, matched anywhere after the
+/// number. Longer ones come first so `个月` isn't read as `月`.
+pub const CJK_UNITS: &[(&str, f64)] = &[
+	("秒", SECOND),
+	("分钟", MINUTE),
+	("分鐘", MINUTE),
+	("分", MINUTE),
+	("小时", HOUR),
+	("小時", HOUR),
+	("時間", HOUR),
+	("时", HOUR),
+	("天", DAY),
+	("星期", WEEK),
+	("週間", WEEK),
+	("周", WEEK),
+	("週", WEEK),
+	("个月", MONTH),
+	("個月", MONTH),
+	("ヶ月", MONTH),
+	("ケ月", MONTH),
+	("か月", MONTH),
+	("ヵ月", MONTH),
+	("カ月", MONTH),
+	("月", MONTH),
+	("日", DAY),
+	("年", YEAR),
+];
+
+/// Marks a date as being in the past, for units written without a number
+/// (`an hour ago`, `hace un día`, `час назад`).
+pub const AGO: &[&str] = &[
+	"ago",
+	"hace",
+	"há",
+	"atrás",
+	"il y a",
+	"vor",
+	"cách đây",
+	"fa",
+	"lalu",
+	"trước",
+	"назад",
+	"тому",
+	"önce",
+	"منذ",
+	"قبل",
+	"前",
+];
+
+/// Whole phrases, as days before now. Phrases containing another phrase
+/// come first (`avant-hier` before `hier`, `一昨日` before `昨日`).
+pub const DAYS: &[(&str, f64)] = &[
+	// the day before yesterday
+	("day before yesterday", 2.0),
+	("anteayer", 2.0),
+	("antes de ayer", 2.0),
+	("anteontem", 2.0),
+	("avant-hier", 2.0),
+	("vorgestern", 2.0),
+	("l'altro ieri", 2.0),
+	("kemarin lusa", 2.0),
+	("hôm kia", 2.0),
+	("позавчера", 2.0),
+	("позавчора", 2.0),
+	("evvelsi gün", 2.0),
+	("أول أمس", 2.0),
+	("اول امس", 2.0),
+	("前天", 2.0),
+	("一昨日", 2.0),
+	("おととい", 2.0),
+	// yesterday
+	("yesterday", 1.0),
+	("ayer", 1.0),
+	("ontem", 1.0),
+	("hier", 1.0),
+	("gestern", 1.0),
+	("ieri", 1.0),
+	("kemarin", 1.0),
+	("hôm qua", 1.0),
+	("вчера", 1.0),
+	("вчора", 1.0),
+	("dün", 1.0),
+	("أمس", 1.0),
+	("امس", 1.0),
+	("昨天", 1.0),
+	("昨日", 1.0),
+	// today and just now
+	("today", 0.0),
+	("just now", 0.0),
+	("hoy", 0.0),
+	("ahora", 0.0),
+	("hoje", 0.0),
+	("agora", 0.0),
+	("aujourd'hui", 0.0),
+	("à l'instant", 0.0),
+	("heute", 0.0),
+	("gerade eben", 0.0),
+	("oggi", 0.0),
+	("adesso", 0.0),
+	("hari ini", 0.0),
+	("baru saja", 0.0),
+	("hôm nay", 0.0),
+	("vừa xong", 0.0),
+	("сегодня", 0.0),
+	("только что", 0.0),
+	("сьогодні", 0.0),
+	("щойно", 0.0),
+	("bugün", 0.0),
+	("şimdi", 0.0),
+	("az önce", 0.0),
+	("اليوم", 0.0),
+	("الآن", 0.0),
+	("今天", 0.0),
+	("刚刚", 0.0),
+	("剛剛", 0.0),
+	("たった今", 0.0),
+	("今日", 0.0),
+];
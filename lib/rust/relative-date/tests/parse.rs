@@ -0,0 +1,135 @@
+use relative_date::parse;
+
+const NOW: f64 = 1_700_000_000.0;
+
+const MINUTE: f64 = 60.0;
+const HOUR: f64 = 60.0 * MINUTE;
+const DAY: f64 = 24.0 * HOUR;
+const WEEK: f64 = 7.0 * DAY;
+const MONTH: f64 = 30.0 * DAY;
+const YEAR: f64 = 365.0 * DAY;
+
+/// Each text and how long before `NOW` it is.
+const RELATIVE: &[(&str, f64)] = &[
+	// en
+	("just now", 0.0),
+	("Today", 0.0),
+	("Yesterday", DAY),
+	("10 seconds ago", 10.0),
+	("1 min ago", MINUTE),
+	("an hour ago", HOUR),
+	("3 hours ago", 3.0 * HOUR),
+	("2 days ago", 2.0 * DAY),
+	("a week ago", WEEK),
+	("5 months ago", 5.0 * MONTH),
+	("2 years ago", 2.0 * YEAR),
+	("5m ago", 5.0 * MINUTE),
+	("3d ago", 3.0 * DAY),
+	("2 mos ago", 2.0 * MONTH),
+	("  4 HOURS AGO\n", 4.0 * HOUR),
+	// es
+	("hace 5 minutos", 5.0 * MINUTE),
+	("hace una semana", WEEK),
+	("Hace 3 días", 3.0 * DAY),
+	("ayer", DAY),
+	("anteayer", 2.0 * DAY),
+	// pt
+	("há 2 horas", 2.0 * HOUR),
+	("3 dias atrás", 3.0 * DAY),
+	("há um mês", MONTH),
+	("ontem", DAY),
+	("hoje", 0.0),
+	// fr
+	("il y a 3 j", 3.0 * DAY),
+	("il y a 2 heures", 2.0 * HOUR),
+	("il y a un an", YEAR),
+	("Aujourd’hui", 0.0),
+	("avant-hier", 2.0 * DAY),
+	("hier", DAY),
+	// de, it
+	("vor 3 Tagen", 3.0 * DAY),
+	("vor 5 Protokoll", 5.0 * MINUTE),
+	("2 giorni fa", 2.0 * DAY),
+	("un'ora fa", HOUR),
+	// id
+	("3 jam yang lalu", 3.0 * HOUR),
+	("sehari lalu", DAY),
+	("2 minggu lalu", 2.0 * WEEK),
+	("kemarin", DAY),
+	("kemarin lusa", 2.0 * DAY),
+	// vi
+	("2 giờ trước", 2.0 * HOUR),
+	("30 giây trước", 30.0),
+	("4 tháng trước", 4.0 * MONTH),
+	("cách đây 3 tiếng", 3.0 * HOUR),
+	("Hôm qua", DAY),
+	// ru
+	("5 дней назад", 5.0 * DAY),
+	("минуту назад", MINUTE),
+	("2 часа назад", 2.0 * HOUR),
+	("3 недели назад", 3.0 * WEEK),
+	("1 год назад", YEAR),
+	("6 мес. назад", 6.0 * MONTH),
+	("вчера", DAY),
+	("позавчера", 2.0 * DAY),
+	// uk
+	("2 години тому", 2.0 * HOUR),
+	("3 год тому", 3.0 * HOUR),
+	("2 тижні тому", 2.0 * WEEK),
+	("5 років тому", 5.0 * YEAR),
+	("вчора", DAY),
+	// tr
+	("3 saat önce", 3.0 * HOUR),
+	("1 ay önce", MONTH),
+	("2 gün önce", 2.0 * DAY),
+	("dün", DAY),
+	// ar
+	("منذ 3 ساعات", 3.0 * HOUR),
+	("منذ ساعتين", 2.0 * HOUR),
+	("منذ يوم", DAY),
+	("منذ ٥ أيام", 5.0 * DAY),
+	("أمس", DAY),
+	// zh
+	("3小时前", 3.0 * HOUR),
+	("5分钟前", 5.0 * MINUTE),
+	("2天前", 2.0 * DAY),
+	("1周前", WEEK),
+	("3个月前", 3.0 * MONTH),
+	("2 年前", 2.0 * YEAR),
+	("昨天", DAY),
+	("前天", 2.0 * DAY),
+	// ja
+	("3時間前", 3.0 * HOUR),
+	("2日前", 2.0 * DAY),
+	("1週間前", WEEK),
+	("３ヶ月前", 3.0 * MONTH),
+	("昨日", DAY),
+	("一昨日", 2.0 * DAY),
+];
+
+/// Texts that aren't relative dates.
+const NOT_RELATIVE: &[&str] = &[
+	"",
+	"March 5, 2023",
+	"12/03/2023",
+	"13:45 05/11",
+	"2023年3月5日",
+	"5 days",
+	"long ago",
+	"Chicago 3 days",
+	"Chapter 12",
+];
+
+#[test]
+fn relative_dates() {
+	for (text, before) in RELATIVE {
+		assert_eq!(parse(text, NOW), Some(NOW - before), "{text:?}");
+	}
+}
+
+#[test]
+fn not_relative_dates() {
+	for text in NOT_RELATIVE {
+		assert_eq!(parse(text, NOW), None, "{text:?}");
+	}
+}
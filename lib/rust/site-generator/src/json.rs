@@ -0,0 +1,83 @@
+//! Writes `source.json`, laid out like the hand-written ones.
+use crate::Manifest;
+use std::fmt::Write;
+
+pub fn source_json(manifest: &Manifest) -> String {
+	let info = &manifest.info;
+	let mut json = String::from("{\n\t\"info\": {\n");
+	let _ = writeln!(json, "\t\t\"id\": {},", string(&info.id));
+	let _ = writeln!(json, "\t\t\"lang\": {},", string(&info.lang));
+	let _ = writeln!(json, "\t\t\"name\": {},", string(&info.name));
+	let _ = writeln!(json, "\t\t\"version\": {},", info.version);
+	if let Some(url) = &info.url {
+		let _ = writeln!(json, "\t\t\"url\": {},", string(url));
+	}
+	if let Some(urls) = &info.urls {
+		json.push_str("\t\t\"urls\": [\n");
+		for (idx, url) in urls.iter().enumerate() {
+			let comma = if idx + 1 < urls.len() { "," } else { "" };
+			let _ = writeln!(json, "\t\t\t{}{comma}", string(url));
+		}
+		json.push_str("\t\t],\n");
+	}
+	let _ = writeln!(json, "\t\t\"nsfw\": {}", info.nsfw);
+	json.push_str("\t}");
+
+	if !manifest.listings.is_empty() {
+		json.push_str(",\n\t\"listings\": [\n");
+		for (idx, listing) in manifest.listings.iter().enumerate() {
+			let comma = if idx + 1 < manifest.listings.len() {
+				","
+			} else {
+				""
+			};
+			let _ = writeln!(
+				json,
+				"\t\t{{\n\t\t\t\"name\": {}\n\t\t}}{comma}",
+				string(listing)
+			);
+		}
+		json.push_str("\t]");
+	}
+	if !manifest.languages.is_empty() {
+		json.push_str(",\n\t\"languages\": [\n");
+		for (idx, code) in manifest.languages.iter().enumerate() {
+			let comma = if idx + 1 < manifest.languages.len() {
+				","
+			} else {
+				""
+			};
+			let _ = writeln!(
+				json,
+				"\t\t{{\n\t\t\t\"code\": {}\n\t\t}}{comma}",
+				string(code)
+			);
+		}
+		json.push_str("\t]");
+	}
+	if let Some(select_type) = &manifest.language_select_type {
+		let _ = write!(json, ",\n\t\"languageSelectType\": {}", string(select_type));
+	}
+	json.push_str("\n}\n");
+	json
+}
+
+/// A JSON string literal.
+pub fn string(value: &str) -> String {
+	let mut out = String::with_capacity(value.len() + 2);
+	out.push('"');
+	for c in value.chars() {
+		match c {
+			'"' => out.push_str("\\\""),
+			'\\' => out.push_str("\\\\"),
+			'\n' => out.push_str("\\n"),
+			'\t' => out.push_str("\\t"),
+			c if (c as u32) < 0x20 => {
+				let _ = write!(out, "\\u{:04x}", c as u32);
+			}
+			c => out.push(c),
+		}
+	}
+	out.push('"');
+	out
+}
@@ -0,0 +1,345 @@
+//! Generates template sources from declarative site manifests.
+//!
+//! A template workspace can describe sources as `sites/<name>/site.toml`
+//! instead of hand-written crates in `sources/`. Each manifest names its
+//! template, the source info and listings that go into `source.json`, and a
+//! `[site]` table with the template's settings. The generator writes a glue
+//! crate for each one into `generated/<name>/`, which the workspace builds
+//! like any other source. `generated/` is committed so that the workspace
+//! builds without running the generator, and [`check_workspace`] tells
+//! whether it's up to date. The Madara, MangaStream, MMRCMS and WPComics
+//! templates have generators; MMRCMS and WPComics sources that override what
+//! their template does are still hand-written in `sources/`.
+//!
+//! ```toml
+//! template = "madara"
+//! listings = ["Trending", "Popular"]
+//!
+//! [info]
+//! id = "en.example"
+//! lang = "en"
+//! name = "Example"
+//! version = 1
+//! url = "https://example.com"
+//! nsfw = 0
+//!
+//! [site]
+//! base_url = "https://example.com"
+//! alt_ajax = true
+//! viewer = "categories"
+//! ```
+use serde::Deserialize;
+use std::{
+	fmt, fs, io,
+	path::{Path, PathBuf},
+};
+
+mod json;
+mod madara;
+mod mangastream;
+mod mmrcms;
+mod settings;
+mod wpcomics;
+
+#[derive(Debug)]
+pub struct Error {
+	pub site: String,
+	pub message: String,
+}
+
+impl Error {
+	fn new(site: &str, message: impl Into<String>) -> Self {
+		Self {
+			site: String::from(site),
+			message: message.into(),
+		}
+	}
+}
+
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}: {}", self.site, self.message)
+	}
+}
+
+impl std::error::Error for Error {}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Manifest {
+	pub template: String,
+	#[serde(default)]
+	pub listings: Vec<String>,
+	pub info: Info,
+	/// The language codes of a `multi` source, picked in its settings.
+	#[serde(default)]
+	pub languages: Vec<String>,
+	pub language_select_type: Option<String>,
+	/// Template settings, checked by the template's generator.
+	pub site: toml::Table,
+}
+
+/// The `info` object of `source.json`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Info {
+	pub id: String,
+	pub lang: String,
+	pub name: String,
+	pub version: u32,
+	pub url: Option<String>,
+	pub urls: Option<Vec<String>>,
+	pub nsfw: u8,
+}
+
+/// A site found in a workspace's `sites` directory.
+pub struct Site {
+	pub name: String,
+	pub dir: PathBuf,
+}
+
+impl Site {
+	pub fn manifest_path(&self) -> PathBuf {
+		self.dir.join("site.toml")
+	}
+
+	pub fn res_dir(&self) -> PathBuf {
+		self.dir.join("res")
+	}
+
+	pub fn load(&self) -> Result<Manifest> {
+		let text = fs::read_to_string(self.manifest_path())
+			.map_err(|err| Error::new(&self.name, err.to_string()))?;
+		toml::from_str(&text).map_err(|err| Error::new(&self.name, err.to_string()))
+	}
+}
+
+/// The files of a generated source crate.
+pub struct Generated {
+	pub cargo_toml: String,
+	pub lib_rs: String,
+	pub source_json: String,
+}
+
+/// Lists the sites of a template workspace, sorted by name.
+pub fn find_sites(workspace: &Path) -> io::Result<Vec<Site>> {
+	let sites_dir = workspace.join("sites");
+	if !sites_dir.is_dir() {
+		return Ok(Vec::new());
+	}
+	let mut sites = Vec::new();
+	for entry in fs::read_dir(sites_dir)? {
+		let dir = entry?.path();
+		if dir.join("site.toml").is_file() {
+			let name = dir
+				.file_name()
+				.and_then(|name| name.to_str())
+				.map(String::from)
+				.unwrap_or_default();
+			sites.push(Site { name, dir });
+		}
+	}
+	sites.sort_by(|a, b| a.name.cmp(&b.name));
+	Ok(sites)
+}
+
+/// Generates the glue crate for a site.
+pub fn generate(name: &str, manifest: &Manifest) -> Result<Generated> {
+	if name.is_empty()
+		|| !name
+			.chars()
+			.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+	{
+		return Err(Error::new(
+			name,
+			"site directories must be lowercase crate names",
+		));
+	}
+	if manifest.info.url.is_none() == manifest.info.urls.is_none() {
+		return Err(Error::new(
+			name,
+			"info needs exactly one of `url` and `urls`",
+		));
+	}
+	let (dependency, lib_rs) = match manifest.template.as_str() {
+		"madara" => ("madara_template", madara::generate(name, manifest)?),
+		"mangastream" => (
+			"mangastream_template",
+			mangastream::generate(name, manifest)?,
+		),
+		"mmrcms" => ("mmrcms_template", mmrcms::generate(name, manifest)?),
+		"wpcomics" => ("wpcomics_template", wpcomics::generate(name, manifest)?),
+		template => {
+			return Err(Error::new(
+				name,
+				format!("no generator for the `{template}` template"),
+			))
+		}
+	};
+	Ok(Generated {
+		cargo_toml: cargo_toml(name, dependency),
+		lib_rs,
+		source_json: json::source_json(manifest),
+	})
+}
+
+/// The files of every generated crate in a workspace, relative to its
+/// `generated` directory.
+fn workspace_files(workspace: &Path) -> Result<Vec<(PathBuf, Vec<u8>)>> {
+	let io_error = |err: io::Error| Error::new(&workspace.display().to_string(), err.to_string());
+
+	let mut files = Vec::new();
+	for site in find_sites(workspace).map_err(io_error)? {
+		let manifest = site.load()?;
+		let generated = generate(&site.name, &manifest)?;
+		let crate_dir = PathBuf::from(&site.name);
+		let site_error = |err: io::Error| Error::new(&site.name, err.to_string());
+
+		files.push((
+			crate_dir.join("Cargo.toml"),
+			generated.cargo_toml.into_bytes(),
+		));
+		files.push((crate_dir.join("src/lib.rs"), generated.lib_rs.into_bytes()));
+		files.push((
+			crate_dir.join("res/source.json"),
+			generated.source_json.into_bytes(),
+		));
+
+		let res_dir = site.res_dir();
+		if res_dir.is_dir() {
+			let mut entries = fs::read_dir(&res_dir)
+				.and_then(|entries| {
+					entries
+						.map(|entry| entry.map(|entry| entry.path()))
+						.collect::<io::Result<Vec<_>>>()
+				})
+				.map_err(site_error)?;
+			entries.sort();
+			for path in entries {
+				let file_name = path.file_name().unwrap_or_default();
+				if file_name == "source.json" {
+					return Err(Error::new(
+						&site.name,
+						"source.json is generated from site.toml and can't be in res",
+					));
+				}
+				let data = fs::read(&path).map_err(site_error)?;
+				files.push((crate_dir.join("res").join(file_name), data));
+			}
+		}
+	}
+	Ok(files)
+}
+
+/// Regenerates every site of a template workspace into `generated/`, and
+/// removes crates whose site is gone. Files are only rewritten when they
+/// change, so that cargo doesn't rebuild everything on each run.
+pub fn generate_workspace(workspace: &Path) -> Result<usize> {
+	let io_error = |err: io::Error| Error::new(&workspace.display().to_string(), err.to_string());
+
+	let files = workspace_files(workspace)?;
+	let out_dir = workspace.join("generated");
+	for path in existing_files(&out_dir).map_err(io_error)? {
+		if !files.iter().any(|(file, _)| *file == path) {
+			fs::remove_file(out_dir.join(&path)).map_err(io_error)?;
+		}
+	}
+	remove_empty_dirs(&out_dir).map_err(io_error)?;
+
+	for (path, contents) in &files {
+		write_if_changed(&out_dir.join(path), contents).map_err(io_error)?;
+	}
+	Ok(find_sites(workspace).map_err(io_error)?.len())
+}
+
+/// The files of `generated/` that are missing, out of date or left over
+/// from a removed site, so that a stale checkout fails instead of building
+/// old sources.
+pub fn check_workspace(workspace: &Path) -> Result<Vec<PathBuf>> {
+	let io_error = |err: io::Error| Error::new(&workspace.display().to_string(), err.to_string());
+
+	let files = workspace_files(workspace)?;
+	let out_dir = workspace.join("generated");
+	let mut stale: Vec<PathBuf> = existing_files(&out_dir)
+		.map_err(io_error)?
+		.into_iter()
+		.filter(|path| !files.iter().any(|(file, _)| file == path))
+		.collect();
+	for (path, contents) in files {
+		if fs::read(out_dir.join(&path)).map_or(true, |current| current != contents) {
+			stale.push(path);
+		}
+	}
+	stale.sort();
+	Ok(stale)
+}
+
+/// The files under `dir`, relative to it.
+fn existing_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+	let mut files = Vec::new();
+	if !dir.is_dir() {
+		return Ok(files);
+	}
+	let mut dirs = vec![PathBuf::new()];
+	while let Some(relative) = dirs.pop() {
+		for entry in fs::read_dir(dir.join(&relative))? {
+			let entry = entry?;
+			let path = relative.join(entry.file_name());
+			if entry.file_type()?.is_dir() {
+				dirs.push(path);
+			} else {
+				files.push(path);
+			}
+		}
+	}
+	Ok(files)
+}
+
+fn remove_empty_dirs(dir: &Path) -> io::Result<bool> {
+	if !dir.is_dir() {
+		return Ok(false);
+	}
+	let mut empty = true;
+	for entry in fs::read_dir(dir)? {
+		let path = entry?.path();
+		if !path.is_dir() || !remove_empty_dirs(&path)? {
+			empty = false;
+		}
+	}
+	if empty {
+		fs::remove_dir(dir)?;
+	}
+	Ok(empty)
+}
+
+fn write_if_changed(path: &Path, contents: impl AsRef<[u8]>) -> io::Result<()> {
+	let contents = contents.as_ref();
+	if fs::read(path).is_ok_and(|current| current == contents) {
+		return Ok(());
+	}
+	if let Some(parent) = path.parent() {
+		fs::create_dir_all(parent)?;
+	}
+	fs::write(path, contents)
+}
+
+fn cargo_toml(name: &str, dependency: &str) -> String {
+	format!(
+		r#"# Generated from sites/{name}/site.toml, don't edit.
+[package]
+name = "{name}"
+version = "0.1.0"
+edition = "2021"
+publish = false
+
+[lib]
+crate-type = ["cdylib"]
+
+[dependencies]
+aidoku = {{ git = "https://github.com/Aidoku/aidoku-rs/" }}
+{dependency} = {{ path = "../../template" }}
+"#
+	)
+}
@@ -0,0 +1,338 @@
+//! Glue crates for the Madara template. Settings map onto the fields of
+//! `MadaraSiteData`; the callbacks are picked from `madara_template::strategies`
+//! by name.
+use crate::{
+	settings::{blocklist, boolean, header, literal, literals, string, strings, unknown_strategy},
+	Error, Manifest, Result,
+};
+use std::fmt::Write;
+use toml::Value;
+
+const STRING_FIELDS: &[&str] = &[
+	"base_url",
+	"lang",
+	"source_path",
+	"search_path",
+	"search_cookies",
+	"post_type",
+	"search_selector",
+	"image_selector",
+	"genre_selector",
+	"search_genre_selector",
+	"author_selector",
+	"description_selector",
+	"chapter_selector",
+	"chapter_next_page_selector",
+	"base_id_selector",
+	"status_filter_ongoing",
+	"status_filter_completed",
+	"status_filter_cancelled",
+	"status_filter_on_hold",
+	"adult_string",
+	"genre_condition",
+	"artist_filter",
+	"year_filter",
+	"genres_filter",
+	"popular",
+	"trending",
+	"ignore_class",
+	"date_format",
+	"date_locale",
+	"text_selector",
+];
+
+const BOOL_FIELDS: &[&str] = &[
+	"alt_ajax",
+	"drop_credit_pages",
+	"chapter_protector",
+	"text_chapters",
+];
+
+const STATUSES: &[(&str, &str)] = &[
+	("ongoing", "MangaStatus::Ongoing"),
+	("completed", "MangaStatus::Completed"),
+	("cancelled", "MangaStatus::Cancelled"),
+	("hiatus", "MangaStatus::Hiatus"),
+];
+
+pub fn generate(name: &str, manifest: &Manifest) -> Result<String> {
+	let mut fields = String::new();
+	let mut imports = vec![
+		"error::Result",
+		"prelude::*",
+		"std::String",
+		"std::Vec",
+		"Chapter",
+		"DeepLink",
+		"Filter",
+		"Listing",
+		"Manga",
+		"MangaPageResult",
+		"Page",
+	];
+	let mut uses_strategies = false;
+	let mut image_referer = None;
+
+	if !manifest.site.contains_key("base_url") {
+		return Err(Error::new(name, "`base_url` is required"));
+	}
+	let base_url_setting = match manifest.site.get("base_url_setting") {
+		Some(value) => {
+			imports.push("std::defaults::defaults_get");
+			Some(string(name, "base_url_setting", value)?)
+		}
+		None => None,
+	};
+
+	for (key, value) in &manifest.site {
+		let key = key.as_str();
+		if let ("base_url", Some(setting)) = (key, base_url_setting) {
+			// the setting's default is in settings.json, `base_url` is only
+			// used if it can't be read
+			let value = string(name, key, value)?;
+			let _ = writeln!(
+				fields,
+				"\t\tbase_url: defaults_get({})
+			.and_then(|value| value.as_string())
+			.map(|value| value.read())
+			.unwrap_or_else(|_| String::from({})),",
+				literal(setting),
+				literal(value),
+			);
+		} else if STRING_FIELDS.contains(&key) {
+			let value = string(name, key, value)?;
+			let _ = writeln!(fields, "\t\t{key}: String::from({}),", literal(value));
+		} else if BOOL_FIELDS.contains(&key) {
+			let value = boolean(name, key, value)?;
+			let _ = writeln!(fields, "\t\t{key}: {value},");
+		} else {
+			match key {
+				"page_blocklist" => {
+					let patterns = blocklist(name, key, value)?;
+					let _ = writeln!(fields, "\t\tpage_blocklist: &{},", literals(&patterns));
+				}
+				"manga_id" => match string(name, key, value)? {
+					"post-id" => {}
+					"slug" => {
+						uses_strategies = true;
+						fields.push_str("\t\tget_manga_id: strategies::slug_manga_id,\n");
+					}
+					other => return Err(unknown_strategy(name, key, other)),
+				},
+				"viewer" => {
+					let strategy = match value.as_str() {
+						None => Some(viewer_table(name, value)?),
+						Some("default") => None,
+						Some("categories") => {
+							Some(String::from("strategies::viewer_from_categories"))
+						}
+						Some("scroll") => Some(String::from("strategies::viewer_scroll")),
+						Some("rtl") => Some(String::from("strategies::viewer_rtl")),
+						Some("ltr") => Some(String::from("strategies::viewer_ltr")),
+						Some(other) => return Err(unknown_strategy(name, key, other)),
+					};
+					if let Some(strategy) = strategy {
+						uses_strategies = true;
+						let _ = writeln!(fields, "\t\tviewer: {strategy},");
+					}
+				}
+				"nsfw" => {
+					let strategy = match value.as_str() {
+						None => Some(nsfw_table(name, value)?),
+						Some("default") => None,
+						Some("safe") => Some(String::from("strategies::nsfw_safe")),
+						Some("suggestive") => Some(String::from("strategies::nsfw_suggestive")),
+						Some("nsfw") => Some(String::from("strategies::nsfw_always")),
+						Some(other) => return Err(unknown_strategy(name, key, other)),
+					};
+					if let Some(strategy) = strategy {
+						uses_strategies = true;
+						let _ = writeln!(fields, "\t\tnsfw: {strategy},");
+					}
+				}
+				"status" => {
+					if let Some(strategy) = value.as_str() {
+						if strategy != "default" {
+							return Err(unknown_strategy(name, key, strategy));
+						}
+					} else {
+						uses_strategies = true;
+						imports.push("MangaStatus");
+						fields.push_str(&status_table(name, value)?);
+					}
+				}
+				"base_url_setting" => {}
+				"image_referer" => image_referer = Some(literal(string(name, key, value)?)),
+				_ => return Err(Error::new(name, format!("`{key}` isn't a Madara setting"))),
+			}
+		}
+	}
+
+	if image_referer.is_some() {
+		imports.push("std::net::Request");
+	}
+	let mut lib = header(name, imports);
+	lib.push('\n');
+	if uses_strategies {
+		lib.push_str("use madara_template::{strategies, template};\n");
+	} else {
+		lib.push_str("use madara_template::template;\n");
+	}
+	let _ = write!(
+		lib,
+		"
+fn get_data() -> template::MadaraSiteData {{
+	template::MadaraSiteData {{
+{fields}		..Default::default()
+	}}
+}}
+
+#[get_manga_list]
+fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {{
+	template::get_manga_list(filters, page, get_data())
+}}
+
+#[get_manga_listing]
+fn get_manga_listing(listing: Listing, page: i32) -> Result<MangaPageResult> {{
+	template::get_manga_listing(get_data(), listing, page)
+}}
+
+#[get_manga_details]
+fn get_manga_details(id: String) -> Result<Manga> {{
+	template::get_manga_details(id, get_data())
+}}
+
+#[get_chapter_list]
+fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {{
+	template::get_chapter_list(id, get_data())
+}}
+
+#[get_page_list]
+fn get_page_list(_manga_id: String, chapter_id: String) -> Result<Vec<Page>> {{
+	template::get_page_list(chapter_id, get_data())
+}}
+"
+	);
+	if let Some(referer) = image_referer {
+		let _ = write!(
+			lib,
+			"
+#[modify_image_request]
+fn modify_image_request(request: Request) {{
+	template::modify_image_request(String::from({referer}), request);
+}}
+"
+		);
+	}
+	lib.push_str(
+		"
+#[handle_url]
+pub fn handle_url(url: String) -> Result<DeepLink> {
+	template::handle_url(url, get_data())
+}
+",
+	);
+	Ok(lib)
+}
+
+/// `status = { selector = "…", ongoing = ["…"], … }`, for sites that show a
+/// localized status.
+fn status_table(name: &str, value: &Value) -> Result<String> {
+	let table = value.as_table().ok_or_else(|| {
+		Error::new(
+			name,
+			"`status` must be \"default\" or a table of status names",
+		)
+	})?;
+	let selector = table
+		.get("selector")
+		.ok_or_else(|| Error::new(name, "the status table needs a `selector`"))?;
+	let selector = literal(string(name, "status.selector", selector)?);
+
+	let mut entries = String::new();
+	for (key, value) in table {
+		if key == "selector" {
+			continue;
+		}
+		let status = STATUSES
+			.iter()
+			.find(|(status, _)| status == key)
+			.map(|(_, variant)| variant)
+			.ok_or_else(|| Error::new(name, format!("`{key}` isn't a manga status")))?;
+		for text in strings(name, key, value)? {
+			let _ = writeln!(entries, "\t\t\t\t\t({}, {status}),", literal(text));
+		}
+	}
+
+	Ok(format!(
+		"\t\tstatus: |html| {{
+			strategies::status_from_table(
+				html,
+				{selector},
+				&[
+{entries}				],
+			)
+		}},
+"
+	))
+}
+
+/// `viewer = { selector = "…", scroll = ["…"] }`, for sites that show the
+/// type of a series.
+fn viewer_table(name: &str, value: &Value) -> Result<String> {
+	let table = value.as_table().ok_or_else(|| {
+		Error::new(
+			name,
+			"`viewer` must be a strategy name or a table with a `selector`",
+		)
+	})?;
+	let mut selector = None;
+	let mut scroll = Vec::new();
+	for (key, value) in table {
+		match key.as_str() {
+			"selector" => selector = Some(string(name, "viewer.selector", value)?),
+			"scroll" => scroll = strings(name, "viewer.scroll", value)?,
+			_ => return Err(Error::new(name, format!("`viewer.{key}` isn't a setting"))),
+		}
+	}
+	let selector =
+		selector.ok_or_else(|| Error::new(name, "the viewer table needs a `selector`"))?;
+	let (selector, scroll) = (literal(selector), literals(&scroll));
+	Ok(format!(
+		"|html, _| {{
+			strategies::viewer_from_table(
+				html,
+				{selector},
+				&{scroll},
+			)
+		}}"
+	))
+}
+
+/// `nsfw = { nsfw = ["…"], suggestive = ["…"] }`, for sites with their own
+/// adult tags.
+fn nsfw_table(name: &str, value: &Value) -> Result<String> {
+	let table = value
+		.as_table()
+		.ok_or_else(|| Error::new(name, "`nsfw` must be a strategy name or a table of tags"))?;
+	let mut nsfw = Vec::new();
+	let mut suggestive = Vec::new();
+	for (key, value) in table {
+		match key.as_str() {
+			"nsfw" => nsfw = strings(name, "nsfw.nsfw", value)?,
+			"suggestive" => suggestive = strings(name, "nsfw.suggestive", value)?,
+			_ => return Err(Error::new(name, format!("`nsfw.{key}` isn't a setting"))),
+		}
+	}
+	let (nsfw, suggestive) = (literals(&nsfw), literals(&suggestive));
+	Ok(format!(
+		"|html, categories| {{
+			strategies::nsfw_from_table(
+				html,
+				categories,
+				&{nsfw},
+				&{suggestive},
+			)
+		}}"
+	))
+}
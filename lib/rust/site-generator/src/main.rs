@@ -0,0 +1,53 @@
+//! `site-generator [--check] <workspace>...` regenerates the sources described
+//! by each template workspace's `sites/*/site.toml`. With `--check`, nothing
+//! is written and it fails if the committed `generated/` crates are out of
+//! date.
+use std::{env, path::PathBuf, process::ExitCode};
+
+fn main() -> ExitCode {
+	let mut check = false;
+	let mut workspaces = Vec::new();
+	for arg in env::args_os().skip(1) {
+		if arg == "--check" {
+			check = true;
+		} else {
+			workspaces.push(PathBuf::from(arg));
+		}
+	}
+	if workspaces.is_empty() {
+		eprintln!("usage: site-generator [--check] <workspace>...");
+		return ExitCode::FAILURE;
+	}
+	let mut result = ExitCode::SUCCESS;
+	for workspace in workspaces {
+		if check {
+			match site_generator::check_workspace(&workspace) {
+				Ok(stale) if stale.is_empty() => {}
+				Ok(stale) => {
+					for path in stale {
+						eprintln!(
+							"{} is out of date",
+							workspace.join("generated").join(path).display()
+						);
+					}
+					eprintln!("run site-generator {} to update it", workspace.display());
+					result = ExitCode::FAILURE;
+				}
+				Err(err) => {
+					eprintln!("error: {err}");
+					return ExitCode::FAILURE;
+				}
+			}
+			continue;
+		}
+		match site_generator::generate_workspace(&workspace) {
+			Ok(0) => {}
+			Ok(count) => println!("generated {count} sources in {}", workspace.display()),
+			Err(err) => {
+				eprintln!("error: {err}");
+				return ExitCode::FAILURE;
+			}
+		}
+	}
+	result
+}
@@ -0,0 +1,242 @@
+//! Glue crates for the MangaStream template. Settings map onto the fields of
+//! `MangaStreamSource`, and `[site.by_language.<code>]` overrides them for
+//! the language picked in a `multi` source's settings.
+use crate::{
+	settings::{blocklist, boolean, header, literal, literals, string, strings, table},
+	Error, Manifest, Result,
+};
+use std::fmt::Write;
+use toml::Value;
+
+const BOOL_FIELDS: &[&str] = &[
+	"use_manga_postids",
+	"use_chapter_postids",
+	"has_permanent_manga_url",
+	"has_permanent_chapter_url",
+	"has_random_chapter_prefix",
+	"is_nsfw",
+	"alt_pages",
+	"protocol",
+	"https_pages",
+	"drop_credit_pages",
+];
+
+const STR_FIELDS: &[&str] = &[
+	"traverse_pathname",
+	"listing_query",
+	"next_page",
+	"next_page_2",
+	"manga_selector",
+	"manga_title",
+	"last_page_text",
+	"last_page_text_2",
+	"manga_details_categories",
+	"manga_details_title",
+	"manga_details_cover",
+	"manga_details_cover_src",
+	"manga_details_author",
+	"manga_details_artist",
+	"manga_details_description",
+	"manga_details_status",
+	"manga_details_type",
+	"manga_details_type_options",
+	"chapter_selector",
+	"chapter_title",
+	"chapter_date",
+	"chapter_url",
+	"chapter_date_format",
+	"chapter_date_format_2",
+	"language",
+	"language_2",
+	"locale",
+	"locale_2",
+	"date_string",
+	"pages_script",
+	"page_selector",
+	"page_url",
+];
+
+/// Fixed size arrays of names, and their size.
+const ARRAY_FIELDS: &[(&str, usize)] = &[
+	("listing", 3),
+	("status_options", 5),
+	("status_options_2", 5),
+];
+
+const STRING_LIST_FIELDS: &[&str] = &["manga_title_trim", "nsfw_genres"];
+
+pub fn generate(name: &str, manifest: &Manifest) -> Result<String> {
+	let imports = vec![
+		"error::Result",
+		"prelude::*",
+		"std::net::Request",
+		"std::String",
+		"std::Vec",
+		"Chapter",
+		"DeepLink",
+		"Filter",
+		"Listing",
+		"Manga",
+		"MangaPageResult",
+		"Page",
+	];
+
+	if !manifest.site.contains_key("base_url") {
+		return Err(Error::new(name, "`base_url` is required"));
+	}
+
+	let overrides = match manifest.site.get("by_language") {
+		Some(value) => table(name, "by_language", value)?
+			.iter()
+			.map(|(code, value)| Ok((code.as_str(), table(name, code, value)?)))
+			.collect::<Result<Vec<_>>>()?,
+		None => Vec::new(),
+	};
+	for (code, settings) in &overrides {
+		if let Some(key) = settings
+			.keys()
+			.find(|key| !manifest.site.contains_key(*key))
+		{
+			return Err(Error::new(
+				name,
+				format!("`by_language.{code}.{key}` needs a default in `[site]`"),
+			));
+		}
+	}
+
+	let mut fields = String::new();
+	for (key, value) in &manifest.site {
+		if key == "by_language" {
+			continue;
+		}
+		let default = field(name, key, value)?;
+		let field_name = match key.as_str() {
+			"tag_ids" => "tagid_mapping",
+			key => key,
+		};
+		let mut cases = String::new();
+		for (code, settings) in &overrides {
+			if let Some(value) = settings.get(key) {
+				let value = field(name, key, value)?;
+				let _ = writeln!(cases, "\t\t\t{code:?} => {value},");
+			}
+		}
+		if cases.is_empty() {
+			let _ = writeln!(fields, "\t\t{field_name}: {default},");
+		} else {
+			let _ = writeln!(
+				fields,
+				"\t\t{field_name}: match lang.as_str() {{\n{cases}\t\t\t_ => {default},\n\t\t}},"
+			);
+		}
+	}
+
+	let mut lib = header(name, imports);
+	lib.push('\n');
+	let instance = if overrides.is_empty() {
+		lib.push_str("use mangastream_template::template::MangaStreamSource;\n");
+		format!("\tMangaStreamSource {{\n{fields}\t\t..Default::default()\n\t}}")
+	} else {
+		lib.push_str(
+			"use mangastream_template::{helper::get_lang_code, template::MangaStreamSource};\n",
+		);
+		format!(
+			"\tlet lang = get_lang_code();\n\tMangaStreamSource {{\n{fields}\t\t..Default::default()\n\t}}"
+		)
+	};
+	let _ = write!(
+		lib,
+		"
+fn get_instance() -> MangaStreamSource {{
+{instance}
+}}
+
+#[get_manga_list]
+fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {{
+	get_instance().parse_manga_list(filters, page)
+}}
+
+#[get_manga_listing]
+fn get_manga_listing(listing: Listing, page: i32) -> Result<MangaPageResult> {{
+	get_instance().parse_manga_listing(get_instance().base_url, listing.name, page)
+}}
+
+#[get_manga_details]
+fn get_manga_details(id: String) -> Result<Manga> {{
+	get_instance().parse_manga_details(id)
+}}
+
+#[get_chapter_list]
+fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {{
+	get_instance().parse_chapter_list(id)
+}}
+
+#[get_page_list]
+fn get_page_list(_manga_id: String, id: String) -> Result<Vec<Page>> {{
+	get_instance().parse_page_list(id)
+}}
+
+#[modify_image_request]
+fn modify_image_request(request: Request) {{
+	get_instance().modify_image_request(request)
+}}
+
+#[handle_url]
+pub fn handle_url(url: String) -> Result<DeepLink> {{
+	get_instance().handle_url(url)
+}}
+"
+	);
+	Ok(lib)
+}
+
+/// The Rust expression for a setting.
+fn field(name: &str, key: &str, value: &Value) -> Result<String> {
+	if key == "base_url" {
+		Ok(format!(
+			"String::from({})",
+			literal(string(name, key, value)?)
+		))
+	} else if BOOL_FIELDS.contains(&key) {
+		Ok(boolean(name, key, value)?.to_string())
+	} else if STR_FIELDS.contains(&key) {
+		Ok(literal(string(name, key, value)?))
+	} else if let Some((_, size)) = ARRAY_FIELDS.iter().find(|(field, _)| *field == key) {
+		let names = strings(name, key, value)?;
+		if names.len() != *size {
+			return Err(Error::new(
+				name,
+				format!("`{key}` needs exactly {size} names"),
+			));
+		}
+		Ok(literals(&names))
+	} else if STRING_LIST_FIELDS.contains(&key) {
+		let names: Vec<String> = strings(name, key, value)?
+			.iter()
+			.map(|name| format!("String::from({})", literal(name)))
+			.collect();
+		Ok(format!("[{}].to_vec()", names.join(", ")))
+	} else if key == "page_blocklist" {
+		Ok(format!("&{}", literals(&blocklist(name, key, value)?)))
+	} else if key == "tag_ids" {
+		Ok(tag_ids(name, value)?)
+	} else {
+		Err(Error::new(
+			name,
+			format!("`{key}` isn't a MangaStream setting"),
+		))
+	}
+}
+
+/// `[site.tag_ids]` maps genre names to the ids the site searches with, for
+/// sources whose second language has its own genres.
+fn tag_ids(name: &str, value: &Value) -> Result<String> {
+	let mut cases = String::new();
+	for (genre, id) in table(name, "tag_ids", value)? {
+		let id = string(name, genre, id)?;
+		let _ = writeln!(cases, "\t\t\t\t{} => {},", literal(genre), literal(id));
+	}
+	Ok(format!(
+		"|tag| {{\n\t\t\tString::from(match tag.as_str() {{\n{cases}\t\t\t\t_ => \"\",\n\t\t\t}})\n\t\t}}"
+	))
+}
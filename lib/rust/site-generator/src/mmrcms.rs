@@ -0,0 +1,189 @@
+//! Glue crates for the MMRCMS template. Settings map onto the fields of
+//! `MMRCMSSource`; the ids searched for each option of the category and tag
+//! filters, and the categories that rate a manga, are lists instead of
+//! closures.
+use crate::{
+	settings::{blocklist, boolean, header, literal, literals, string, strings, table},
+	Error, Manifest, Result,
+};
+use std::fmt::Write;
+use toml::Value;
+
+const STR_FIELDS: &[&str] = &["base_url", "lang", "manga_path", "category", "tags"];
+
+const BOOL_FIELDS: &[&str] = &["use_search_engine", "drop_credit_pages"];
+
+pub fn generate(name: &str, manifest: &Manifest) -> Result<String> {
+	let mut imports = vec![
+		"error::Result",
+		"prelude::*",
+		"std::net::Request",
+		"std::String",
+		"std::Vec",
+		"Chapter",
+		"DeepLink",
+		"Filter",
+		"Listing",
+		"Manga",
+		"MangaPageResult",
+		"Page",
+	];
+
+	if !manifest.site.contains_key("base_url") {
+		return Err(Error::new(name, "`base_url` is required"));
+	}
+
+	let mut uses_helper = false;
+	let mut fields = String::new();
+	for (key, value) in &manifest.site {
+		let field = if STR_FIELDS.contains(&key.as_str()) {
+			format!("{key}: {}", literal(string(name, key, value)?))
+		} else if BOOL_FIELDS.contains(&key.as_str()) {
+			format!("{key}: {}", boolean(name, key, value)?)
+		} else if key == "page_blocklist" {
+			format!("{key}: &{}", literals(&blocklist(name, key, value)?))
+		} else if key == "category_ids" || key == "tag_ids" {
+			uses_helper = true;
+			let mapper = match key.as_str() {
+				"category_ids" => "category_mapper",
+				_ => "tags_mapper",
+			};
+			format!(
+				"{mapper}: |idx| helper::id_from_list(&{}, idx)",
+				literals(&strings(name, key, value)?)
+			)
+		} else if key == "category_parser" {
+			let (parser, fixed) = category_parser(name, value)?;
+			if fixed {
+				imports.extend(["MangaContentRating", "MangaViewer"]);
+			} else {
+				uses_helper = true;
+			}
+			format!("category_parser: {parser}")
+		} else {
+			return Err(Error::new(name, format!("`{key}` isn't an MMRCMS setting")));
+		};
+		let _ = writeln!(fields, "\t\t{field},");
+	}
+
+	let mut lib = header(name, imports);
+	lib.push('\n');
+	if uses_helper {
+		lib.push_str("use mmrcms_template::{helper, template::MMRCMSSource};\n");
+	} else {
+		lib.push_str("use mmrcms_template::template::MMRCMSSource;\n");
+	}
+	let _ = write!(
+		lib,
+		"
+fn get_instance() -> MMRCMSSource<'static> {{
+	MMRCMSSource {{
+{fields}		..Default::default()
+	}}
+}}
+
+#[get_manga_list]
+fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {{
+	get_instance().get_manga_list(filters, page)
+}}
+
+#[get_manga_listing]
+fn get_manga_listing(listing: Listing, page: i32) -> Result<MangaPageResult> {{
+	get_instance().get_manga_listing(listing, page)
+}}
+
+#[get_manga_details]
+fn get_manga_details(id: String) -> Result<Manga> {{
+	get_instance().get_manga_details(id)
+}}
+
+#[get_chapter_list]
+fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {{
+	get_instance().get_chapter_list(id)
+}}
+
+#[get_page_list]
+fn get_page_list(manga_id: String, id: String) -> Result<Vec<Page>> {{
+	get_instance().get_page_list(manga_id, id)
+}}
+
+#[modify_image_request]
+fn modify_image_request(request: Request) {{
+	get_instance().modify_image_request(request)
+}}
+
+#[handle_url]
+fn handle_url(url: String) -> Result<DeepLink> {{
+	get_instance().handle_url(url)
+}}
+"
+	);
+	Ok(lib)
+}
+
+/// `[site.category_parser]` either lists the `nsfw`, `suggestive` and
+/// `scroll` categories, or gives a fixed `rating` and `viewer` for sites
+/// whose categories don't tell. Returns the parser, and whether it's fixed.
+fn category_parser(name: &str, value: &Value) -> Result<(String, bool)> {
+	let settings = table(name, "category_parser", value)?;
+	if let Some(key) = settings
+		.keys()
+		.find(|key| !["nsfw", "suggestive", "scroll", "rating", "viewer"].contains(&key.as_str()))
+	{
+		return Err(Error::new(
+			name,
+			format!("`category_parser.{key}` isn't a category parser setting"),
+		));
+	}
+
+	match (settings.get("rating"), settings.get("viewer")) {
+		(None, None) => {}
+		(Some(rating), Some(viewer)) => {
+			if settings.len() != 2 {
+				return Err(Error::new(
+					name,
+					"a fixed `category_parser` can't list categories",
+				));
+			}
+			let rating = match string(name, "rating", rating)? {
+				"safe" => "Safe",
+				"suggestive" => "Suggestive",
+				"nsfw" => "Nsfw",
+				rating => return Err(Error::new(name, format!("`{rating}` isn't a rating"))),
+			};
+			let viewer = match string(name, "viewer", viewer)? {
+				"rtl" => "Rtl",
+				"ltr" => "Ltr",
+				"vertical" => "Vertical",
+				"scroll" => "Scroll",
+				viewer => return Err(Error::new(name, format!("`{viewer}` isn't a viewer"))),
+			};
+			return Ok((
+				format!("|_, _| (MangaContentRating::{rating}, MangaViewer::{viewer})"),
+				true,
+			));
+		}
+		_ => {
+			return Err(Error::new(
+				name,
+				"a fixed `category_parser` needs both `rating` and `viewer`",
+			))
+		}
+	}
+
+	let list = |key: &str| -> Result<String> {
+		Ok(match settings.get(key) {
+			Some(value) => format!("&{}", literals(&strings(name, key, value)?)),
+			None => String::from("&[]"),
+		})
+	};
+	Ok((
+		format!(
+			"|_, categories| {{\n\t\t\thelper::parse_categories(\n\t\t\t\tcategories,\n\t\t\t\t{},\n\t\t\t\t{},\n\t\t\t\t{},\n\t\t\t)\n\t\t}}",
+			list("nsfw")?,
+			list("suggestive")?,
+			list("scroll")?
+		),
+		false,
+	))
+}
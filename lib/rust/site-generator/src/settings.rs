@@ -0,0 +1,96 @@
+//! Reading `[site]` values and writing the parts every glue crate shares.
+use crate::{Error, Result};
+use page_filter::Regex;
+use std::fmt::Write;
+use toml::Value;
+
+pub fn string<'a>(name: &str, key: &str, value: &'a Value) -> Result<&'a str> {
+	value
+		.as_str()
+		.ok_or_else(|| Error::new(name, format!("`{key}` must be a string")))
+}
+
+pub fn strings<'a>(name: &str, key: &str, value: &'a Value) -> Result<Vec<&'a str>> {
+	value
+		.as_array()
+		.and_then(|values| values.iter().map(Value::as_str).collect())
+		.ok_or_else(|| Error::new(name, format!("`{key}` must be a list of strings")))
+}
+
+pub fn boolean(name: &str, key: &str, value: &Value) -> Result<bool> {
+	value
+		.as_bool()
+		.ok_or_else(|| Error::new(name, format!("`{key}` must be a boolean")))
+}
+
+pub fn table<'a>(name: &str, key: &str, value: &'a Value) -> Result<&'a toml::Table> {
+	value
+		.as_table()
+		.ok_or_else(|| Error::new(name, format!("`{key}` must be a table")))
+}
+
+/// `page_blocklist`, checked against what the page filter supports.
+pub fn blocklist<'a>(name: &str, key: &str, value: &'a Value) -> Result<Vec<&'a str>> {
+	let patterns = strings(name, key, value)?;
+	for pattern in &patterns {
+		if Regex::new(pattern).is_none() {
+			return Err(Error::new(
+				name,
+				format!("`{pattern}` isn't a supported page filter pattern"),
+			));
+		}
+	}
+	Ok(patterns)
+}
+
+pub fn unknown_strategy(name: &str, key: &str, strategy: &str) -> Error {
+	Error::new(name, format!("`{strategy}` isn't a `{key}` strategy"))
+}
+
+/// The header of a generated `lib.rs`, down to the `aidoku` imports.
+pub fn header(name: &str, mut imports: Vec<&str>) -> String {
+	imports.sort_by_key(|import| {
+		// modules before types, like rustfmt
+		(
+			import.chars().next().is_some_and(char::is_uppercase),
+			*import,
+		)
+	});
+	imports.dedup();
+
+	let mut lib = format!(
+		"// Generated from sites/{name}/site.toml, don't edit.\n#![no_std]\nuse aidoku::{{\n"
+	);
+	for import in imports {
+		let _ = writeln!(lib, "\t{import},");
+	}
+	lib.push_str("};\n");
+	lib
+}
+
+/// A Rust string literal. Unlike `{:?}`, it keeps combining marks as they
+/// are, so that the generated crates stay readable.
+pub fn literal(value: &str) -> String {
+	let mut out = String::with_capacity(value.len() + 2);
+	out.push('"');
+	for c in value.chars() {
+		match c {
+			'"' => out.push_str("\\\""),
+			'\\' => out.push_str("\\\\"),
+			'\n' => out.push_str("\\n"),
+			'\t' => out.push_str("\\t"),
+			c if c.is_control() => {
+				let _ = write!(out, "\\u{{{:x}}}", c as u32);
+			}
+			c => out.push(c),
+		}
+	}
+	out.push('"');
+	out
+}
+
+/// An array of Rust string literals.
+pub fn literals(values: &[&str]) -> String {
+	let values: Vec<String> = values.iter().map(|value| literal(value)).collect();
+	format!("[{}]", values.join(", "))
+}
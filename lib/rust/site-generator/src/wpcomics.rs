@@ -0,0 +1,180 @@
+//! Glue crates for the WPComics template. Settings map onto the fields of
+//! `WPComicsSource`, and the sources search with the template's `Genre`
+//! filter through `helper::genre_search_url`.
+use crate::{
+	settings::{blocklist, boolean, header, literal, literals, string, table, unknown_strategy},
+	Error, Manifest, Result,
+};
+use std::fmt::Write;
+use toml::Value;
+
+const STR_FIELDS: &[&str] = &[
+	"next_page",
+	"manga_cell",
+	"manga_cell_url",
+	"manga_cell_title",
+	"manga_cell_image",
+	"manga_cell_image_attr",
+	"manga_listing_pagination",
+	"manga_listing_extension",
+	"manga_details_title",
+	"manga_details_cover",
+	"manga_details_author",
+	"manga_details_description",
+	"manga_details_tags",
+	"manga_details_tags_splitter",
+	"manga_details_status",
+	"manga_details_chapters",
+	"chapter_date_selector",
+	"chapter_anchor_selector",
+	"manga_viewer_page",
+	"manga_viewer_page_url_suffix",
+];
+
+const BOOL_FIELDS: &[&str] = &[
+	"chapter_skip_first",
+	"drop_credit_pages",
+	"vinahost_protection",
+];
+
+const TRANSFORMER_FIELDS: &[&str] = &[
+	"manga_details_title_transformer",
+	"manga_details_author_transformer",
+	"manga_details_status_transformer",
+];
+
+pub fn generate(name: &str, manifest: &Manifest) -> Result<String> {
+	let imports = vec![
+		"error::Result",
+		"prelude::*",
+		"std::net::Request",
+		"std::String",
+		"std::Vec",
+		"Chapter",
+		"DeepLink",
+		"Filter",
+		"Listing",
+		"Manga",
+		"MangaPageResult",
+		"Page",
+	];
+
+	let base_url = match manifest.site.get("base_url") {
+		Some(value) => string(name, "base_url", value)?,
+		None => return Err(Error::new(name, "`base_url` is required")),
+	};
+
+	let mut image_user_agent = None;
+	let mut fields = String::new();
+	for (key, value) in &manifest.site {
+		let field = if key == "base_url" {
+			format!("base_url: String::from({})", literal(base_url))
+		} else if key == "image_user_agent" {
+			image_user_agent = Some(string(name, key, value)?);
+			continue;
+		} else if STR_FIELDS.contains(&key.as_str()) {
+			format!("{key}: {}", literal(string(name, key, value)?))
+		} else if BOOL_FIELDS.contains(&key.as_str()) {
+			format!("{key}: {}", boolean(name, key, value)?)
+		} else if TRANSFORMER_FIELDS.contains(&key.as_str()) {
+			let transformer = match string(name, key, value)? {
+				"trunc_trailing_comic" => "helper::trunc_trailing_comic",
+				"trim" => "|text| String::from(text.trim())",
+				strategy => return Err(unknown_strategy(name, key, strategy)),
+			};
+			format!("{key}: {transformer}")
+		} else if key == "page_blocklist" {
+			format!("{key}: &{}", literals(&blocklist(name, key, value)?))
+		} else if key == "listing_paths" {
+			format!("listing_mapping: {}", listing_paths(name, value)?)
+		} else {
+			return Err(Error::new(
+				name,
+				format!("`{key}` isn't a WPComics setting"),
+			));
+		};
+		let _ = writeln!(fields, "\t\t{field},");
+	}
+
+	let mut lib = header(name, imports);
+	lib.push('\n');
+	let (template_imports, modify_image_request) = match image_user_agent {
+		Some(user_agent) => (
+			"{\n\thelper,\n\ttemplate::{self, WPComicsSource},\n}",
+			format!(
+				"fn modify_image_request(request: Request) {{
+	template::modify_image_request(
+		String::from({}),
+		String::from({}),
+		request,
+	)
+}}",
+				literal(base_url),
+				literal(user_agent)
+			),
+		),
+		None => (
+			"{helper, template::WPComicsSource}",
+			String::from("fn modify_image_request(_request: Request) {}"),
+		),
+	};
+	let _ = writeln!(lib, "use wpcomics_template::{template_imports};");
+	let _ = write!(
+		lib,
+		"
+fn get_instance() -> WPComicsSource {{
+	WPComicsSource {{
+{fields}		..Default::default()
+	}}
+}}
+
+#[get_manga_list]
+fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {{
+	get_instance().get_manga_list(helper::genre_search_url({base_url}, filters, page)?)
+}}
+
+#[get_manga_listing]
+fn get_manga_listing(listing: Listing, page: i32) -> Result<MangaPageResult> {{
+	get_instance().get_manga_listing(listing, page)
+}}
+
+#[get_manga_details]
+fn get_manga_details(id: String) -> Result<Manga> {{
+	get_instance().get_manga_details(id)
+}}
+
+#[get_chapter_list]
+fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {{
+	get_instance().get_chapter_list(id)
+}}
+
+#[get_page_list]
+fn get_page_list(_manga_id: String, chapter_id: String) -> Result<Vec<Page>> {{
+	get_instance().get_page_list(chapter_id)
+}}
+
+#[modify_image_request]
+{modify_image_request}
+
+#[handle_url]
+pub fn handle_url(url: String) -> Result<DeepLink> {{
+	get_instance().handle_url(url)
+}}
+",
+		base_url = literal(base_url)
+	);
+	Ok(lib)
+}
+
+/// `[site.listing_paths]` maps listing names to the paths of their pages,
+/// for `listing_mapping`.
+fn listing_paths(name: &str, value: &Value) -> Result<String> {
+	let mut cases = String::new();
+	for (listing, path) in table(name, "listing_paths", value)? {
+		let path = string(name, listing, path)?;
+		let _ = writeln!(cases, "\t\t\t\t{} => {},", literal(listing), literal(path));
+	}
+	Ok(format!(
+		"|listing| {{\n\t\t\tString::from(match listing.as_str() {{\n{cases}\t\t\t\t_ => \"\",\n\t\t\t}})\n\t\t}}"
+	))
+}
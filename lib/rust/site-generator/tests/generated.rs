@@ -0,0 +1,290 @@
+//! The generated crates can't be built without the Aidoku toolchain, so
+//! they're checked against their template instead: every crate has to
+//! parse, and everything it uses from the template has to exist. A few
+//! sites are also compared with `tests/snapshots`, whose expected output
+//! matches the hand-written crates the manifests replaced.
+use site_generator::Site;
+use std::{
+	collections::{HashMap, HashSet},
+	fs,
+	path::{Path, PathBuf},
+};
+use syn::{
+	visit::{self, Visit},
+	Expr, ExprCall, ExprMethodCall, ExprPath, ExprStruct, FnArg, ImplItem, Item, ItemUse, Member,
+	Path as SynPath, UseTree,
+};
+
+fn repo() -> PathBuf {
+	Path::new(env!("CARGO_MANIFEST_DIR")).join("../../..")
+}
+
+fn parse(path: &Path) -> syn::File {
+	let text = fs::read_to_string(path).unwrap_or_else(|err| panic!("{}: {err}", path.display()));
+	syn::parse_file(&text).unwrap_or_else(|err| panic!("{}: {err}", path.display()))
+}
+
+fn is_public(vis: &syn::Visibility) -> bool {
+	matches!(vis, syn::Visibility::Public(_))
+}
+
+/// How many arguments a function takes, not counting `self`.
+fn arguments(sig: &syn::Signature) -> usize {
+	sig.inputs
+		.iter()
+		.filter(|arg| matches!(arg, FnArg::Typed(_)))
+		.count()
+}
+
+/// What a template crate exports: the fields of its settings struct, the
+/// public functions of each module and the public methods of the struct.
+struct Template {
+	name: String,
+	struct_name: String,
+	fields: HashSet<String>,
+	modules: HashMap<String, HashMap<String, usize>>,
+	methods: HashMap<String, usize>,
+}
+
+impl Template {
+	fn load(workspace: &str, struct_name: &str) -> Template {
+		let src = repo().join("src/rust").join(workspace).join("template/src");
+		let mut template = Template {
+			name: format!("{workspace}_template"),
+			struct_name: String::from(struct_name),
+			fields: HashSet::new(),
+			modules: HashMap::new(),
+			methods: HashMap::new(),
+		};
+		for entry in fs::read_dir(&src).unwrap() {
+			let path = entry.unwrap().path();
+			let module = path.file_stem().unwrap().to_string_lossy().into_owned();
+			let mut functions = HashMap::new();
+			for item in parse(&path).items {
+				match item {
+					Item::Fn(function) if is_public(&function.vis) => {
+						functions.insert(function.sig.ident.to_string(), arguments(&function.sig));
+					}
+					Item::Struct(item) if item.ident == struct_name => {
+						template.fields = item
+							.fields
+							.iter()
+							.filter_map(|field| field.ident.as_ref().map(ToString::to_string))
+							.collect();
+					}
+					Item::Impl(item) if item.trait_.is_none() => {
+						let is_source = matches!(&*item.self_ty, syn::Type::Path(ty)
+							if ty.path.segments.last().is_some_and(|s| s.ident == struct_name));
+						if !is_source {
+							continue;
+						}
+						for item in item.items {
+							if let ImplItem::Fn(method) = item {
+								if is_public(&method.vis) {
+									template.methods.insert(
+										method.sig.ident.to_string(),
+										arguments(&method.sig),
+									);
+								}
+							}
+						}
+					}
+					_ => {}
+				}
+			}
+			template.modules.insert(module, functions);
+		}
+		assert!(!template.fields.is_empty(), "{struct_name} not found");
+		template
+	}
+
+	fn function(&self, path: &SynPath) -> Option<(String, Option<usize>)> {
+		let segments: Vec<String> = path.segments.iter().map(|s| s.ident.to_string()).collect();
+		let (module, name) = match segments.as_slice() {
+			[module, name] => (module, name),
+			[krate, module, name] if *krate == self.name => (module, name),
+			_ => return None,
+		};
+		let functions = self.modules.get(module)?;
+		Some((format!("{module}::{name}"), functions.get(name).copied()))
+	}
+}
+
+struct Checker<'a> {
+	template: &'a Template,
+	errors: Vec<String>,
+}
+
+impl Checker<'_> {
+	fn check_call(&mut self, name: String, arguments: Option<usize>, given: usize) {
+		match arguments {
+			None => self.errors.push(format!("`{name}` doesn't exist")),
+			Some(count) if count != given => self
+				.errors
+				.push(format!("`{name}` takes {count} arguments, not {given}")),
+			Some(_) => {}
+		}
+	}
+
+	fn check_use(&mut self, prefix: &mut Vec<String>, tree: &UseTree) {
+		match tree {
+			UseTree::Path(path) => {
+				prefix.push(path.ident.to_string());
+				self.check_use(prefix, &path.tree);
+				prefix.pop();
+			}
+			UseTree::Name(name) => {
+				let name = name.ident.to_string();
+				if let [krate, module] = prefix.as_slice() {
+					if *krate == self.template.name
+						&& name != "self" && name != self.template.struct_name
+					{
+						let exists = self
+							.template
+							.modules
+							.get(module)
+							.is_some_and(|functions| functions.contains_key(&name));
+						if !exists {
+							self.errors
+								.push(format!("`{module}::{name}` doesn't exist"));
+						}
+					}
+				}
+			}
+			UseTree::Group(group) => {
+				for tree in &group.items {
+					self.check_use(prefix, tree);
+				}
+			}
+			UseTree::Rename(_) | UseTree::Glob(_) => {}
+		}
+	}
+}
+
+impl<'ast> Visit<'ast> for Checker<'_> {
+	fn visit_item_use(&mut self, item: &'ast ItemUse) {
+		self.check_use(&mut Vec::new(), &item.tree);
+	}
+
+	fn visit_expr_call(&mut self, call: &'ast ExprCall) {
+		if let Expr::Path(ExprPath { path, .. }) = &*call.func {
+			if let Some((name, arguments)) = self.template.function(path) {
+				self.check_call(name, arguments, call.args.len());
+			}
+		}
+		for arg in &call.args {
+			self.visit_expr(arg);
+		}
+	}
+
+	fn visit_expr_method_call(&mut self, call: &'ast ExprMethodCall) {
+		// methods of the settings struct, called on `get_instance()`
+		let on_source = matches!(&*call.receiver, Expr::Call(ExprCall { func, .. })
+			if matches!(&**func, Expr::Path(ExprPath { path, .. }) if path.is_ident("get_instance")));
+		if on_source {
+			let name = call.method.to_string();
+			let arguments = self.template.methods.get(&name).copied();
+			self.check_call(name, arguments, call.args.len());
+		}
+		visit::visit_expr_method_call(self, call);
+	}
+
+	fn visit_expr_path(&mut self, expr: &'ast ExprPath) {
+		// strategies passed as function pointers
+		if let Some((name, None)) = self.template.function(&expr.path) {
+			self.errors.push(format!("`{name}` doesn't exist"));
+		}
+	}
+
+	fn visit_expr_struct(&mut self, expr: &'ast ExprStruct) {
+		if expr
+			.path
+			.segments
+			.last()
+			.is_some_and(|s| s.ident == self.template.struct_name)
+		{
+			for field in &expr.fields {
+				if let Member::Named(ident) = &field.member {
+					if !self.template.fields.contains(&ident.to_string()) {
+						self.errors
+							.push(format!("{} has no `{ident}`", self.template.struct_name));
+					}
+				}
+			}
+		}
+		visit::visit_expr_struct(self, expr);
+	}
+}
+
+fn check_workspace(workspace: &str, struct_name: &str) {
+	let template = Template::load(workspace, struct_name);
+	let sites = site_generator::find_sites(&repo().join("src/rust").join(workspace)).unwrap();
+	assert!(!sites.is_empty(), "no {workspace} site manifests found");
+
+	for site in sites {
+		let generated = site_generator::generate(&site.name, &site.load().unwrap()).unwrap();
+		let file = syn::parse_file(&generated.lib_rs)
+			.unwrap_or_else(|err| panic!("{}: {err}\n{}", site.name, generated.lib_rs));
+		assert!(
+			toml::from_str::<toml::Table>(&generated.cargo_toml).is_ok(),
+			"{}: invalid Cargo.toml",
+			site.name
+		);
+
+		let mut checker = Checker {
+			template: &template,
+			errors: Vec::new(),
+		};
+		checker.visit_file(&file);
+		assert!(
+			checker.errors.is_empty(),
+			"{}: {}",
+			site.name,
+			checker.errors.join(", ")
+		);
+	}
+}
+
+#[test]
+fn generated_crates_match_the_madara_template() {
+	check_workspace("madara", "MadaraSiteData");
+}
+
+#[test]
+fn generated_crates_match_the_mangastream_template() {
+	check_workspace("mangastream", "MangaStreamSource");
+}
+
+#[test]
+fn generated_crates_match_the_mmrcms_template() {
+	check_workspace("mmrcms", "MMRCMSSource");
+}
+
+#[test]
+fn generated_crates_match_the_wpcomics_template() {
+	check_workspace("wpcomics", "WPComicsSource");
+}
+
+#[test]
+fn snapshots() {
+	let snapshots = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/snapshots");
+	for entry in fs::read_dir(&snapshots).unwrap() {
+		let dir = entry.unwrap().path();
+		let name = dir.file_name().unwrap().to_string_lossy().into_owned();
+		let site = Site {
+			name: name.clone(),
+			dir: dir.clone(),
+		};
+		let generated = site_generator::generate(&name, &site.load().unwrap()).unwrap();
+		for (file, contents) in [
+			("lib.rs", &generated.lib_rs),
+			("source.json", &generated.source_json),
+		] {
+			let expected = fs::read_to_string(dir.join(file)).unwrap_or_default();
+			assert!(
+				*contents == expected,
+				"{name}/{file} changed, expected:\n{expected}\ngenerated:\n{contents}"
+			);
+		}
+	}
+}
@@ -0,0 +1,64 @@
+//! Every site manifest in the repository has to generate a source.
+use std::{collections::HashSet, fs, path::Path};
+
+#[test]
+fn every_manifest_generates() {
+	let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../../src/rust");
+	let mut ids = HashSet::new();
+	let mut count = 0;
+
+	for workspace in fs::read_dir(root).unwrap() {
+		let workspace = workspace.unwrap().path();
+		for site in site_generator::find_sites(&workspace).unwrap() {
+			let manifest = site.load().unwrap_or_else(|err| panic!("{err}"));
+			site_generator::generate(&site.name, &manifest).unwrap_or_else(|err| panic!("{err}"));
+
+			assert!(
+				ids.insert(manifest.info.id.clone()),
+				"{}: `{}` is already used by another source",
+				site.name,
+				manifest.info.id
+			);
+			// `pt.` and `pt-br.` are both fine for `pt-br`
+			let language = manifest.info.lang.split('-').next().unwrap_or_default();
+			let id_language = manifest.info.id.split('.').next().unwrap_or_default();
+			assert!(
+				id_language.split('-').next() == Some(language),
+				"{}: the id should start with the language",
+				site.name
+			);
+			// the workspace's icon is packaged first, for sites without one
+			assert!(
+				site.res_dir().join("Icon.png").is_file()
+					|| workspace.join("res/Icon.png").is_file(),
+				"{}: res/Icon.png is missing",
+				site.name
+			);
+			assert!(
+				!workspace.join("sources").join(&site.name).exists(),
+				"{}: a hand-written source has the same name",
+				site.name
+			);
+			count += 1;
+		}
+	}
+
+	assert!(count > 0, "no site manifests found");
+}
+
+/// `generated/` is committed so that the workspaces build without running the
+/// generator first, which only works while it matches the manifests.
+#[test]
+fn generated_crates_are_up_to_date() {
+	let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../../src/rust");
+	for workspace in fs::read_dir(root).unwrap() {
+		let workspace = workspace.unwrap().path();
+		let stale =
+			site_generator::check_workspace(&workspace).unwrap_or_else(|err| panic!("{err}"));
+		assert!(
+			stale.is_empty(),
+			"{}: run site-generator to update {stale:?}",
+			workspace.display()
+		);
+	}
+}
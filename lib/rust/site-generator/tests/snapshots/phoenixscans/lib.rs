@@ -0,0 +1,73 @@
+// Generated from sites/phoenixscans/site.toml, don't edit.
+#![no_std]
+use aidoku::{
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	std::net::Request,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	Page,
+};
+
+use mmrcms_template::{helper, template::MMRCMSSource};
+
+fn get_instance() -> MMRCMSSource<'static> {
+	MMRCMSSource {
+		base_url: "https://phoenix-scans.pl",
+		category: "Kategorii",
+		category_mapper: |idx| helper::id_from_list(&["", "33", "34", "35", "36", "37", "38", "39", "40", "41", "42", "43", "44", "45", "46", "47", "48", "49", "50", "51", "52", "53", "56", "57", "58", "59", "60", "61", "62", "63", "64", "66", "67"], idx),
+		category_parser: |_, categories| {
+			helper::parse_categories(
+				categories,
+				&["Dojrzałe", "Hentai"],
+				&[],
+				&["Webtoon", "Manhwa", "Manhua"],
+			)
+		},
+		lang: "pl",
+		tags_mapper: |idx| helper::id_from_list(&["", "aktywne", "zakonczone", "porzucone", "zawieszone", "zlicencjonowane"], idx),
+		tags: "Tagów",
+		..Default::default()
+	}
+}
+
+#[get_manga_list]
+fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
+	get_instance().get_manga_list(filters, page)
+}
+
+#[get_manga_listing]
+fn get_manga_listing(listing: Listing, page: i32) -> Result<MangaPageResult> {
+	get_instance().get_manga_listing(listing, page)
+}
+
+#[get_manga_details]
+fn get_manga_details(id: String) -> Result<Manga> {
+	get_instance().get_manga_details(id)
+}
+
+#[get_chapter_list]
+fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
+	get_instance().get_chapter_list(id)
+}
+
+#[get_page_list]
+fn get_page_list(manga_id: String, id: String) -> Result<Vec<Page>> {
+	get_instance().get_page_list(manga_id, id)
+}
+
+#[modify_image_request]
+fn modify_image_request(request: Request) {
+	get_instance().modify_image_request(request)
+}
+
+#[handle_url]
+fn handle_url(url: String) -> Result<DeepLink> {
+	get_instance().handle_url(url)
+}
@@ -1,39 +1,49 @@
+// Generated from sites/reaperscansfr/site.toml, don't edit.
 #![no_std]
 use aidoku::{
-	error::Result, prelude::*, std::String, std::Vec, Chapter, DeepLink, Filter, Listing, Manga,
-	MangaPageResult, MangaStatus, Page,
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	MangaStatus,
+	Page,
 };
-use madara_template::template;
+
+use madara_template::{strategies, template};
 
 fn get_data() -> template::MadaraSiteData {
-	let data: template::MadaraSiteData = template::MadaraSiteData {
+	template::MadaraSiteData {
+		alt_ajax: true,
 		base_url: String::from("https://reaperscans.fr"),
 		lang: String::from("fr"),
+		popular: String::from("Populaire"),
 		source_path: String::from("serie"),
-		status_filter_ongoing: String::from("En cours"),
-		status_filter_completed: String::from("Terminé"),
+		status: |html| {
+			strategies::status_from_table(
+				html,
+				"div.post-content_item:contains(Statut) div.summary-content",
+				&[
+					("canceled", MangaStatus::Cancelled),
+					("dropped", MangaStatus::Cancelled),
+					("completed", MangaStatus::Completed),
+					("on hold", MangaStatus::Hiatus),
+					("ongoing", MangaStatus::Ongoing),
+				],
+			)
+		},
 		status_filter_cancelled: String::from("Annulé"),
+		status_filter_completed: String::from("Terminé"),
 		status_filter_on_hold: String::from("En pause"),
-		popular: String::from("Populaire"),
+		status_filter_ongoing: String::from("En cours"),
 		trending: String::from("Tendance"),
-		status: |html| {
-			let status_str = html
-				.select("div.post-content_item:contains(Statut) div.summary-content")
-				.text()
-				.read()
-				.to_lowercase();
-			match status_str.as_str() {
-				"ongoing" => MangaStatus::Ongoing,
-				"completed" => MangaStatus::Completed,
-				"canceled" | "dropped" => MangaStatus::Cancelled,
-				"on hold" => MangaStatus::Hiatus,
-				_ => MangaStatus::Unknown,
-			}
-		},
-		alt_ajax: true,
 		..Default::default()
-	};
-	data
+	}
 }
 
 #[get_manga_list]
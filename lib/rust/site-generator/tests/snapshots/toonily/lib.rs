@@ -1,23 +1,33 @@
+// Generated from sites/toonily/site.toml, don't edit.
 #![no_std]
 use aidoku::{
-	error::Result, prelude::*, std::net::Request, std::String, std::Vec, Chapter, DeepLink, Filter,
-	Listing, Manga, MangaPageResult, Page,
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	std::net::Request,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	Page,
 };
 
 use madara_template::template;
 
 fn get_data() -> template::MadaraSiteData {
-	let data: template::MadaraSiteData = template::MadaraSiteData {
+	template::MadaraSiteData {
+		alt_ajax: true,
 		base_url: String::from("https://toonily.com"),
-		source_path: String::from("webtoon"),
-		search_path: String::from("search/x/page"),
-		search_cookies: String::from("toonily-mature=1"),
 		post_type: String::from(""),
+		search_cookies: String::from("toonily-mature=1"),
+		search_path: String::from("search/x/page"),
 		search_selector: String::from("div.page-item-detail.manga"),
-		alt_ajax: true,
+		source_path: String::from("webtoon"),
 		..Default::default()
-	};
-	data
+	}
 }
 
 #[get_manga_list]
@@ -1,53 +1,48 @@
+// Generated from sites/xoxocomics/site.toml, don't edit.
 #![no_std]
-pub mod helper;
-use crate::helper::*;
 use aidoku::{
-	error::Result, prelude::*, std::net::Request, std::String, std::Vec, Chapter, DeepLink, Filter,
-	FilterType, Listing, Manga, MangaPageResult, Page,
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	std::net::Request,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	Page,
 };
+
 use wpcomics_template::{
-	helper::{get_tag_id, trunc_trailing_comic, urlencode},
+	helper,
 	template::{self, WPComicsSource},
 };
 
 fn get_instance() -> WPComicsSource {
 	WPComicsSource {
 		base_url: String::from("https://xoxocomics.com"),
-		listing_mapping: listing_map,
-
-		manga_details_title_transformer: trunc_trailing_comic,
-
-		chapter_skip_first: true,
 		chapter_date_selector: "div.col-xs-3",
+		chapter_skip_first: true,
+		listing_mapping: |listing| {
+			String::from(match listing.as_str() {
+				"Completed" => "status/completed",
+				"Hot" => "hot",
+				"Ongoing" => "status/ongoing",
+				"Popular" => "popular-comics",
+				_ => "",
+			})
+		},
+		manga_details_title_transformer: helper::trunc_trailing_comic,
 		manga_viewer_page_url_suffix: "/all",
-
 		..Default::default()
 	}
 }
 
 #[get_manga_list]
 fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
-	let mut title: String = String::new();
-	let mut genre: String = String::new();
-	for filter in filters {
-		match filter.kind {
-			FilterType::Title => {
-				title = urlencode(filter.value.as_string()?.read());
-			}
-			_ => match filter.name.as_str() {
-				"Genre" => {
-					genre = get_tag_id(filter.value.as_int().unwrap_or(0));
-				}
-				_ => continue,
-			},
-		}
-	}
-	get_instance().get_manga_list(get_search_url(
-		String::from("https://xoxocomics.com"),
-		title,
-		genre,
-		page,
-	))
+	get_instance().get_manga_list(helper::genre_search_url("https://xoxocomics.com", filters, page)?)
 }
 
 #[get_manga_listing]
@@ -0,0 +1,53 @@
+//! Manga and chapter ids that keep working when a site moves to a new
+//! domain.
+//!
+//! Pages on the site are stored by their path and resolved against the
+//! site's current URL, which users can override with a `sourceURL` setting.
+//! Ids saved as whole URLs, from before a source stored paths or on a host
+//! the site has since left, are moved to the current URL too.
+#![no_std]
+extern crate alloc;
+
+use alloc::{format, string::String};
+
+/// The site's URL: the user's setting if it isn't empty, else `default`,
+/// without a trailing slash.
+pub fn base_url(setting: Option<&str>, default: &str) -> String {
+	let url = setting.map(str::trim).unwrap_or_default();
+	let url = if url.is_empty() { default } else { url };
+	String::from(url.trim_end_matches('/'))
+}
+
+/// The id of the manga or chapter at `url`. Pages on the site, on the host
+/// of `base_url` or one of `hosts`, are stored by their path, anything else
+/// by its whole URL.
+pub fn get_id(url: &str, base_url: &str, hosts: &[&str]) -> String {
+	match split_host(url) {
+		Some((host, path)) if is_site_host(host, base_url, hosts) => String::from(path),
+		_ => String::from(url),
+	}
+}
+
+/// The URL of a manga or chapter id on the site at `base_url`.
+pub fn get_url(id: &str, base_url: &str, hosts: &[&str]) -> String {
+	match split_host(id) {
+		Some((host, path)) if is_site_host(host, base_url, hosts) => format!("{base_url}{path}"),
+		Some(_) => String::from(id),
+		None => format!("{base_url}{id}"),
+	}
+}
+
+/// The host and path of an absolute URL.
+fn split_host(url: &str) -> Option<(&str, &str)> {
+	let rest = url
+		.strip_prefix("https://")
+		.or_else(|| url.strip_prefix("http://"))?;
+	match rest.find('/') {
+		Some(i) => Some((&rest[..i], &rest[i..])),
+		None => Some((rest, "")),
+	}
+}
+
+fn is_site_host(host: &str, base_url: &str, hosts: &[&str]) -> bool {
+	hosts.contains(&host) || split_host(base_url).is_some_and(|(base_host, _)| base_host == host)
+}
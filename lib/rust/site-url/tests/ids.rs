@@ -0,0 +1,90 @@
+use site_url::{base_url, get_id, get_url};
+
+const BASE: &str = "https://chapmanganato.com";
+const HOSTS: &[&str] = &["chapmanganato.com", "readmanganato.com"];
+
+#[test]
+fn base_urls() {
+	let cases = [
+		(None, "https://chapmanganato.com"),
+		(Some(""), "https://chapmanganato.com"),
+		(Some("  "), "https://chapmanganato.com"),
+		(Some("https://natomanga.com/"), "https://natomanga.com"),
+		(Some(" https://natomanga.com "), "https://natomanga.com"),
+	];
+	for (setting, expected) in cases {
+		assert_eq!(base_url(setting, BASE), expected, "{setting:?}");
+	}
+}
+
+#[test]
+fn ids() {
+	let cases = [
+		("https://chapmanganato.com/manga-ab123", "/manga-ab123"),
+		(
+			"http://chapmanganato.com/manga-ab123/chapter-5",
+			"/manga-ab123/chapter-5",
+		),
+		("https://readmanganato.com/manga-ab123", "/manga-ab123"),
+		("https://chapmanganato.com", ""),
+		// other sites are kept whole
+		(
+			"https://manganato.com/manga-ab123",
+			"https://manganato.com/manga-ab123",
+		),
+		("/manga-ab123", "/manga-ab123"),
+	];
+	for (url, expected) in cases {
+		assert_eq!(get_id(url, BASE, HOSTS), expected, "{url}");
+	}
+}
+
+#[test]
+fn ids_on_overridden_base() {
+	let base = "https://natomanga.com";
+	assert_eq!(
+		get_id("https://natomanga.com/manga-ab123", base, HOSTS),
+		"/manga-ab123"
+	);
+	assert_eq!(
+		get_id("https://chapmanganato.com/manga-ab123", base, HOSTS),
+		"/manga-ab123"
+	);
+}
+
+#[test]
+fn urls() {
+	let cases = [
+		("/manga-ab123", "https://chapmanganato.com/manga-ab123"),
+		// saved before the site moved
+		(
+			"https://readmanganato.com/manga-ab123",
+			"https://chapmanganato.com/manga-ab123",
+		),
+		(
+			"http://chapmanganato.com/manga-ab123/chapter-5",
+			"https://chapmanganato.com/manga-ab123/chapter-5",
+		),
+		// other sites are left alone
+		(
+			"https://manganato.com/manga-ab123",
+			"https://manganato.com/manga-ab123",
+		),
+	];
+	for (id, expected) in cases {
+		assert_eq!(get_url(id, BASE, HOSTS), expected, "{id}");
+	}
+}
+
+#[test]
+fn urls_on_overridden_base() {
+	let base = "https://natomanga.com";
+	assert_eq!(
+		get_url("/manga-ab123", base, HOSTS),
+		"https://natomanga.com/manga-ab123"
+	);
+	assert_eq!(
+		get_url("https://readmanganato.com/manga-ab123", base, HOSTS),
+		"https://natomanga.com/manga-ab123"
+	);
+}
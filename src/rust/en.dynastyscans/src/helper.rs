@@ -1,9 +1,17 @@
 use aidoku::{
-	error::Result, prelude::*, std::html::Node, std::net::HttpMethod, std::net::Request,
-	std::ArrayRef, std::ObjectRef, std::String, std::Vec, Manga, MangaContentRating, MangaStatus,
-	MangaViewer,
+	error::Result, prelude::*, std::defaults::defaults_get, std::html::Node,
+	std::net::HttpMethod, std::net::Request, std::ArrayRef, std::ObjectRef, std::String, std::Vec,
+	Manga, MangaContentRating, MangaStatus, MangaViewer,
 };
 
+/// The tag types chapters are collected under, and the path of their pages.
+pub const COLLECTIONS: [(&str, &str); 4] = [
+	("Series", "series"),
+	("Anthology", "anthologies"),
+	("Doujin", "doujins"),
+	("Issue", "issues"),
+];
+
 pub fn urlencode(string: String) -> String {
 	let mut result: Vec<u8> = Vec::with_capacity(string.len() * 3);
 	let hex = "0123456789abcdef".as_bytes();
@@ -74,6 +82,122 @@ pub fn string_after(string: String, after: char) -> String {
 	result
 }
 
+/// Returns the id and name of the series, anthology, doujin or issue a
+/// chapter belongs to, from the chapter's tags.
+pub fn get_collection(tags: &ArrayRef) -> Result<Option<(String, String)>> {
+	for (tag_type, path) in COLLECTIONS {
+		let collections = find_in_array(tags, String::from(tag_type))?;
+		if let Some(collection) = collections.first() {
+			let id = format!(
+				"{}/{}",
+				path,
+				collection.get("permalink").as_string()?.read()
+			);
+			let name = collection.get("name").as_string()?.read();
+			return Ok(Some((id, name)));
+		}
+	}
+	Ok(None)
+}
+
+/// Returns the collections of a list of chapters, each one only once.
+pub fn get_chapter_collections(chapters: ArrayRef) -> Result<Vec<(String, String)>> {
+	let mut collections: Vec<(String, String)> = Vec::new();
+	for chapter in chapters {
+		let chapter_object = chapter.as_object()?;
+		// volume headers have no tags
+		let tags = match chapter_object.get("tags").as_array() {
+			Ok(tags) => tags,
+			Err(_) => continue,
+		};
+		if let Some(collection) = get_collection(&tags)? {
+			if !collections.iter().any(|(id, _)| *id == collection.0) {
+				collections.push(collection);
+			}
+		}
+	}
+	Ok(collections)
+}
+
+/// Turns ids and names into manga, only fetching the details when images
+/// aren't skipped.
+pub fn get_manga_list(collections: Vec<(String, String)>) -> Vec<Manga> {
+	let skip_images = match defaults_get("skipImages") {
+		Ok(bool) => bool.as_bool().unwrap_or(false),
+		Err(_) => false,
+	};
+
+	let mut manga_arr: Vec<Manga> = Vec::new();
+	for (id, title) in collections {
+		if skip_images {
+			manga_arr.push(Manga {
+				id,
+				title,
+				status: MangaStatus::Completed,
+				nsfw: MangaContentRating::Nsfw,
+				..Default::default()
+			});
+		} else if let Ok(manga) = get_manga_details(id) {
+			manga_arr.push(manga);
+		}
+	}
+	manga_arr
+}
+
+static mut CACHED_TAG: Option<(String, String)> = None;
+
+/// Returns the permalink of a tag from the site's tag index, whose pages are
+/// `{"tags": [{"A": [{"name": …, "permalink": …}, …]}, …], "total_pages": …}`,
+/// or `None` if no tag has that name.
+pub fn tag_permalink(name: &str) -> Option<String> {
+	if let Some((cached_name, permalink)) = unsafe { CACHED_TAG.clone() } {
+		if cached_name == name {
+			return Some(permalink);
+		}
+	}
+
+	let mut page = 1;
+	loop {
+		let url = format!(
+			"https://dynasty-scans.com/tags.json?page={}",
+			i32_to_string(page)
+		);
+		let json = Request::new(url.as_str(), HttpMethod::Get)
+			.json()
+			.ok()?
+			.as_object()
+			.ok()?;
+		for group in json.get("tags").as_array().ok()? {
+			let Ok(group) = group.as_object() else {
+				continue;
+			};
+			for tags in group.values() {
+				let Ok(tags) = tags.as_array() else {
+					continue;
+				};
+				for tag in tags {
+					let Ok(tag) = tag.as_object() else {
+						continue;
+					};
+					let Ok(tag_name) = tag.get("name").as_string() else {
+						continue;
+					};
+					if !tag_name.read().eq_ignore_ascii_case(name) {
+						continue;
+					}
+					let permalink = tag.get("permalink").as_string().ok()?.read();
+					unsafe { CACHED_TAG = Some((String::from(name), permalink.clone())) };
+					return Some(permalink);
+				}
+			}
+		}
+		if page >= json.get("total_pages").as_int().unwrap_or(0) as i32 {
+			return None;
+		}
+		page += 1;
+	}
+}
+
 pub fn get_manga_details(id: String) -> Result<Manga> {
 	let url = format!("https://dynasty-scans.com/{}.json", &id);
 	let json = Request::new(url.as_str(), HttpMethod::Get)
@@ -7,12 +7,17 @@ use aidoku::{
 
 mod helper;
 
+/// How many entries of the series, anthology, doujin and issue indexes are
+/// shown per page, as they list everything at once.
+const LISTING_PAGE_SIZE: usize = 20;
+
 #[get_manga_list]
 fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
 	let mut query = String::new();
 	let mut sort = String::new();
 	let mut included_tags: Vec<String> = Vec::new();
 	let mut excluded_tags: Vec<String> = Vec::new();
+	let mut included_names: Vec<String> = Vec::new();
 	let mut types: Vec<String> = Vec::new();
 	for filter in filters {
 		match filter.kind {
@@ -23,7 +28,10 @@ fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
 				if let Ok(tag_id) = filter.object.get("id").as_string() {
 					match filter.value.as_int().unwrap_or(-1) {
 						0 => excluded_tags.push(tag_id.read()),
-						1 => included_tags.push(tag_id.read()),
+						1 => {
+							included_tags.push(tag_id.read());
+							included_names.push(filter.name);
+						}
 						_ => continue,
 					}
 				}
@@ -52,6 +60,18 @@ fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
 		}
 	}
 
+	// A tag picked on its own opens its tag page, which also has the
+	// anthologies and doujins using it
+	if query.is_empty()
+		&& types.is_empty()
+		&& excluded_tags.is_empty()
+		&& included_names.len() == 1
+	{
+		if let Some(permalink) = helper::tag_permalink(&included_names[0]) {
+			return get_tag_page(&permalink, page);
+		}
+	}
+
 	let mut manga_arr: Vec<Manga> = Vec::new();
 	let mut total: i32 = 1;
 
@@ -137,18 +157,16 @@ fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
 #[get_manga_listing]
 fn get_manga_listing(listing: Listing, page: i32) -> Result<MangaPageResult> {
 	match listing.name.as_str() {
-		"Recently Added" => {}
-		_ => return get_manga_list(Vec::new(), page),
+		"Recently Added" => get_recently_added(page),
+		"Series" => get_collection_listing("series", page),
+		"Anthologies" => get_collection_listing("anthologies", page),
+		"Doujins" => get_collection_listing("doujins", page),
+		"Issues" => get_collection_listing("issues", page),
+		_ => get_manga_list(Vec::new(), page),
 	}
+}
 
-	let mut added_ids: Vec<String> = Vec::new();
-	let mut manga_arr: Vec<Manga> = Vec::new();
-
-	let skip_images = match defaults_get("skipImages") {
-		Ok(bool) => bool.as_bool().unwrap_or(false),
-		Err(_) => false,
-	};
-
+fn get_recently_added(page: i32) -> Result<MangaPageResult> {
 	let json = Request::new(
 		format!(
 			"https://dynasty-scans.com/chapters/added.json?page={}",
@@ -159,64 +177,79 @@ fn get_manga_listing(listing: Listing, page: i32) -> Result<MangaPageResult> {
 	)
 	.json()?
 	.as_object()?;
-	for chapter in json.get("chapters").as_array()? {
-		let chapter_object = chapter.as_object()?;
-		let result_object;
-		let tags = chapter_object.get("tags").as_array()?;
-		let series = helper::find_in_array(&tags, String::from("Series"))?;
-		if !series.is_empty() {
-			result_object = series[0].clone();
-		} else {
-			// anthology or doujin
-			let anthologies = helper::find_in_array(&tags, String::from("Anthology"))?;
-			if !anthologies.is_empty() {
-				result_object = anthologies[0].clone();
-			} else {
-				// has to be a doujin
-				let doujins = helper::find_in_array(&tags, String::from("Doujin"))?;
-				if !doujins.is_empty() {
-					result_object = doujins[0].clone();
-				} else {
-					// idek
-					continue;
-				}
-			}
-		}
-		let mut id = String::from(
-			match result_object.get("type").as_string()?.read().as_str() {
-				"Series" => "series",
-				"Anthology" => "anthologies",
-				"Doujin" => "doujins",
-				_ => continue,
-			},
-		);
-		id.push('/');
-		id.push_str(result_object.get("permalink").as_string()?.read().as_str());
-		if added_ids.contains(&id.clone()) {
+	let collections = helper::get_chapter_collections(json.get("chapters").as_array()?)?;
+	Ok(MangaPageResult {
+		manga: helper::get_manga_list(collections),
+		has_more: page < json.get("total_pages").as_int().unwrap_or(0) as i32,
+	})
+}
+
+fn get_tag_page(permalink: &str, page: i32) -> Result<MangaPageResult> {
+	let json = Request::new(
+		format!(
+			"https://dynasty-scans.com/tags/{}.json?page={}",
+			permalink,
+			&helper::i32_to_string(page)
+		)
+		.as_str(),
+		HttpMethod::Get,
+	)
+	.json()?
+	.as_object()?;
+	let collections = helper::get_chapter_collections(json.get("taggings").as_array()?)?;
+	Ok(MangaPageResult {
+		manga: helper::get_manga_list(collections),
+		has_more: page < json.get("total_pages").as_int().unwrap_or(0) as i32,
+	})
+}
+
+/// The index of one collection type, which is fetched again on the first page
+/// so refreshing picks up new entries, and reused for the pages after it.
+static mut CACHED_INDEX_PATH: Option<String> = None;
+static mut CACHED_INDEX: Option<Vec<(String, String)>> = None;
+
+fn cache_collection_index(path: &str, page: i32) -> Result<()> {
+	if page > 1 && unsafe { CACHED_INDEX_PATH.as_deref() } == Some(path) {
+		return Ok(());
+	}
+
+	let url = format!("https://dynasty-scans.com/{}", path);
+	let html = Request::new(url.as_str(), HttpMethod::Get).html()?;
+
+	let mut collections: Vec<(String, String)> = Vec::new();
+	for link in html.select("dl.tag-list dd a").array() {
+		let link_node = match link.as_node() {
+			Ok(node) => node,
+			Err(_) => continue,
+		};
+		let href = link_node.attr("href").read();
+		if href.len() < 2 {
 			continue;
 		}
-		added_ids.push(id.clone());
-		if skip_images {
-			let title = result_object.get("name").as_string()?.read();
-			manga_arr.push(Manga {
-				id: id.clone(),
-				title,
-				status: MangaStatus::Completed,
-				nsfw: MangaContentRating::Nsfw,
-				..Default::default()
-			});
-		} else {
-			match helper::get_manga_details(id.clone()) {
-				Ok(manga) => {
-					manga_arr.push(manga);
-				}
-				Err(_) => continue,
-			}
-		}
+		collections.push((String::from(&href[1..]), link_node.text().read()));
 	}
+
+	unsafe {
+		CACHED_INDEX = Some(collections);
+		CACHED_INDEX_PATH = Some(String::from(path));
+	};
+	Ok(())
+}
+
+fn get_collection_listing(path: &str, page: i32) -> Result<MangaPageResult> {
+	cache_collection_index(path, page)?;
+	let collections = unsafe { CACHED_INDEX.clone() }.unwrap_or_default();
+
+	let start = (page.max(1) as usize - 1) * LISTING_PAGE_SIZE;
+	let has_more = collections.len() > start + LISTING_PAGE_SIZE;
+	let collections = collections
+		.into_iter()
+		.skip(start)
+		.take(LISTING_PAGE_SIZE)
+		.collect();
 	Ok(MangaPageResult {
-		manga: manga_arr,
-		has_more: page < json.get("total_pages").as_int().unwrap_or(0) as i32,
+		manga: helper::get_manga_list(collections),
+		has_more,
 	})
 }
 
@@ -235,6 +268,11 @@ fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
 	let json_chapters = json.get("taggings").as_array()?;
 	let mut chapters = Vec::new();
 
+	// Anthology and issue chapters are separate stories, numbered by their
+	// position and credited to their authors
+	let is_anthology = id.starts_with("anthologies/") || id.starts_with("issues/");
+	let mut position: f32 = 0.0;
+
 	let mut volume_on: f32 = -1.0;
 	for chapter in json_chapters {
 		let chapter_obj = chapter.as_object()?;
@@ -258,8 +296,11 @@ fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
 			.get("released_on")
 			.as_date("YYYY-MM-dd", None, None)
 			.unwrap_or(0.0);
+		position += 1.0;
 		let chapter_num_pos = id.split('/').last().unwrap().len() + 3;
-		let chapter_num = if chapter_num_pos >= chapter_id.len() {
+		let chapter_num = if is_anthology {
+			position
+		} else if chapter_num_pos >= chapter_id.len() {
 			-1.0
 		} else {
 			helper::string_replace(String::from(&chapter_id[chapter_num_pos..]), '_', '.')
@@ -271,6 +312,14 @@ fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
 			Ok(tags) => tags,
 			Err(_) => ArrayRef::new(),
 		};
+		let authors = match helper::find_in_array(&tags, String::from("Author")) {
+			Ok(author_arr) => author_arr
+				.iter()
+				.filter_map(|author| author.get("name").as_string().ok())
+				.map(|author| author.read())
+				.collect::<Vec<String>>(),
+			Err(_) => Vec::new(),
+		};
 		let scanlator = match helper::find_in_array(&tags, String::from("Scanlator")) {
 			Ok(scanlator_arr) => {
 				if !scanlator_arr.is_empty() {
@@ -282,6 +331,12 @@ fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
 			Err(_) => String::new(),
 		};
 
+		let scanlator = if is_anthology && !authors.is_empty() {
+			authors.join(", ")
+		} else {
+			scanlator
+		};
+
 		chapters.push(Chapter {
 			id: chapter_id,
 			title,
@@ -1,4 +1,5 @@
 use aidoku::{
+	prelude::format,
 	std::{current_date, html::Node, net::Request, Vec},
 	std::{net::HttpMethod, String},
 	MangaStatus,
@@ -25,24 +26,9 @@ pub fn get_manga_id(raw_id: String) -> String {
 }
 
 pub fn get_date(time_ago: String) -> f64 {
-	let number = time_ago
-		.split_whitespace()
-		.next()
-		.unwrap_or("")
-		.parse::<f64>()
-		.unwrap_or(0.0);
-	match time_ago
-		.to_uppercase()
-		.split_whitespace()
-		.last()
-		.unwrap_or("")
-	{
-		"YEAR" | "YEARS" => current_date() - (number * 31556926.0),
-		"MONTH" | "MONTHS" => current_date() - (number * 2629743.0),
-		"WEEK" | "WEEKS" => current_date() - (number * 604800.0),
-		"YESTERDAY" | "DAYS" => current_date() - (number * 86400.0),
-		_ => current_date(),
-	}
+	// the site leaves out the "ago", as in "3 weeks"
+	let now = current_date();
+	relative_date::parse(&format!("{time_ago} ago"), now).unwrap_or(now)
 }
 
 pub fn manga_status(status: String) -> MangaStatus {
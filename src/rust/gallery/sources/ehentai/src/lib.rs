@@ -0,0 +1,261 @@
+#![no_std]
+extern crate alloc;
+
+mod parser;
+
+use aidoku::{
+	error::Result,
+	helpers::uri::QueryParameters,
+	prelude::*,
+	std::{defaults::defaults_get, net::Request, String, Vec},
+	Chapter, DeepLink, Filter, Manga, MangaPageResult, Page,
+};
+use alloc::string::ToString;
+use gallery_template::template::{GallerySource, Namespace, SearchQuery, Tag};
+
+const USER_AGENT: &str = "Mozilla/5.0 (iPhone; CPU iPhone OS 16_1_2 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/16.1 Mobile/15E148 Safari/604.1";
+
+/// The category checkboxes of the search form, as bits of `f_cats`.
+const CATEGORIES: [(&str, i32); 10] = [
+	("misc", 1),
+	("doujinshi", 2),
+	("manga", 4),
+	("artistcg", 8),
+	("gamecg", 16),
+	("imageset", 32),
+	("cosplay", 64),
+	("asianporn", 128),
+	("non-h", 256),
+	("western", 512),
+];
+const ALL_CATEGORIES: i32 = 1023;
+
+const PAGE_COUNTS: [&str; 5] = ["10", "20", "50", "100", "200"];
+
+/// The next-page link of the last search, and the page it leads to. Results
+/// are paged by the id of the last gallery, so pages can't be built from
+/// their number alone.
+static mut NEXT_PAGE: Option<(i32, String)> = None;
+
+fn get_setting(key: &str) -> Option<String> {
+	defaults_get(key)
+		.and_then(|value| value.as_string())
+		.map(|value| String::from(value.read().trim()))
+		.ok()
+		.filter(|value| !value.is_empty())
+}
+
+fn use_exhentai() -> bool {
+	defaults_get("exhentai")
+		.and_then(|value| value.as_bool())
+		.unwrap_or(false)
+}
+
+fn base_url() -> &'static str {
+	if use_exhentai() {
+		"https://exhentai.org"
+	} else {
+		"https://e-hentai.org"
+	}
+}
+
+/// `nw=1` skips the content warning. The account cookies are what lets
+/// ExHentai load at all.
+fn cookies() -> Option<String> {
+	let mut cookies = Vec::from([String::from("nw=1")]);
+	for (name, key) in [
+		("ipb_member_id", "ipbMemberId"),
+		("ipb_pass_hash", "ipbPassHash"),
+	] {
+		if let Some(value) = get_setting(key) {
+			cookies.push(format!("{}={}", name, value));
+		}
+	}
+	Some(cookies.join("; "))
+}
+
+fn get_instance() -> GallerySource {
+	GallerySource {
+		base_url: base_url(),
+		user_agent: Some(USER_AGENT),
+		cookies,
+
+		namespaces: &[
+			("artist", Namespace::Artist),
+			("cosplayer", Namespace::Artist),
+			("group", Namespace::Group),
+			("parody", Namespace::Parody),
+			("character", Namespace::Character),
+			("category", Namespace::Category),
+			("language", Namespace::Language),
+			("other", Namespace::Tag),
+		],
+		languages: &[
+			("en", "english"),
+			("ja", "japanese"),
+			("zh", "chinese"),
+			("ko", "korean"),
+			("es", "spanish"),
+			("fr", "french"),
+			("de", "german"),
+			("ru", "russian"),
+		],
+
+		search_request,
+		parse_search: |_, request, page| {
+			let (list, next_page) = parser::parse_search(request.html()?)?;
+			unsafe {
+				NEXT_PAGE = next_page.map(|url| (page + 1, url));
+			}
+			Ok(list)
+		},
+
+		gallery_url: |source, id| format!("{}/g/{}/", source.base_url, id),
+		gallery_id: |_, url| parser::gallery_id(url),
+		gallery: |source, id| {
+			let url = (source.gallery_url)(source, id);
+			parser::parse_gallery(source, String::from(id), source.get(&url).html()?)
+		},
+
+		..Default::default()
+	}
+}
+
+/// Tags are matched exactly, e.g. `female:"glasses$" -artist:"name$"`.
+/// Tag names from the filters already carry their namespace, like
+/// `female:glasses`.
+fn search_term(source: &GallerySource, tag: &Tag) -> String {
+	let (keyword, name) = match (tag.namespace, tag.name.split_once(':')) {
+		(Namespace::Tag, Some((keyword, name))) => (keyword, name),
+		(Namespace::Tag, None) => ("", tag.name.as_str()),
+		(namespace, _) => (source.keyword(namespace), tag.name.as_str()),
+	};
+	let prefix = if tag.excluded { "-" } else { "" };
+	if keyword.is_empty() {
+		format!("{}\"{}$\"", prefix, name)
+	} else {
+		format!("{}{}:\"{}$\"", prefix, keyword, name)
+	}
+}
+
+fn category_mask(query: &SearchQuery) -> i32 {
+	let bits = |excluded: bool| {
+		query
+			.tags
+			.iter()
+			.filter(|tag| tag.namespace == Namespace::Category && tag.excluded == excluded)
+			.filter_map(|tag| CATEGORIES.iter().find(|(name, _)| *name == tag.name))
+			.fold(0, |mask, (_, bit)| mask | bit)
+	};
+	let included = bits(false);
+	let excluded = bits(true);
+	// f_cats lists the categories to hide
+	if included != 0 {
+		excluded | (ALL_CATEGORIES & !included)
+	} else {
+		excluded
+	}
+}
+
+fn search_request(source: &GallerySource, query: &SearchQuery, page: i32) -> Request {
+	if page > 1 {
+		if let Some((next_page, url)) = unsafe { NEXT_PAGE.clone() } {
+			if next_page == page {
+				return source.get(&url);
+			}
+		}
+	}
+
+	let mut terms = Vec::new();
+	if let Some(title) = &query.title {
+		terms.push(title.clone());
+	}
+	for tag in &query.tags {
+		if tag.namespace != Namespace::Category {
+			terms.push(search_term(source, tag));
+		}
+	}
+	// Japanese galleries aren't tagged with a language
+	for language in query
+		.languages
+		.iter()
+		.filter(|language| **language != "japanese")
+	{
+		terms.push(search_term(
+			source,
+			&Tag::new(Namespace::Language, *language),
+		));
+	}
+
+	let mut params = QueryParameters::new();
+	params.set("f_search", Some(terms.join(" ").as_str()));
+	let mask = category_mask(query);
+	if mask != 0 {
+		params.set("f_cats", Some(mask.to_string().as_str()));
+	}
+	params.set("advsearch", Some("1"));
+	if let Some(rating) = query.select("Minimum Rating").filter(|index| *index > 0) {
+		params.set("f_srdd", Some((rating + 1).to_string().as_str()));
+	}
+	for (name, param) in [("Minimum Pages", "f_spf"), ("Maximum Pages", "f_spt")] {
+		let pages = query
+			.select(name)
+			.and_then(|index| PAGE_COUNTS.get((index as usize).checked_sub(1)?));
+		if let Some(pages) = pages {
+			params.set(param, Some(*pages));
+		}
+	}
+	if page > 1 {
+		params.set("page", Some((page - 1).to_string().as_str()));
+	}
+
+	source.get(&format!("{}/?{}", source.base_url, params))
+}
+
+#[get_manga_list]
+fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
+	get_instance().get_manga_list(filters, page)
+}
+
+#[get_manga_details]
+fn get_manga_details(id: String) -> Result<Manga> {
+	get_instance().get_manga_details(id)
+}
+
+#[get_chapter_list]
+fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
+	get_instance().get_chapter_list(id)
+}
+
+/// Every image sits on its own page, which only hands out the image once
+/// it's asked for, so they're all looked up here.
+#[get_page_list]
+fn get_page_list(_manga_id: String, chapter_id: String) -> Result<Vec<Page>> {
+	let source = get_instance();
+	let image_pages = parser::get_image_pages(&source, &chapter_id)?;
+	let api_url = if use_exhentai() {
+		"https://exhentai.org/api.php"
+	} else {
+		"https://api.e-hentai.org/api.php"
+	};
+
+	Ok(parser::get_image_urls(&source, api_url, &image_pages)?
+		.into_iter()
+		.enumerate()
+		.map(|(index, url)| Page {
+			index: index as i32,
+			url,
+			..Default::default()
+		})
+		.collect())
+}
+
+#[modify_image_request]
+fn modify_image_request(request: Request) {
+	get_instance().modify_image_request(request)
+}
+
+#[handle_url]
+pub fn handle_url(url: String) -> Result<DeepLink> {
+	get_instance().handle_url(url)
+}
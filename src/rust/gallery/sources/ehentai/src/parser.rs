@@ -0,0 +1,298 @@
+use aidoku::{
+	error::{AidokuError, AidokuErrorKind, NodeError, Result},
+	prelude::format,
+	std::{html::Node, net::HttpMethod, String, Vec},
+};
+use gallery_template::{
+	helper::{is_number, node_texts},
+	template::{Gallery, GalleryList, GallerySource, Namespace, Tag},
+};
+
+/// How many other H@H nodes to try when an image doesn't load.
+const MAX_RETRIES: usize = 2;
+
+fn parse_error<T>() -> Result<T> {
+	Err(AidokuError {
+		reason: AidokuErrorKind::NodeError(NodeError::ParseError),
+	})
+}
+
+/// `https://e-hentai.org/g/{gid}/{token}/` to `{gid}/{token}`.
+pub fn gallery_id(url: &str) -> Option<String> {
+	let (_, path) = url.split_once("/g/")?;
+	let mut segments = path
+		.split(['/', '?', '#'])
+		.filter(|segment| !segment.is_empty());
+	Some(format!("{}/{}", segments.next()?, segments.next()?))
+}
+
+/// Reads a search page, in either the list or the thumbnail layout, along
+/// with its next-page link.
+pub fn parse_search(html: Node) -> Result<(GalleryList, Option<String>)> {
+	let mut galleries = Vec::new();
+	for row in html
+		.select("table.itg > tbody > tr, div.itg > div.gl1t")
+		.array()
+	{
+		let row = row.as_node()?;
+		let title = row.select(".glink").first();
+		if title.text().read().is_empty() {
+			continue;
+		}
+		let Some(id) = gallery_id(&row.select("a:has(.glink)").attr("href").read()) else {
+			continue;
+		};
+
+		let image = row.select(".glthumb img, .gl1t img").first();
+		let mut cover = image.attr("data-src").read();
+		if cover.is_empty() {
+			cover = image.attr("src").read();
+		}
+
+		let mut tags = Vec::new();
+		let category = row.select(".cn, .cs").first().text().read();
+		if !category.is_empty() {
+			tags.push(Tag::new(Namespace::Category, category));
+		}
+
+		galleries.push(Gallery {
+			id,
+			title: String::from(title.text().read().trim()),
+			cover,
+			tags,
+			..Default::default()
+		});
+	}
+
+	let next_page = html.select("a#unext").attr("href").read();
+	let next_page = Some(next_page).filter(|url| !url.is_empty());
+	Ok((
+		GalleryList {
+			galleries,
+			has_more: next_page.is_some(),
+		},
+		next_page,
+	))
+}
+
+pub fn parse_gallery(source: &GallerySource, id: String, html: Node) -> Result<Gallery> {
+	let title = html.select("#gn").text().read();
+	if title.is_empty() {
+		return parse_error();
+	}
+
+	// background:transparent url(https://...) 0 0 no-repeat
+	let style = html.select("#gd1 > div").attr("style").read();
+	let cover = style
+		.split_once("url(")
+		.and_then(|(_, rest)| rest.split_once(')'))
+		.map(|(url, _)| String::from(url.trim_matches(['"', '\''])))
+		.unwrap_or_default();
+
+	let mut tags = Vec::new();
+	let category = html.select("#gdc div").text().read();
+	if !category.is_empty() {
+		tags.push(Tag::new(Namespace::Category, category));
+	}
+	for row in html.select("#taglist tr").array() {
+		let row = row.as_node()?;
+		let label = row.select("td.tc").text().read();
+		let keyword = label.trim().trim_end_matches(':');
+		for name in node_texts(row.select("td div a")) {
+			tags.push(match source.namespace(keyword) {
+				Some(namespace) => Tag::new(namespace, name),
+				// female, male and mixed stay part of the tag, as the site
+				// shows them
+				None => Tag::new(Namespace::Tag, format!("{}:{}", keyword, name)),
+			});
+		}
+	}
+
+	let mut uploaded = 0.0;
+	let mut length = 0;
+	for row in html.select("#gdd tr").array() {
+		let row = row.as_node()?;
+		let value = row.select("td.gdt2");
+		match row.select("td.gdt1").text().read().trim() {
+			"Posted:" => {
+				uploaded = value
+					.text()
+					.0
+					.as_date("yyyy-MM-dd HH:mm", None, Some("UTC"))
+					.unwrap_or(0.0)
+			}
+			// 24 pages
+			"Length:" => {
+				let text = value.text().read();
+				let count = text.split_whitespace().next().unwrap_or_default();
+				if is_number(count) {
+					length = count.parse().unwrap_or(0);
+				}
+			}
+			_ => continue,
+		}
+	}
+
+	Ok(Gallery {
+		url: (source.gallery_url)(source, &id),
+		id,
+		title,
+		cover,
+		tags,
+		description: html.select("#gj").text().read(),
+		pages: (0..length).map(|_| String::new()).collect(),
+		uploaded,
+		uploader: html.select("#gdn a").first().text().read(),
+		..Default::default()
+	})
+}
+
+/// Collects the `/s/` page of every image, following the thumbnail pages of
+/// the gallery.
+pub fn get_image_pages(source: &GallerySource, id: &str) -> Result<Vec<String>> {
+	let url = (source.gallery_url)(source, id);
+	let mut pages: Vec<String> = Vec::new();
+	let mut thumbnail_page = 0;
+	loop {
+		let html = source
+			.get(&format!("{}?p={}", url, thumbnail_page))
+			.html()?;
+		let links: Vec<String> = html
+			.select("#gdt a")
+			.array()
+			.filter_map(|link| link.as_node().ok())
+			.map(|link| link.attr("href").read())
+			.filter(|href| href.contains("/s/") && !pages.contains(href))
+			.collect();
+		if links.is_empty() {
+			break;
+		}
+		pages.extend(links);
+
+		// the last table cell links to the next thumbnail page, or is empty
+		let next = html.select("table.ptt td:last-child a").attr("href").read();
+		if next.is_empty() {
+			break;
+		}
+		thumbnail_page += 1;
+	}
+	Ok(pages)
+}
+
+/// `return nl('43577-463914')` to `43577-463914`.
+fn failover_key(html: &Node) -> Option<String> {
+	let onclick = html.select("#loadfail").attr("onclick").read();
+	let (_, rest) = onclick.split_once("nl('")?;
+	let (key, _) = rest.split_once('\'')?;
+	Some(String::from(key))
+}
+
+fn is_image(image: &str) -> bool {
+	!image.is_empty() && !image.ends_with("/509.gif")
+}
+
+/// Resolves an image page to the image on whichever H@H node it was handed
+/// to. A node that can't serve it leaves the image out or swaps in the 509
+/// placeholder, in which case the page is asked again with `nl` so another
+/// node gets picked, up to [`MAX_RETRIES`] times.
+///
+/// The retries only cover what the page says when it's loaded. An image
+/// that's handed out fine but then fails to download in the reader isn't
+/// retried, since the reader loads it by itself after the page list is
+/// returned.
+pub fn get_image_url(source: &GallerySource, page_url: &str) -> Result<String> {
+	let mut url = String::from(page_url);
+	for _ in 0..=MAX_RETRIES {
+		let html = source.get(&url).html()?;
+		let image = html.select("#img").attr("src").read();
+		if is_image(&image) {
+			return Ok(image);
+		}
+		let Some(key) = failover_key(&html) else {
+			break;
+		};
+		let separator = if page_url.contains('?') { '&' } else { '?' };
+		url = format!("{}{}nl={}", page_url, separator, key);
+	}
+	parse_error()
+}
+
+/// `https://e-hentai.org/s/{imgkey}/{gid}-{page}` to its image key, gallery
+/// id and page number.
+fn image_page_keys(url: &str) -> Option<(&str, &str, &str)> {
+	let (_, path) = url.split_once("/s/")?;
+	let (imgkey, rest) = path.split_once('/')?;
+	let (gid, page) = rest.split(['?', '#']).next()?.split_once('-')?;
+	Some((imgkey, gid, page))
+}
+
+/// `var showkey="…";` in the scripts of an image page.
+fn show_key(html: &Node) -> Option<String> {
+	let scripts = html.select("script").html().read();
+	let (_, rest) = scripts.split_once("showkey=\"")?;
+	let (key, _) = rest.split_once('"')?;
+	Some(String::from(key))
+}
+
+/// Resolves the image pages of a gallery. Only the first one is loaded as a
+/// page, for its image and the gallery's show key. The others are asked from
+/// the API's `showpage`, which answers with the image's markup instead of a
+/// whole page. Images the API doesn't hand out go through
+/// [`get_image_url`] and its retries.
+pub fn get_image_urls(
+	source: &GallerySource,
+	api_url: &str,
+	pages: &[String],
+) -> Result<Vec<String>> {
+	let Some(first_page) = pages.first() else {
+		return Ok(Vec::new());
+	};
+	let html = source.get(first_page).html()?;
+	let first_image = html.select("#img").attr("src").read();
+	let show_key = show_key(&html);
+
+	let mut images = Vec::with_capacity(pages.len());
+	images.push(if is_image(&first_image) {
+		first_image
+	} else {
+		get_image_url(source, first_page)?
+	});
+	for page_url in &pages[1..] {
+		let image = match (&show_key, image_page_keys(page_url)) {
+			(Some(show_key), Some(keys)) => show_page(source, api_url, show_key, keys),
+			_ => None,
+		};
+		images.push(match image {
+			Some(image) => image,
+			None => get_image_url(source, page_url)?,
+		});
+	}
+	Ok(images)
+}
+
+/// The image of one page from the API, if it handed one out.
+fn show_page(
+	source: &GallerySource,
+	api_url: &str,
+	show_key: &str,
+	(imgkey, gid, page): (&str, &str, &str),
+) -> Option<String> {
+	let body = format!(
+		r#"{{"method":"showpage","gid":{},"page":{},"imgkey":"{}","showkey":"{}"}}"#,
+		gid, page, imgkey, show_key
+	);
+	let json = source
+		.request(api_url, HttpMethod::Post)
+		.header("Content-Type", "application/json")
+		.body(body)
+		.json()
+		.ok()?
+		.as_object()
+		.ok()?;
+	// `i3` is the `<img id="img" src="…">` of the page
+	let markup = json.get("i3").as_string().ok()?.read();
+	let (_, rest) = markup.split_once("id=\"img\" src=\"")?;
+	let (image, _) = rest.split_once('"')?;
+	let image = image.replace("&amp;", "&");
+	is_image(&image).then_some(image)
+}
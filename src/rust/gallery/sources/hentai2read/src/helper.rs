@@ -6,7 +6,7 @@ pub const BASE_URL: &str = "https://hentai2read.com";
 pub fn create_advanced_search_body(
 	manga_title: Option<&str>,
 	artist_name: Option<&str>,
-	status: i64,
+	status: i32,
 	tag_search_mode: Option<&str>,
 	include_tags: Vec<i64>,
 	exclude_tags: Vec<i64>,
@@ -38,8 +38,7 @@ pub fn create_advanced_search_body(
 }
 
 pub fn genre_id_from_filter(str: &str) -> i64 {
-	let genre_id = str.split('_').last().unwrap_or_default();
-	genre_id.parse::<i64>().unwrap_or_default()
+	str.parse::<i64>().unwrap_or_default()
 }
 
 pub fn clean_cover_url(str: &str) -> String {
@@ -0,0 +1,109 @@
+#![no_std]
+
+mod helper;
+mod parser;
+extern crate alloc;
+
+use aidoku::{
+	error::Result,
+	prelude::{format, get_chapter_list, get_manga_details, get_manga_list, get_page_list},
+	std::{
+		net::{HttpMethod, Request},
+		*,
+	},
+	Chapter, Filter, Manga, MangaPageResult, Page,
+};
+use alloc::string::ToString;
+use gallery_template::template::{GallerySource, Namespace, QueryGrammar, SearchQuery};
+use helper::{change_page, create_advanced_search_body, genre_id_from_filter, BASE_URL};
+
+use parser::{parse_chapter_list, parse_manga, parse_page_list, parse_search};
+
+fn get_instance() -> GallerySource {
+	GallerySource {
+		base_url: BASE_URL,
+
+		// filter ids are {namespace}_{id}, and every namespace is searched by id
+		namespaces: &[
+			("category", Namespace::Category),
+			("tag", Namespace::Tag),
+			("doujin", Namespace::Parody),
+		],
+		grammar: QueryGrammar {
+			include_prefix: "",
+			exclude_prefix: "",
+			namespaced: false,
+		},
+
+		search_request,
+		parse_search: |_, request, _| parse_search(&request.html()?),
+
+		..Default::default()
+	}
+}
+
+/// The advanced search is a POST that only returns the first page. Later
+/// pages are plain links built from the first page's pagination.
+fn search_request(_: &GallerySource, query: &SearchQuery, page: i32) -> Request {
+	let url = format!("{BASE_URL}/hentai-list/advanced-search/");
+
+	let tag_search_mode = match query.select("Tag Search Mode") {
+		Some(1) => "or",
+		_ => "and",
+	};
+	let body_data = create_advanced_search_body(
+		query.title.as_deref(),
+		query.author.as_deref(),
+		query.select("Status").unwrap_or(0),
+		Some(tag_search_mode),
+		query
+			.included()
+			.map(|tag| genre_id_from_filter(&tag.name))
+			.collect(),
+		query
+			.excluded()
+			.map(|tag| genre_id_from_filter(&tag.name))
+			.collect(),
+	);
+	let request = Request::new(url, HttpMethod::Post).body(body_data);
+	if page <= 1 {
+		return request;
+	}
+
+	let next_page_url = request
+		.html()
+		.map(|html| {
+			html.select(".pagination a#js-linkNext")
+				.attr("href")
+				.to_string()
+		})
+		.unwrap_or_default();
+	Request::new(change_page(&next_page_url, page), HttpMethod::Get)
+}
+
+#[get_manga_list]
+fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
+	get_instance().get_manga_list(filters, page)
+}
+
+#[get_manga_details]
+fn get_manga_details(id: String) -> Result<Manga> {
+	let manga_url = format!("{BASE_URL}/{id}");
+
+	let html = Request::new(manga_url, HttpMethod::Get).html()?;
+	parse_manga(id, html)
+}
+
+#[get_chapter_list]
+fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
+	let url = format!("{BASE_URL}/{id}");
+	let html = Request::new(url, HttpMethod::Get).html()?;
+	parse_chapter_list(html)
+}
+
+#[get_page_list]
+fn get_page_list(id: String, chapter: String) -> Result<Vec<Page>> {
+	let url = format!("{BASE_URL}/{id}/{chapter}/1");
+	let html = Request::new(url, HttpMethod::Get).html()?;
+	parse_page_list(html)
+}
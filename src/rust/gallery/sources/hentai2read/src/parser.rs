@@ -1,17 +1,18 @@
 use aidoku::error::Result;
-use aidoku::prelude::{format, println};
+use aidoku::prelude::format;
 use aidoku::std::json;
 use aidoku::{std::html::Node, Manga};
 use aidoku::{Chapter, MangaContentRating, MangaStatus, MangaViewer, Page};
 use alloc::string::ToString;
 use alloc::{string::String, vec::Vec};
+use gallery_template::template::{Gallery, GalleryList};
 
 use crate::helper::{
 	between_string, clean_cover_url, get_manga_id, parse_chapter_number, BASE_URL,
 };
 
-pub fn parse_search(html: &Node) -> Vec<Manga> {
-	let mut manga_arr: Vec<Manga> = Vec::new();
+pub fn parse_search(html: &Node) -> Result<GalleryList> {
+	let mut galleries: Vec<Gallery> = Vec::new();
 	for result in html
 		.select(".block-content.row .book-grid-item-container")
 		.array()
@@ -29,19 +30,25 @@ pub fn parse_search(html: &Node) -> Vec<Manga> {
 		let manga_id = get_manga_id(&manga_url);
 		let title = manga_url_node.select("span.title-text").text().to_string();
 
-		manga_arr.push(Manga {
+		galleries.push(Gallery {
 			id: manga_id,
 			cover,
 			title,
 			url: manga_url,
-			status: MangaStatus::Completed,
-			nsfw: MangaContentRating::Nsfw,
-			viewer: MangaViewer::Rtl,
 			..Default::default()
 		});
 	}
 
-	manga_arr
+	let has_more = !html
+		.select(".pagination a#js-linkNext")
+		.html()
+		.read()
+		.is_empty();
+
+	Ok(GalleryList {
+		galleries,
+		has_more,
+	})
 }
 
 pub fn parse_manga(id: String, html: Node) -> Result<Manga> {
@@ -56,7 +63,6 @@ pub fn parse_manga(id: String, html: Node) -> Result<Manga> {
 	let cover = html.select(".img-container a img").attr("src").to_string();
 
 	let url = format!("{BASE_URL}/{id}/");
-	println!("url: {}", url);
 
 	for item in html
 		.select(".list.list-simple-mini li.text-primary")
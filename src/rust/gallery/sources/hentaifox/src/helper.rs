@@ -0,0 +1,75 @@
+use aidoku::{helpers::uri::QueryParameters, prelude::*, std::String};
+use alloc::string::ToString;
+use gallery_template::template::SearchQuery;
+
+pub const USER_AGENT: &str = "Mozilla/5.0 (iPhone; CPU iPhone OS 16_1_2 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/16.0 Mobile/15E148 Safari/604.1";
+pub const BASE_URL: &str = "https://hentaifox.com";
+
+// numbers only from string as i32
+pub fn numbers_only_from_string(string: String) -> i32 {
+	let mut result: i32 = 0;
+	let mut index = 0;
+	let mut negative = false;
+	let mut length = string.len();
+	if length == 0 {
+		return result;
+	}
+	if string.as_bytes()[0] == b'-' {
+		negative = true;
+		index = 1;
+		length -= 1;
+	}
+	for i in index..length {
+		let curr = string.as_bytes()[i];
+		if !curr.is_ascii_digit() {
+			break;
+		}
+		result = result * 10 + (curr - b'0') as i32;
+	}
+	if negative {
+		result *= -1;
+	}
+	result
+}
+
+pub fn get_gallery_id(path: String) -> i32 {
+	let parts = path.split('/').nth(2).unwrap_or("");
+	numbers_only_from_string(String::from(parts))
+}
+
+/// Text searches go through `/search`, otherwise the site can only browse
+/// one tag at a time.
+pub fn build_search_url(query: &SearchQuery, page: i32) -> String {
+	let popular = query.sort == "popular";
+	if let Some(term) = &query.title {
+		let mut params = QueryParameters::new();
+		params.set("q", Some(term.as_str()));
+		params.set("page", Some(page.to_string().as_str()));
+		if popular {
+			params.set("sort", Some("popular"));
+		}
+
+		format!("{BASE_URL}/search?{params}")
+	} else {
+		let mut path = String::new();
+		if let Some(tag) = query.included().next() {
+			if popular {
+				path = format!("/tag/{}/popular/pag/{}", tag.name, page);
+			} else {
+				path = format!("/tag/{}/pag/{}", tag.name, page);
+			}
+		} else {
+			if popular {
+				path.push_str("/popular");
+			}
+			if page > 1 {
+				if page > 2 {
+					path.push_str(format!("/pag/{}", page).as_str());
+				} else {
+					path.push_str(format!("/page/{}", page).as_str());
+				}
+			}
+		}
+		format!("{BASE_URL}{path}")
+	}
+}
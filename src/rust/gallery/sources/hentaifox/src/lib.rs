@@ -0,0 +1,71 @@
+#![no_std]
+
+mod helper;
+mod parser;
+
+extern crate alloc;
+
+use aidoku::{
+	error::Result, prelude::*, std::net::Request, std::String, std::Vec, Chapter, DeepLink, Filter,
+	Manga, MangaPageResult, Page,
+};
+use gallery_template::template::GallerySource;
+use helper::{BASE_URL, USER_AGENT};
+
+fn get_instance() -> GallerySource {
+	GallerySource {
+		base_url: BASE_URL,
+		user_agent: Some(USER_AGENT),
+
+		sorts: &["latest", "popular"],
+
+		search_request: |source, query, page| source.get(&helper::build_search_url(query, page)),
+		parse_search: |_, request, page| parser::parse_search(request.html()?, page),
+
+		gallery: |source, id| {
+			let url = (source.gallery_url)(source, id);
+			parser::parse_gallery(source, String::from(id), source.get(&url).html()?)
+		},
+
+		// https://i2.hentaifox.com/{load_dir}/{load_id}/{page}.jpg
+		page_url: |gallery, index, _| {
+			format!(
+				"https://i2.hentaifox.com/{}/{}.jpg",
+				gallery.media_id,
+				index + 1
+			)
+		},
+
+		..Default::default()
+	}
+}
+
+#[get_manga_list]
+fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
+	get_instance().get_manga_list(filters, page)
+}
+
+#[get_manga_details]
+fn get_manga_details(id: String) -> Result<Manga> {
+	get_instance().get_manga_details(id)
+}
+
+#[get_chapter_list]
+fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
+	get_instance().get_chapter_list(id)
+}
+
+#[get_page_list]
+fn get_page_list(_manga_id: String, chapter_id: String) -> Result<Vec<Page>> {
+	get_instance().get_page_list(chapter_id)
+}
+
+#[modify_image_request]
+fn modify_image_request(request: Request) {
+	get_instance().modify_image_request(request)
+}
+
+#[handle_url]
+pub fn handle_url(url: String) -> Result<DeepLink> {
+	get_instance().handle_url(url)
+}
@@ -0,0 +1,124 @@
+use aidoku::{
+	error::Result,
+	prelude::format,
+	std::{html::Node, String, Vec},
+};
+use alloc::{string::ToString, vec};
+use gallery_template::{
+	helper::strip_count,
+	template::{Gallery, GalleryList, GallerySource, Tag},
+};
+
+use crate::helper::{get_gallery_id, numbers_only_from_string};
+
+pub fn parse_search(html: Node, page: i32) -> Result<GalleryList> {
+	let mut galleries = Vec::new();
+	let mut total: i32 = 1;
+
+	for result in html.select(".lc_galleries .thumb").array() {
+		let Ok(res_node) = result.as_node() else {
+			continue;
+		};
+		let a_tag = res_node.select(".caption .g_title a");
+		let id = get_gallery_id(a_tag.attr("href").read());
+
+		galleries.push(Gallery {
+			id: id.to_string(),
+			title: a_tag.text().read(),
+			cover: res_node.select(".inner_thumb img").attr("src").read(),
+			..Default::default()
+		})
+	}
+
+	for paging_res in html.select(".pagination .page-item a").array() {
+		let Ok(paging) = paging_res.as_node() else {
+			continue;
+		};
+		let href = paging.attr("href").read();
+		if href == "#" {
+			continue;
+		}
+		let href_parts = href.split('/').collect::<Vec<&str>>();
+
+		// get second last part in href
+		let last_str = String::from(href_parts[href_parts.len() - 1]);
+
+		if last_str.starts_with("?q=") {
+			if !last_str.contains("&page=") {
+				continue;
+			}
+			let last_str_parts = last_str.split('&').collect::<Vec<&str>>();
+
+			let page_str = String::from(last_str_parts[1]);
+
+			let page_str_parts = page_str.split('=').collect::<Vec<&str>>();
+			let page_num_str = String::from(page_str_parts[1]);
+			let page_num = numbers_only_from_string(page_num_str);
+
+			if page_num > total {
+				total = page_num;
+			}
+
+			continue;
+		}
+
+		if href_parts.len() < 2 {
+			continue;
+		}
+		let num_str = String::from(href_parts[href_parts.len() - 2]);
+
+		let num = numbers_only_from_string(num_str);
+
+		if num > total {
+			total = num;
+		}
+	}
+
+	Ok(GalleryList {
+		galleries,
+		has_more: page < total,
+	})
+}
+
+pub fn parse_gallery(source: &GallerySource, id: String, html: Node) -> Result<Gallery> {
+	let info = html.select(".gallery_top .gallery_right");
+
+	// every namespace is a list of links like /artist/{slug}/
+	let mut tags = Vec::new();
+	for link in info.select("ul li a").array() {
+		let Ok(link) = link.as_node() else {
+			continue;
+		};
+		let href = link.attr("href").read();
+		let Some(namespace) = href
+			.split('/')
+			.find(|segment| !segment.is_empty())
+			.and_then(|keyword| source.namespace(keyword))
+		else {
+			continue;
+		};
+		let name = strip_count(&link.text().read());
+		if !name.is_empty() {
+			tags.push(Tag::new(namespace, name));
+		}
+	}
+
+	let page_count = numbers_only_from_string(html.select("#load_pages").attr("value").read());
+
+	Ok(Gallery {
+		id,
+		title: info.select("h1").text().read(),
+		cover: html
+			.select(".gallery_top .gallery_left img")
+			.attr("src")
+			.read(),
+		media_id: format!(
+			"{}/{}",
+			html.select("#load_dir").attr("value").read(),
+			html.select("#load_id").attr("value").read()
+		),
+		tags,
+		pages: vec![String::new(); page_count.max(0) as usize],
+		..Default::default()
+	})
+}
@@ -0,0 +1,126 @@
+#![no_std]
+extern crate alloc;
+
+use aidoku::{
+	error::Result, prelude::*, std::net::Request, std::String, std::ValueRef, std::Vec, Chapter,
+	Filter, MangaPageResult, MangaViewer, Page,
+};
+use gallery_template::template::{Gallery, GallerySource, Namespace, QueryGrammar, Tag};
+
+mod parser;
+
+const BASE_URL: &str = "https://nana.my.id";
+
+fn get_instance() -> GallerySource {
+	GallerySource {
+		base_url: BASE_URL,
+		user_agent: Some("Aidoku"),
+		viewer: MangaViewer::Scroll,
+
+		grammar: QueryGrammar {
+			include_prefix: "+",
+			exclude_prefix: "-",
+			namespaced: false,
+		},
+
+		search_request: |source, query, page| {
+			source.get(&parser::get_search_url(source, query, page))
+		},
+		parse_search: |_, request, _| parser::parse_search(request.html()?),
+
+		gallery_url: |_, id| format!("{}/reader/{}", BASE_URL, id),
+		gallery: |source, id| {
+			let url = (source.gallery_url)(source, id);
+			parser::parse_gallery(String::from(id), source.get(&url).html()?)
+		},
+		reader: Some(|source, id| {
+			let url = format!("{}/api/archives/{}/extractthumbnails", BASE_URL, id);
+			parser::parse_reader(String::from(id), source.get(&url).json()?.as_object()?)
+		}),
+
+		// the api lists thumbnails as ./image/thumbnails/..., which are also
+		// served full-size from /image/pages/...
+		page_url: |_, _, thumbnail| {
+			let path = thumbnail.replace("thumbnails", "pages");
+			format!("{}{}", BASE_URL, path.strip_prefix('.').unwrap_or(&path))
+		},
+
+		..Default::default()
+	}
+}
+
+#[get_manga_list]
+fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
+	get_instance().get_manga_list(filters, page)
+}
+
+/// The reader page has no tags or author, so the ones from the search
+/// results are kept when the app passes them along.
+///
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn get_manga_details(manga_rid: i32) -> i32 {
+	let manga = ValueRef::new(manga_rid).as_object().unwrap();
+	let Ok(id) = manga.get("id").as_string() else {
+		return -1;
+	};
+	let source = get_instance();
+
+	let (Ok(title), Ok(cover)) = (
+		manga.get("title").as_string(),
+		manga.get("cover").as_string(),
+	) else {
+		return match source.get_manga_details(id.read()) {
+			Ok(manga) => manga.create(),
+			Err(_) => -1,
+		};
+	};
+
+	let mut tags = Vec::new();
+	if let Ok(author) = manga.get("author").as_string() {
+		tags.push(Tag::new(Namespace::Artist, author.read()));
+	}
+	if let Ok(categories) = manga.get("tags").as_array() {
+		for tag in categories {
+			let Ok(tag) = tag.as_string() else {
+				return -1;
+			};
+			tags.push(Tag::new(Namespace::Tag, tag.read()));
+		}
+	}
+
+	source
+		.manga(Gallery {
+			id: id.read(),
+			title: title.read(),
+			cover: cover.read(),
+			tags,
+			url: manga
+				.get("url")
+				.as_string()
+				.map(|url| url.read())
+				.unwrap_or_default(),
+			..Default::default()
+		})
+		.create()
+}
+
+#[get_chapter_list]
+fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
+	// the reader page is only needed for the pages, so skip fetching it here
+	let source = get_instance();
+	Ok(Vec::from([source.chapter(&Gallery {
+		id,
+		..Default::default()
+	})]))
+}
+
+#[get_page_list]
+fn get_page_list(_manga_id: String, chapter_id: String) -> Result<Vec<Page>> {
+	get_instance().get_page_list(chapter_id)
+}
+
+#[modify_image_request]
+pub fn modify_image_request(request: Request) {
+	get_instance().modify_image_request(request)
+}
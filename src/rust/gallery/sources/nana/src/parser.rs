@@ -0,0 +1,114 @@
+use aidoku::{
+	error::Result,
+	helpers::uri::encode_uri_component,
+	prelude::*,
+	std::html::Node,
+	std::{ObjectRef, String, Vec},
+};
+use gallery_template::template::{
+	Gallery, GalleryList, GallerySource, Namespace, SearchQuery, Tag,
+};
+
+const BASE_URL: &str = "https://nana.my.id";
+
+fn absolute_url(url: String) -> String {
+	if url.starts_with('/') {
+		format!("{}{}", BASE_URL, url)
+	} else {
+		url
+	}
+}
+
+pub fn parse_search(html: Node) -> Result<GalleryList> {
+	let mut galleries = Vec::new();
+	for page in html.select("#thumbs_container > .id1").array() {
+		if let Ok(obj) = page.as_node() {
+			let a = obj.select(".id3 > a");
+			let id: String = a
+				.attr("href")
+				.read()
+				.split('/')
+				.last()
+				.unwrap_or_default()
+				.into();
+
+			let title = a.attr("title").read();
+			let author = a
+				.select("img")
+				.attr("alt")
+				.read()
+				.replace(&format!("{} by ", title), "");
+			let cover = absolute_url(a.select("img").attr("src").read());
+
+			let mut tags = Vec::new();
+			if !author.is_empty() {
+				tags.push(Tag::new(Namespace::Artist, author));
+			}
+			for tag in obj.select(".id4 > .tags > span").array() {
+				if let Ok(tag) = tag.as_node() {
+					tags.push(Tag::new(Namespace::Tag, tag.text().read()));
+				}
+			}
+
+			if !id.is_empty() && !title.is_empty() && !cover.is_empty() {
+				galleries.push(Gallery {
+					id,
+					title,
+					cover,
+					tags,
+					..Default::default()
+				});
+			}
+		}
+	}
+
+	Ok(GalleryList {
+		galleries,
+		has_more: !html
+			.select("a.paginate_button.current + a.paginate_button")
+			.array()
+			.is_empty(),
+	})
+}
+
+pub fn parse_gallery(id: String, html: Node) -> Result<Gallery> {
+	let title = String::from(
+		html.select("#archivePagesOverlay .spanh3reader")
+			.text()
+			.read()
+			.trim(),
+	);
+	let cover = absolute_url(html.select("a#display img").attr("src").read())
+		.replace("/image/page", "/image/thumbnails");
+
+	Ok(Gallery {
+		id,
+		title,
+		cover,
+		..Default::default()
+	})
+}
+
+pub fn parse_reader(id: String, obj: ObjectRef) -> Result<Gallery> {
+	let mut pages = Vec::new();
+	for page in obj.get("pages").as_array()? {
+		pages.push(page.as_string()?.read());
+	}
+
+	Ok(Gallery {
+		id,
+		pages,
+		..Default::default()
+	})
+}
+
+// the site only sorts by date, so only the direction is taken from the filter
+pub fn get_search_url(source: &GallerySource, query: &SearchQuery, page: i32) -> String {
+	format!(
+		"{}/?p={}&sort={}&q={}",
+		BASE_URL,
+		page,
+		if query.ascending { "asc" } else { "desc" },
+		encode_uri_component(query.to_query_string(source))
+	)
+}
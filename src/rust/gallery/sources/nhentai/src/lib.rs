@@ -0,0 +1,193 @@
+#![no_std]
+extern crate alloc;
+
+mod parser;
+
+use aidoku::{
+	error::Result,
+	helpers::uri::encode_uri_component,
+	prelude::*,
+	std::{
+		defaults::{defaults_get, defaults_set},
+		net::Request,
+		String, StringRef, Vec,
+	},
+	Chapter, DeepLink, Filter, Listing, Manga, MangaPageResult, Page,
+};
+use gallery_template::template::{GallerySource, SearchQuery};
+
+const BASE_URL: &str = "https://nhentai.net";
+const USER_AGENT: &str = "Mozilla/5.0 (iPhone; CPU iPhone OS 16_1_2 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/16.1 Mobile/15E148 Safari/604.1";
+
+const SESSION_KEY: &str = "sessionId";
+const CSRF_KEY: &str = "csrfToken";
+
+fn get_instance() -> GallerySource {
+	GallerySource {
+		base_url: BASE_URL,
+		user_agent: Some(USER_AGENT),
+
+		languages: &[("en", "english"), ("ja", "japanese"), ("zh", "chinese")],
+		sorts: &["date", "popular-today", "popular-week", "popular"],
+		listings: &[
+			("Latest", "date"),
+			("Popular - Today", "popular-today"),
+			("Popular - This Week", "popular-week"),
+			("Popular - All Time", "popular"),
+		],
+		id_search: true,
+
+		search_request: |source, query, page| {
+			source.get(&format!(
+				"{}/api/galleries/search?query={}&page={}&sort={}",
+				BASE_URL,
+				encode_uri_component(search_query(source, query)),
+				page,
+				query.sort
+			))
+		},
+		parse_search: |source, request, page| parser::parse_search(source, request.json()?, page),
+
+		gallery_url: |_, id| format!("{}/g/{}", BASE_URL, id),
+		gallery: |source, id| {
+			let url = format!("{}/api/gallery/{}", BASE_URL, id);
+			parser::parse_gallery(source, source.get(&url).json()?.as_object()?)
+		},
+
+		cover_url: |gallery| {
+			format!(
+				"https://t.nhentai.net/galleries/{}/cover.{}",
+				gallery.media_id, gallery.cover
+			)
+		},
+		page_url: |gallery, index, extension| {
+			format!(
+				"https://i.nhentai.net/galleries/{}/{}.{}",
+				gallery.media_id,
+				index + 1,
+				extension
+			)
+		},
+
+		..Default::default()
+	}
+}
+
+const PAGE_COUNTS: [&str; 5] = ["10", "20", "50", "100", "200"];
+const UPLOADED: [&str; 4] = ["1d", "1w", "1m", "1y"];
+
+/// Adds the page count and upload date ranges to the tag query, e.g.
+/// `tag:"glasses" pages:>20 uploaded:<1w`.
+fn search_query(source: &GallerySource, query: &SearchQuery) -> String {
+	let mut terms = Vec::from([query.to_query_string(source)]);
+	let option = |name: &str, options: &[&'static str]| {
+		let index = query.select(name)? as usize;
+		options.get(index.checked_sub(1)?).copied()
+	};
+	if let Some(pages) = option("Minimum Pages", &PAGE_COUNTS[..]) {
+		terms.push(format!("pages:>{}", pages));
+	}
+	if let Some(pages) = option("Maximum Pages", &PAGE_COUNTS[..]) {
+		terms.push(format!("pages:<{}", pages));
+	}
+	if let Some(uploaded) = option("Uploaded", &UPLOADED[..]) {
+		terms.push(format!("uploaded:<{}", uploaded));
+	}
+	terms.retain(|term| !term.is_empty());
+	terms.join(" ")
+}
+
+fn get_setting(key: &str) -> Option<String> {
+	defaults_get(key)
+		.and_then(|value| value.as_string())
+		.map(|value| String::from(value.read().trim()))
+		.ok()
+		.filter(|value| !value.is_empty())
+}
+
+/// The session copied from a signed-in browser. The `Cookie` header replaces
+/// the cookies the app has for the site, `cf_clearance` included, so only the
+/// favorites requests, which need the session, send it.
+fn cookies() -> Option<String> {
+	let cookies = [("sessionid", SESSION_KEY), ("csrftoken", CSRF_KEY)]
+		.into_iter()
+		.filter_map(|(name, key)| get_setting(key).map(|value| format!("{}={}", name, value)))
+		.collect::<Vec<_>>();
+	if cookies.is_empty() {
+		None
+	} else {
+		Some(cookies.join("; "))
+	}
+}
+
+/// Favorites are searched with the same query as everything else, so the
+/// language setting applies to them too.
+fn get_favorites(page: i32) -> Result<MangaPageResult> {
+	if get_setting(SESSION_KEY).is_none() {
+		return Ok(MangaPageResult {
+			manga: Vec::new(),
+			has_more: false,
+		});
+	}
+
+	let source = GallerySource {
+		cookies,
+		search_request: |source, query, page| {
+			source.get(&format!(
+				"{}/favorites/?q={}&page={}",
+				BASE_URL,
+				encode_uri_component(search_query(source, query)),
+				page
+			))
+		},
+		parse_search: |_, request, _| parser::parse_favorites(request.html()?),
+		..get_instance()
+	};
+	source.search(&source.empty_query(), page)
+}
+
+#[get_manga_list]
+fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
+	get_instance().get_manga_list(filters, page)
+}
+
+#[get_manga_listing]
+fn get_manga_listing(listing: Listing, page: i32) -> Result<MangaPageResult> {
+	if listing.name == "Favorites" {
+		return get_favorites(page);
+	}
+	get_instance().get_manga_listing(listing, page)
+}
+
+#[get_manga_details]
+fn get_manga_details(id: String) -> Result<Manga> {
+	get_instance().get_manga_details(id)
+}
+
+#[get_chapter_list]
+fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
+	get_instance().get_chapter_list(id)
+}
+
+#[get_page_list]
+fn get_page_list(_manga_id: String, chapter_id: String) -> Result<Vec<Page>> {
+	get_instance().get_page_list(chapter_id)
+}
+
+#[modify_image_request]
+fn modify_image_request(request: Request) {
+	get_instance().modify_image_request(request)
+}
+
+#[handle_url]
+pub fn handle_url(url: String) -> Result<DeepLink> {
+	get_instance().handle_url(url)
+}
+
+#[handle_notification]
+fn handle_notification(notification: String) {
+	if notification == "signOut" {
+		defaults_set(SESSION_KEY, StringRef::from("").0);
+		defaults_set(CSRF_KEY, StringRef::from("").0);
+	}
+}
@@ -0,0 +1,122 @@
+use aidoku::{
+	error::Result,
+	prelude::format,
+	std::{html::Node, ObjectRef, String, ValueRef, Vec},
+};
+use gallery_template::{
+	helper::get_id,
+	template::{Gallery, GalleryList, GallerySource, Namespace, Tag},
+};
+
+pub fn parse_search(source: &GallerySource, json: ValueRef, page: i32) -> Result<GalleryList> {
+	let json = json.as_object()?;
+	let mut galleries = Vec::new();
+	for gallery in json.get("result").as_array()? {
+		galleries.push(parse_gallery(source, gallery.as_object()?)?);
+	}
+	let total = json.get("num_pages").as_int().unwrap_or(0) as i32;
+
+	Ok(GalleryList {
+		galleries,
+		has_more: page < total,
+	})
+}
+
+pub fn parse_favorites(html: Node) -> Result<GalleryList> {
+	let mut galleries = Vec::new();
+	for item in html.select("#favcontainer .gallery").array() {
+		let Ok(item) = item.as_node() else {
+			continue;
+		};
+		// /g/{id}/
+		let href = item.select("a.cover").attr("href").read();
+		let Some(id) = href.split('/').filter(|segment| !segment.is_empty()).nth(1) else {
+			continue;
+		};
+
+		let img = item.select("a.cover img");
+		let mut thumbnail = img.attr("data-src").read();
+		if thumbnail.is_empty() {
+			thumbnail = img.attr("src").read();
+		}
+		// https://t.nhentai.net/galleries/{media_id}/thumb.{extension}
+		let (media_id, cover) = thumbnail
+			.split_once("/galleries/")
+			.and_then(|(_, path)| path.split_once("/thumb."))
+			.map(|(media_id, extension)| (String::from(media_id), String::from(extension)))
+			.unwrap_or_default();
+
+		galleries.push(Gallery {
+			id: String::from(id),
+			title: item.select(".caption").text().read(),
+			cover,
+			media_id,
+			..Default::default()
+		});
+	}
+
+	Ok(GalleryList {
+		galleries,
+		has_more: !html.select(".pagination a.next").array().is_empty(),
+	})
+}
+
+pub fn parse_gallery(source: &GallerySource, json: ObjectRef) -> Result<Gallery> {
+	let id = get_id(json.get("id"))?;
+
+	let titles = json.get("title").as_object()?;
+	let title = titles
+		.get("english")
+		.as_string()
+		.or_else(|_| titles.get("pretty").as_string())?
+		.read();
+
+	let images = json.get("images").as_object()?;
+	let cover = get_file_type(
+		images
+			.get("cover")
+			.as_object()?
+			.get("t")
+			.as_string()?
+			.read(),
+	);
+	let mut pages = Vec::new();
+	for page in images.get("pages").as_array()? {
+		pages.push(get_file_type(
+			page.as_object()?.get("t").as_string()?.read(),
+		));
+	}
+
+	let mut tags = Vec::new();
+	if let Ok(json_tags) = json.get("tags").as_array() {
+		for tag in json_tags {
+			let tag = tag.as_object()?;
+			let namespace = source
+				.namespace(&tag.get("type").as_string()?.read())
+				.unwrap_or(Namespace::Tag);
+			tags.push(Tag::new(namespace, tag.get("name").as_string()?.read()));
+		}
+	}
+
+	Ok(Gallery {
+		description: format!("#{}", id),
+		id,
+		title,
+		cover,
+		media_id: json.get("media_id").as_string()?.read(),
+		tags,
+		pages,
+		uploaded: json.get("upload_date").as_float().unwrap_or(0.0),
+		..Default::default()
+	})
+}
+
+fn get_file_type(filetype: String) -> String {
+	String::from(match filetype.as_str() {
+		"j" => "jpg",
+		"p" => "png",
+		"g" => "gif",
+		"w" => "webp",
+		_ => "",
+	})
+}
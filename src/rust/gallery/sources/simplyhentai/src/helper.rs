@@ -0,0 +1,28 @@
+use aidoku::{helpers::uri::*, prelude::format, std::defaults::defaults_get};
+use alloc::string::{String, ToString};
+use gallery_template::template::SearchQuery;
+
+pub const USER_AGENT: &str = "Mozilla/5.0 (iPhone; CPU iPhone OS 16_1_2 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/16.0 Mobile/15E148 Safari/604.1";
+pub const BASE_URL: &str = "https://simply-hentai.com";
+pub const API_BASE_URL: &str = "https://api.simply-hentai.com/v3";
+
+pub fn make_search_url(query: &SearchQuery, page: i32) -> String {
+	let mut params = QueryParameters::new();
+	params.set("query", Some(query.title.as_deref().unwrap_or_default()));
+	if !query.sort.is_empty() {
+		params.set("sort", Some(query.sort));
+	}
+	params.set("page", Some(page.to_string().as_str()));
+	params.set("filter[language][0]", query.language());
+	for (i, tag) in query.included().enumerate() {
+		params.set(format!("filter[tags][{}]", i), Some(tag.name.as_str()));
+	}
+	format!("{API_BASE_URL}/search/complex?{params}")
+}
+
+pub fn get_image_quality() -> String {
+	defaults_get("image_quality")
+		.and_then(|value| value.as_string())
+		.map(|value| value.read())
+		.unwrap_or_else(|_| String::from("full"))
+}
@@ -0,0 +1,85 @@
+#![no_std]
+
+mod helper;
+mod parser;
+extern crate alloc;
+
+use aidoku::{
+	error::Result,
+	prelude::{
+		format, get_chapter_list, get_manga_details, get_manga_list, get_manga_listing,
+		get_page_list, modify_image_request,
+	},
+	std::net::Request,
+	Chapter, Filter, Listing, Manga, MangaPageResult, Page,
+};
+use alloc::{string::String, vec::Vec};
+use gallery_template::template::GallerySource;
+use helper::{make_search_url, API_BASE_URL, BASE_URL, USER_AGENT};
+
+fn get_instance() -> GallerySource {
+	GallerySource {
+		base_url: BASE_URL,
+		user_agent: Some(USER_AGENT),
+
+		languages: &[
+			("en", "English"),
+			("ja", "Japanese"),
+			("fr", "French"),
+			("de", "German"),
+			("it", "Italian"),
+			("ru", "Russian"),
+			("es", "Spanish"),
+			("ko", "Korean"),
+			("pl", "Polish"),
+			("zh", "Chinese"),
+		],
+		// relevance is the api's default
+		sorts: &["", "upload-date", "popularity"],
+		listings: &[("Latest", "upload-date"), ("Popular", "popularity")],
+
+		search_request: |source, query, page| source.get(&make_search_url(query, page)),
+		parse_search: |_, request, page| parser::parse_search(page, request.json()?.as_object()?),
+
+		gallery: |source, id| {
+			let url = format!("{API_BASE_URL}/manga/{id}");
+			parser::parse_gallery(String::from(id), source.get(&url).json()?.as_object()?)
+		},
+		reader: Some(|source, id| {
+			let url = format!("{API_BASE_URL}/manga/{id}/pages");
+			parser::parse_reader(String::from(id), source.get(&url).json()?.as_object()?)
+		}),
+
+		..Default::default()
+	}
+}
+
+#[get_manga_list]
+fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
+	get_instance().get_manga_list(filters, page)
+}
+
+#[get_manga_listing]
+fn get_manga_listing(listing: Listing, page: i32) -> Result<MangaPageResult> {
+	get_instance().get_manga_listing(listing, page)
+}
+
+#[get_manga_details]
+fn get_manga_details(manga_id: String) -> Result<Manga> {
+	get_instance().get_manga_details(manga_id)
+}
+
+#[get_chapter_list]
+fn get_chapter_list(manga_id: String) -> Result<Vec<Chapter>> {
+	get_instance().get_chapter_list(manga_id)
+}
+
+#[get_page_list]
+fn get_page_list(manga_id: String, _chapter_id: String) -> Result<Vec<Page>> {
+	get_instance().get_page_list(manga_id)
+}
+
+#[modify_image_request]
+fn modify_image_request(request: Request) {
+	get_instance().modify_image_request(request)
+}
@@ -0,0 +1,113 @@
+use aidoku::{error::Result, prelude::format, std::ObjectRef};
+use alloc::{
+	string::{String, ToString},
+	vec::Vec,
+};
+use gallery_template::template::{Gallery, GalleryList, Namespace, Tag};
+
+use crate::helper::{get_image_quality, BASE_URL};
+
+fn get_preview(data: &ObjectRef, image_quality: &str) -> Result<String> {
+	Ok(data
+		.get("preview")
+		.as_object()?
+		.get("sizes")
+		.as_object()?
+		.get(image_quality)
+		.as_string()?
+		.read())
+}
+
+pub fn parse_search(page: i32, res: ObjectRef) -> Result<GalleryList> {
+	let mut galleries = Vec::new();
+	let has_more = res.get("pagination").as_object()?.get("pages").as_int()? > page.into();
+
+	let list = res.get("data").as_array()?;
+	let image_quality = get_image_quality();
+	for itemref in list {
+		let object = itemref.as_object()?;
+		let data = object.get("object").as_object()?;
+
+		galleries.push(Gallery {
+			id: data.get("id").as_int()?.to_string(),
+			title: data.get("title").as_string()?.read(),
+			cover: get_preview(&data, &image_quality)?,
+			..Default::default()
+		});
+	}
+
+	Ok(GalleryList {
+		galleries,
+		has_more,
+	})
+}
+
+fn push_resources(
+	tags: &mut Vec<Tag>,
+	data: &ObjectRef,
+	key: &str,
+	namespace: Namespace,
+) -> Result<()> {
+	let resources = data.get(key).as_array()?;
+	for resource in resources {
+		let resource = resource.as_object()?;
+		let title = resource.get("title").as_string()?;
+		tags.push(Tag::new(namespace, title.read()));
+	}
+	Ok(())
+}
+
+pub fn parse_gallery(id: String, res: ObjectRef) -> Result<Gallery> {
+	let data = res.get("data").as_object()?;
+
+	let mut tags = Vec::new();
+	push_resources(&mut tags, &data, "artists", Namespace::Artist)?;
+	push_resources(&mut tags, &data, "tags", Namespace::Tag)?;
+
+	let series = data.get("series").as_object()?;
+	let series_title = series.get("title").as_string()?.read();
+	if !series_title.is_empty() {
+		tags.push(Tag::new(Namespace::Parody, series_title));
+	}
+	let series_slug = series.get("slug").as_string()?.read();
+	let slug = data.get("slug").as_string()?.read();
+
+	Ok(Gallery {
+		id,
+		title: data.get("title").as_string()?.read(),
+		cover: get_preview(&data, &get_image_quality())?,
+		tags,
+		uploaded: data.get("created_at").as_date(
+			"yyyy-MM-dd'T'HH:mm:ss+ss:ss",
+			None,
+			Some("UTC"),
+		)?,
+		url: format!("{BASE_URL}/{series_slug}/{slug}"),
+		..Default::default()
+	})
+}
+
+pub fn parse_reader(id: String, res: ObjectRef) -> Result<Gallery> {
+	let data = res.get("data").as_object()?;
+	let image_quality = get_image_quality();
+
+	let mut images = Vec::new();
+	for imageref in data.get("pages").as_array()? {
+		let image = imageref.as_object()?;
+		let page_num = image.get("page_num").as_int()?;
+		let url = image
+			.get("sizes")
+			.as_object()?
+			.get(&image_quality)
+			.as_string()?
+			.read();
+		images.push((page_num, url));
+	}
+	images.sort_by_key(|(page_num, _)| *page_num);
+
+	Ok(Gallery {
+		id,
+		pages: images.into_iter().map(|(_, url)| url).collect(),
+		..Default::default()
+	})
+}
@@ -0,0 +1,136 @@
+#![no_std]
+
+use aidoku::{
+	error::Result,
+	helpers::uri::encode_uri_component,
+	prelude::*,
+	std::net::{HttpMethod, Request},
+	std::{String, Vec},
+	Chapter, DeepLink, Filter, Listing, Manga, MangaPageResult, Page,
+};
+use gallery_template::{
+	helper::is_number,
+	template::{GallerySource, Namespace, SearchQuery},
+};
+extern crate alloc;
+mod parser;
+
+const BASE_URL: &str = "https://www.tsumino.com";
+
+fn get_instance() -> GallerySource {
+	GallerySource {
+		base_url: BASE_URL,
+		user_agent: Some("Aidoku"),
+
+		sorts: &[
+			"Newest",
+			"Oldest",
+			"Alphabetical",
+			"Rating",
+			"Pages",
+			"Views",
+			"Random",
+			"Comments",
+			"Popularity",
+		],
+		listings: &[
+			("Newest", "Newest"),
+			("Oldest", "Oldest"),
+			("Popularity", "Popularity"),
+		],
+
+		search_request,
+		parse_search: |_, request, page| parser::parse_search(request.json()?, page),
+
+		gallery_url: |_, id| format!("{}/entry/{}", BASE_URL, id),
+		// https://www.tsumino.com/entry/{id} or https://www.tsumino.com/Read/Index/{id}
+		gallery_id: |_, url| {
+			url.split(['/', '?', '#'])
+				.filter(|segment| is_number(segment))
+				.last()
+				.map(String::from)
+		},
+		gallery: |source, id| {
+			let url = (source.gallery_url)(source, id);
+			parser::parse_gallery(String::from(id), source.get(&url).html()?)
+		},
+		reader: Some(|source, id| {
+			let url = format!("{}/Read/Index/{}", BASE_URL, id);
+			parser::parse_reader(String::from(id), source.get(&url).html()?)
+		}),
+
+		// the cdn url has a [PAGE] placeholder
+		page_url: |gallery, index, _| {
+			gallery
+				.media_id
+				.replace("[PAGE]", &format!("{}", index + 1))
+		},
+
+		..Default::default()
+	}
+}
+
+fn search_request(source: &GallerySource, query: &SearchQuery, page: i32) -> Request {
+	let mut parameters = format!("PageNumber={}", page);
+	if let Some(title) = &query.title {
+		parameters.push_str("&Text=");
+		parameters.push_str(&encode_uri_component(title));
+	}
+	parameters.push_str("&Sort=");
+	parameters.push_str(query.sort);
+	for (index, tag) in query.tags.iter().enumerate() {
+		let kind = match tag.namespace {
+			Namespace::Tag => 1,
+			Namespace::Category => 2,
+			Namespace::Group => 4,
+			Namespace::Artist => 5,
+			Namespace::Parody => 6,
+			Namespace::Character => 7,
+			Namespace::Language => continue,
+		};
+		parameters.push_str(&format!(
+			"&Tags[{index}][Type]={kind}&Tags[{index}][Text]={}&Tags[{index}][Exclude]={}",
+			encode_uri_component(&tag.name),
+			tag.excluded
+		));
+	}
+
+	source
+		.request(&format!("{}/search/operate/", BASE_URL), HttpMethod::Post)
+		.body(parameters)
+}
+
+#[get_manga_list]
+fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
+	get_instance().get_manga_list(filters, page)
+}
+
+#[get_manga_listing]
+fn get_manga_listing(listing: Listing, page: i32) -> Result<MangaPageResult> {
+	get_instance().get_manga_listing(listing, page)
+}
+
+#[get_manga_details]
+fn get_manga_details(id: String) -> Result<Manga> {
+	get_instance().get_manga_details(id)
+}
+
+#[get_chapter_list]
+fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
+	get_instance().get_chapter_list(id)
+}
+
+#[get_page_list]
+fn get_page_list(id: String, _: String) -> Result<Vec<Page>> {
+	get_instance().get_page_list(id)
+}
+
+#[modify_image_request]
+fn modify_image_request(request: Request) {
+	get_instance().modify_image_request(request)
+}
+
+#[handle_url]
+fn handle_url(url: String) -> Result<DeepLink> {
+	get_instance().handle_url(url)
+}
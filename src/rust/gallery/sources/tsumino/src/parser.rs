@@ -0,0 +1,113 @@
+use aidoku::{
+	error::{AidokuError, AidokuErrorKind, NodeError, Result},
+	std::{html::Node, String, ValueRef, Vec},
+};
+use alloc::vec;
+use gallery_template::{
+	helper::{get_id, node_texts},
+	template::{Gallery, GalleryList, Namespace, Tag},
+};
+
+pub fn parse_search(json: ValueRef, page: i32) -> Result<GalleryList> {
+	let json = json.as_object()?;
+	let mut galleries = Vec::new();
+	for manga in json.get("data").as_array()? {
+		let Ok(main) = manga
+			.as_object()
+			.and_then(|obj| obj.get("entry").as_object())
+		else {
+			continue;
+		};
+		let Ok(id) = get_id(main.get("id")) else {
+			continue;
+		};
+		let Ok(title) = main.get("title").as_string() else {
+			continue;
+		};
+		galleries.push(Gallery {
+			id,
+			title: title.read(),
+			cover: main
+				.get("thumbnailUrl")
+				.as_string()
+				.map(|cover| cover.read())
+				.unwrap_or_default(),
+			..Default::default()
+		});
+	}
+	let total = json.get("pageCount").as_int().unwrap_or(0) as i32;
+
+	Ok(GalleryList {
+		galleries,
+		has_more: page < total,
+	})
+}
+
+pub fn parse_gallery(id: String, html: Node) -> Result<Gallery> {
+	let info = html.select("div.book-info-container");
+
+	let mut tags = Vec::new();
+	for (selector, namespace) in [
+		("#Artist a", Namespace::Artist),
+		("#Category a", Namespace::Category),
+		("#Group a", Namespace::Group),
+		("#Parody a", Namespace::Parody),
+		("#Characters a", Namespace::Character),
+		("#Tag a", Namespace::Tag),
+	] {
+		for name in node_texts(info.select(selector)) {
+			tags.push(Tag::new(namespace, name));
+		}
+	}
+
+	let page_count = info
+		.select("#Pages")
+		.text()
+		.read()
+		.trim()
+		.parse::<usize>()
+		.unwrap_or(0);
+
+	Ok(Gallery {
+		id,
+		title: html
+			.select("meta[property=og:title]")
+			.attr("content")
+			.read(),
+		cover: html.select("img").attr("src").read(),
+		tags,
+		pages: vec![String::new(); page_count],
+		uploaded: info
+			.select("#Uploaded")
+			.text()
+			.0
+			.as_date("yyyy MMMM d", Some("en_US"), None)
+			.unwrap_or(-1.0),
+		// skitty wants this to be put in
+		uploader: node_texts(info.select("#Uploader a"))
+			.into_iter()
+			.next()
+			.unwrap_or_default(),
+		..Default::default()
+	})
+}
+
+pub fn parse_reader(id: String, html: Node) -> Result<Gallery> {
+	let page_count = html
+		.select("h1")
+		.text()
+		.read()
+		.split(' ')
+		.last()
+		.and_then(|count| count.parse::<usize>().ok())
+		.ok_or(AidokuError {
+			reason: AidokuErrorKind::NodeError(NodeError::ParseError),
+		})?;
+
+	Ok(Gallery {
+		id,
+		media_id: html.select("#image-container").attr("data-cdn").read(),
+		pages: vec![String::new(); page_count],
+		..Default::default()
+	})
+}
@@ -0,0 +1,34 @@
+use aidoku::{
+	error::Result,
+	std::{html::Node, String, ValueRef, Vec},
+};
+use alloc::string::ToString;
+
+pub fn is_number(s: &str) -> bool {
+	!s.is_empty() && s.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Reads an id that some APIs return as a number and others as a string.
+pub fn get_id(value: ValueRef) -> Result<String> {
+	let id = value.as_int().unwrap_or(0);
+	Ok(if id != 0 {
+		id.to_string()
+	} else {
+		value.as_string()?.read()
+	})
+}
+
+/// The text of every node matched by a selection, trimmed, skipping empty ones.
+pub fn node_texts(nodes: Node) -> Vec<String> {
+	nodes
+		.array()
+		.filter_map(|node| node.as_node().ok())
+		.map(|node| String::from(node.text().read().trim()))
+		.filter(|text| !text.is_empty())
+		.collect()
+}
+
+/// Drops the gallery count some sites print after a tag, e.g. `glasses 12345`.
+pub fn strip_count(tag: &str) -> String {
+	String::from(tag.trim_end_matches(|c: char| c.is_ascii_digit() || c.is_whitespace()))
+}
@@ -0,0 +1,5 @@
+#![no_std]
+extern crate alloc;
+
+pub mod helper;
+pub mod template;
@@ -0,0 +1,582 @@
+use aidoku::{
+	error::{AidokuError, AidokuErrorKind, Result},
+	helpers::uri::encode_uri_component,
+	prelude::*,
+	std::{
+		defaults::defaults_get,
+		net::{HttpMethod, Request},
+		String, Vec,
+	},
+	Chapter, DeepLink, Filter, FilterType, Listing, Manga, MangaContentRating, MangaPageResult,
+	MangaStatus, MangaViewer, Page,
+};
+use alloc::vec;
+
+use crate::helper::is_number;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Namespace {
+	Tag,
+	Artist,
+	Group,
+	Parody,
+	Character,
+	Category,
+	Language,
+}
+
+#[derive(Clone)]
+pub struct Tag {
+	pub namespace: Namespace,
+	pub name: String,
+	pub excluded: bool,
+}
+
+impl Tag {
+	pub fn new<T: Into<String>>(namespace: Namespace, name: T) -> Tag {
+		Tag {
+			namespace,
+			name: name.into(),
+			excluded: false,
+		}
+	}
+}
+
+/// How tags are written into a free-text query, e.g. `tag:"glasses" -artist:"name"`
+/// or `+"glasses" -"name"`.
+pub struct QueryGrammar {
+	pub include_prefix: &'static str,
+	pub exclude_prefix: &'static str,
+	/// Whether terms are written as `namespace:"name"` or just `"name"`.
+	pub namespaced: bool,
+}
+
+pub struct SearchQuery {
+	pub title: Option<String>,
+	pub author: Option<String>,
+	pub tags: Vec<Tag>,
+	/// Site names of the languages picked in the source settings.
+	pub languages: Vec<&'static str>,
+	/// The site's value for the selected sort option, empty for the site default.
+	pub sort: &'static str,
+	pub ascending: bool,
+	/// Select filters by name, for anything site-specific.
+	pub selects: Vec<(String, i32)>,
+}
+
+impl SearchQuery {
+	pub fn included(&self) -> impl Iterator<Item = &Tag> {
+		self.tags.iter().filter(|tag| !tag.excluded)
+	}
+
+	pub fn excluded(&self) -> impl Iterator<Item = &Tag> {
+		self.tags.iter().filter(|tag| tag.excluded)
+	}
+
+	/// The first selected language, for sites that only search one at a time.
+	pub fn language(&self) -> Option<&'static str> {
+		self.languages.first().copied()
+	}
+
+	pub fn select(&self, name: &str) -> Option<i32> {
+		self.selects
+			.iter()
+			.find(|(select, _)| select == name)
+			.map(|(_, value)| *value)
+	}
+
+	/// Folds the title, tags and languages into a single query string using
+	/// the source's grammar.
+	pub fn to_query_string(&self, source: &GallerySource) -> String {
+		let mut terms = Vec::new();
+		if let Some(title) = &self.title {
+			terms.push(title.clone());
+		}
+		for tag in &self.tags {
+			terms.push(source.query_term(tag));
+		}
+		if source.grammar.namespaced {
+			for language in &self.languages {
+				terms.push(source.query_term(&Tag::new(Namespace::Language, *language)));
+			}
+		}
+		terms.join(" ")
+	}
+}
+
+/// Everything a site knows about one gallery. Fields a page doesn't have are
+/// left empty.
+#[derive(Default)]
+pub struct Gallery {
+	pub id: String,
+	pub title: String,
+	/// Passed to `cover_url`, which returns it as-is by default.
+	pub cover: String,
+	/// The id the site's image servers know the gallery by, if it differs.
+	pub media_id: String,
+	pub tags: Vec<Tag>,
+	pub description: String,
+	/// One entry per page, passed to `page_url`.
+	pub pages: Vec<String>,
+	pub uploaded: f64,
+	pub uploader: String,
+	/// Overrides `gallery_url` when the site's links can't be built from the id.
+	pub url: String,
+}
+
+impl Gallery {
+	pub fn names(&self, namespace: Namespace) -> Vec<String> {
+		self.tags
+			.iter()
+			.filter(|tag| tag.namespace == namespace)
+			.map(|tag| tag.name.clone())
+			.collect()
+	}
+}
+
+pub struct GalleryList {
+	pub galleries: Vec<Gallery>,
+	pub has_more: bool,
+}
+
+pub struct GallerySource {
+	pub base_url: &'static str,
+	pub lang: &'static str,
+	pub user_agent: Option<&'static str>,
+	/// Cookies for site requests, e.g. a signed-in session. They're sent as a
+	/// `Cookie` header, which replaces the cookies the app has for the site.
+	pub cookies: fn() -> Option<String>,
+	pub viewer: MangaViewer,
+
+	/// The site's keyword for each namespace. Used to read `keyword_name`
+	/// genre filter ids, to write namespaced query terms and to sort the tags
+	/// the site returns.
+	pub namespaces: &'static [(&'static str, Namespace)],
+	pub grammar: QueryGrammar,
+	/// (setting code, site name) pairs for the `languages` setting. The first
+	/// one is searched when nothing is selected.
+	pub languages: &'static [(&'static str, &'static str)],
+	/// The site's value for each sort filter option, in `filters.json` order.
+	pub sorts: &'static [&'static str],
+	/// The site's sort value for each listing.
+	pub listings: &'static [(&'static str, &'static str)],
+	/// Opens the gallery directly when the title search is a gallery id.
+	pub id_search: bool,
+
+	pub search_request: fn(&GallerySource, &SearchQuery, i32) -> Request,
+	pub parse_search: fn(&GallerySource, Request, i32) -> Result<GalleryList>,
+
+	// {base_url}/gallery/{id}
+	pub gallery_url: fn(&GallerySource, &str) -> String,
+	pub gallery_id: fn(&GallerySource, &str) -> Option<String>,
+	pub gallery: fn(&GallerySource, &str) -> Result<Gallery>,
+	/// Fetches the pages when they aren't on the page `gallery` reads.
+	pub reader: Option<fn(&GallerySource, &str) -> Result<Gallery>>,
+
+	pub cover_url: fn(&Gallery) -> String,
+	pub page_url: fn(&Gallery, usize, &str) -> String,
+}
+
+fn unimplemented<T>() -> Result<T> {
+	Err(AidokuError {
+		reason: AidokuErrorKind::Unimplemented,
+	})
+}
+
+impl Default for GallerySource {
+	fn default() -> GallerySource {
+		GallerySource {
+			base_url: "",
+			lang: "en",
+			user_agent: None,
+			cookies: || None,
+			viewer: MangaViewer::Rtl,
+
+			namespaces: &[
+				("tag", Namespace::Tag),
+				("artist", Namespace::Artist),
+				("group", Namespace::Group),
+				("parody", Namespace::Parody),
+				("character", Namespace::Character),
+				("category", Namespace::Category),
+				("language", Namespace::Language),
+			],
+			grammar: QueryGrammar {
+				include_prefix: "",
+				exclude_prefix: "-",
+				namespaced: true,
+			},
+			languages: &[],
+			sorts: &[],
+			listings: &[],
+			id_search: false,
+
+			search_request: |source, query, page| {
+				source.get(&format!(
+					"{}/search?q={}&page={}",
+					source.base_url,
+					encode_uri_component(query.to_query_string(source)),
+					page
+				))
+			},
+			parse_search: |_, _, _| unimplemented(),
+
+			gallery_url: |source, id| format!("{}/gallery/{}", source.base_url, id),
+			// {base_url}/{path}/{id}/...
+			gallery_id: |source, url| {
+				url.strip_prefix(source.base_url)?
+					.split(['/', '?', '#'])
+					.filter(|segment| !segment.is_empty())
+					.nth(1)
+					.map(String::from)
+			},
+			gallery: |_, _| unimplemented(),
+			reader: None,
+
+			cover_url: |gallery| gallery.cover.clone(),
+			page_url: |_, _, page| String::from(page),
+		}
+	}
+}
+
+impl GallerySource {
+	pub fn request(&self, url: &str, method: HttpMethod) -> Request {
+		let mut request = Request::new(url, method);
+		if let Some(user_agent) = self.user_agent {
+			request = request.header("User-Agent", user_agent);
+		}
+		if let Some(cookies) = (self.cookies)() {
+			request = request.header("Cookie", &cookies);
+		}
+		request
+	}
+
+	pub fn get(&self, url: &str) -> Request {
+		self.request(url, HttpMethod::Get)
+	}
+
+	fn viewer(&self) -> MangaViewer {
+		#[allow(clippy::needless_match)]
+		match self.viewer {
+			MangaViewer::Rtl => MangaViewer::Rtl,
+			MangaViewer::Ltr => MangaViewer::Ltr,
+			MangaViewer::Scroll => MangaViewer::Scroll,
+			MangaViewer::Vertical => MangaViewer::Vertical,
+			_ => MangaViewer::Rtl,
+		}
+	}
+
+	pub fn namespace(&self, keyword: &str) -> Option<Namespace> {
+		self.namespaces
+			.iter()
+			.find(|(name, _)| name.eq_ignore_ascii_case(keyword))
+			.map(|(_, namespace)| *namespace)
+	}
+
+	pub fn keyword(&self, namespace: Namespace) -> &'static str {
+		self.namespaces
+			.iter()
+			.find(|(_, ns)| *ns == namespace)
+			.map(|(name, _)| *name)
+			.unwrap_or("tag")
+	}
+
+	pub fn query_term(&self, tag: &Tag) -> String {
+		let prefix = if tag.excluded {
+			self.grammar.exclude_prefix
+		} else {
+			self.grammar.include_prefix
+		};
+		if self.grammar.namespaced {
+			format!("{}{}:\"{}\"", prefix, self.keyword(tag.namespace), tag.name)
+		} else {
+			format!("{}\"{}\"", prefix, tag.name)
+		}
+	}
+
+	/// Genre filters are tags unless their id is `keyword_name` for one of
+	/// the site's namespaces.
+	fn filter_tag(&self, filter: &Filter) -> Tag {
+		let id = filter
+			.object
+			.get("id")
+			.as_string()
+			.map(|id| id.read())
+			.unwrap_or_else(|_| filter.name.clone());
+		if let Some((keyword, name)) = id.split_once('_') {
+			if let Some(namespace) = self.namespace(keyword) {
+				return Tag::new(namespace, name);
+			}
+		}
+		Tag::new(Namespace::Tag, id)
+	}
+
+	/// Reads a comma-separated list like `name, -group:other`. Terms without
+	/// a known namespace fall back to `namespace`.
+	pub fn parse_terms(&self, text: &str, namespace: Namespace) -> Vec<Tag> {
+		let mut tags = Vec::new();
+		for term in text.split(',') {
+			let mut term = term.trim();
+			let excluded = term.starts_with('-');
+			if excluded {
+				term = term[1..].trim_start();
+			}
+
+			let mut tag = match term
+				.split_once(':')
+				.and_then(|(keyword, name)| Some((self.namespace(keyword.trim())?, name)))
+			{
+				Some((namespace, name)) => Tag::new(namespace, name.trim().trim_matches('"')),
+				None => Tag::new(namespace, term.trim_matches('"')),
+			};
+			if tag.name.is_empty() {
+				continue;
+			}
+			tag.excluded = excluded;
+			tags.push(tag);
+		}
+		tags
+	}
+
+	fn selected_languages(&self) -> Vec<&'static str> {
+		let mut languages = Vec::new();
+		if let Ok(codes) = defaults_get("languages").and_then(|value| value.as_array()) {
+			for code in codes {
+				let Ok(code) = code.as_string() else {
+					continue;
+				};
+				let code = code.read();
+				if let Some((_, name)) = self.languages.iter().find(|(lang, _)| *lang == code) {
+					languages.push(*name);
+				}
+			}
+		}
+		if languages.is_empty() {
+			if let Some((_, name)) = self.languages.first() {
+				languages.push(*name);
+			}
+		}
+		languages
+	}
+
+	/// A query with only the source's defaults and language settings applied.
+	pub fn empty_query(&self) -> SearchQuery {
+		SearchQuery {
+			title: None,
+			author: None,
+			tags: Vec::new(),
+			languages: self.selected_languages(),
+			sort: self.sorts.first().copied().unwrap_or_default(),
+			ascending: false,
+			selects: Vec::new(),
+		}
+	}
+
+	pub fn parse_filters(&self, filters: Vec<Filter>) -> SearchQuery {
+		let mut query = self.empty_query();
+		for filter in filters {
+			match filter.kind {
+				FilterType::Title => {
+					if let Ok(title) = filter.value.as_string() {
+						query.title = Some(title.read());
+					}
+				}
+				FilterType::Author => {
+					let Ok(author) = filter.value.as_string() else {
+						continue;
+					};
+					if self.grammar.namespaced {
+						query
+							.tags
+							.extend(self.parse_terms(&author.read(), Namespace::Artist));
+					} else {
+						query.author = Some(author.read());
+					}
+				}
+				FilterType::Genre => {
+					let excluded = match filter.value.as_int().unwrap_or(-1) {
+						0 => true,
+						1 => false,
+						_ => continue,
+					};
+					let mut tag = self.filter_tag(&filter);
+					tag.excluded = excluded;
+					query.tags.push(tag);
+				}
+				FilterType::Select => {
+					let value = filter.value.as_int().unwrap_or(0) as i32;
+					query.selects.push((filter.name, value));
+				}
+				FilterType::Sort => {
+					let Ok(value) = filter.value.as_object() else {
+						continue;
+					};
+					let index = value.get("index").as_int().unwrap_or(0) as usize;
+					if let Some(sort) = self.sorts.get(index) {
+						query.sort = *sort;
+					}
+					query.ascending = value.get("ascending").as_bool().unwrap_or(false);
+				}
+				_ => continue,
+			}
+		}
+		query
+	}
+
+	pub fn search(&self, query: &SearchQuery, page: i32) -> Result<MangaPageResult> {
+		let request = (self.search_request)(self, query, page);
+		let list = (self.parse_search)(self, request, page)?;
+		Ok(MangaPageResult {
+			manga: list
+				.galleries
+				.into_iter()
+				.map(|gallery| self.manga(gallery))
+				.collect(),
+			has_more: list.has_more,
+		})
+	}
+
+	pub fn get_manga_list(&self, filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
+		let query = self.parse_filters(filters);
+		if self.id_search {
+			if let Some(id) = query.title.as_deref().map(str::trim) {
+				if is_number(id) {
+					let manga = self.get_manga_details(String::from(id))?;
+					return Ok(MangaPageResult {
+						manga: vec![manga],
+						has_more: false,
+					});
+				}
+			}
+		}
+		self.search(&query, page)
+	}
+
+	pub fn get_manga_listing(&self, listing: Listing, page: i32) -> Result<MangaPageResult> {
+		let mut query = self.empty_query();
+		if let Some((_, sort)) = self.listings.iter().find(|(name, _)| *name == listing.name) {
+			query.sort = *sort;
+		}
+		self.search(&query, page)
+	}
+
+	pub fn manga(&self, gallery: Gallery) -> Manga {
+		let cover = (self.cover_url)(&gallery);
+		let url = if gallery.url.is_empty() {
+			(self.gallery_url)(self, &gallery.id)
+		} else {
+			gallery.url.clone()
+		};
+
+		let artists = gallery.names(Namespace::Artist).join(", ");
+		let mut categories = gallery.names(Namespace::Category);
+		categories.extend(gallery.names(Namespace::Tag));
+
+		let mut description = Vec::new();
+		if !gallery.description.is_empty() {
+			description.push(gallery.description.clone());
+		}
+		if !gallery.pages.is_empty() {
+			description.push(format!("Pages: {}", gallery.pages.len()));
+		}
+		for (label, namespace) in [
+			("Parodies", Namespace::Parody),
+			("Characters", Namespace::Character),
+			("Groups", Namespace::Group),
+			("Languages", Namespace::Language),
+		] {
+			let names = gallery.names(namespace);
+			if !names.is_empty() {
+				description.push(format!("{}: {}", label, names.join(", ")));
+			}
+		}
+
+		Manga {
+			id: gallery.id,
+			cover,
+			title: gallery.title,
+			author: artists.clone(),
+			artist: artists,
+			description: description.join("\n\n"),
+			url,
+			categories,
+			status: MangaStatus::Completed,
+			nsfw: MangaContentRating::Nsfw,
+			viewer: self.viewer(),
+		}
+	}
+
+	pub fn chapter(&self, gallery: &Gallery) -> Chapter {
+		let lang = gallery
+			.names(Namespace::Language)
+			.iter()
+			.find_map(|name| {
+				self.languages
+					.iter()
+					.find(|(_, language)| language.eq_ignore_ascii_case(name))
+			})
+			.map(|(code, _)| *code)
+			.unwrap_or(self.lang);
+		let scanlator = if gallery.uploader.is_empty() {
+			gallery.names(Namespace::Group).join(", ")
+		} else {
+			gallery.uploader.clone()
+		};
+		let url = if gallery.url.is_empty() {
+			(self.gallery_url)(self, &gallery.id)
+		} else {
+			gallery.url.clone()
+		};
+
+		Chapter {
+			id: gallery.id.clone(),
+			chapter: 1.0,
+			date_updated: gallery.uploaded,
+			scanlator,
+			url,
+			lang: String::from(lang),
+			..Default::default()
+		}
+	}
+
+	pub fn get_manga_details(&self, id: String) -> Result<Manga> {
+		let gallery = (self.gallery)(self, &id)?;
+		Ok(self.manga(gallery))
+	}
+
+	pub fn get_chapter_list(&self, id: String) -> Result<Vec<Chapter>> {
+		let gallery = (self.gallery)(self, &id)?;
+		Ok(vec![self.chapter(&gallery)])
+	}
+
+	pub fn get_page_list(&self, id: String) -> Result<Vec<Page>> {
+		let gallery = (self.reader.unwrap_or(self.gallery))(self, &id)?;
+		Ok(gallery
+			.pages
+			.iter()
+			.enumerate()
+			.map(|(index, page)| Page {
+				index: index as i32,
+				url: (self.page_url)(&gallery, index, page),
+				..Default::default()
+			})
+			.collect())
+	}
+
+	pub fn modify_image_request(&self, request: Request) {
+		let request = request.header("Referer", self.base_url);
+		if let Some(user_agent) = self.user_agent {
+			request.header("User-Agent", user_agent);
+		}
+	}
+
+	pub fn handle_url(&self, url: String) -> Result<DeepLink> {
+		let Some(id) = (self.gallery_id)(self, &url) else {
+			return unimplemented();
+		};
+		Ok(DeepLink {
+			manga: Some(self.get_manga_details(id)?),
+			chapter: None,
+		})
+	}
+}
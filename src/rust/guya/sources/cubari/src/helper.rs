@@ -0,0 +1,132 @@
+use aidoku::{
+	prelude::format,
+	std::defaults::{defaults_get, defaults_set},
+	std::{ArrayRef, String, StringRef, Vec},
+	Manga, MangaContentRating, MangaStatus, MangaViewer,
+};
+use base64::{engine::general_purpose, Engine};
+
+const SAVED_KEY: &str = "saved";
+
+/// Sites Cubari proxies, as `(host, path prefix, cubari source)`.
+const PROXIED_SITES: [(&str, &str, &str); 6] = [
+	("imgur.com", "a/", "imgur"),
+	("imgur.com", "gallery/", "imgur"),
+	("reddit.com", "gallery/", "reddit"),
+	("mangadex.org", "title/", "mangadex"),
+	("nhentai.net", "g/", "nhentai"),
+	("mangasee123.com", "manga/", "mangasee"),
+];
+
+/// Cubari sources that can be typed in directly, e.g. `gist/cmF3L2…`.
+const CUBARI_SOURCES: [&str; 6] = ["gist", "imgur", "reddit", "mangadex", "nhentai", "mangasee"];
+
+/// Turns a Cubari reader link, a raw gist link, a link to one of the sites
+/// Cubari proxies, or a bare `{source}/{slug}` into a series id.
+pub fn parse_series_id(input: &str) -> Option<String> {
+	let url = input
+		.trim()
+		.split(|c: char| c == '?' || c == '#')
+		.next()
+		.unwrap_or_default()
+		.trim_start_matches("https://")
+		.trim_start_matches("http://")
+		.trim_start_matches("www.")
+		.trim_start_matches("m.")
+		.trim_start_matches("old.");
+	let (host, path) = url.split_once('/')?;
+
+	match host {
+		"cubari.moe" => {
+			let mut parts = path.strip_prefix("read/")?.split('/');
+			let source = parts
+				.next()
+				.filter(|source| !source.is_empty() && *source != "api")?;
+			let slug = parts.next().filter(|slug| !slug.is_empty())?;
+			Some(format!("{}/{}", source, slug))
+		}
+		// cubari reads gists from the base64 of their raw path
+		"gist.githubusercontent.com" => Some(format!(
+			"gist/{}",
+			general_purpose::URL_SAFE_NO_PAD.encode(format!("raw/{}", path.trim_end_matches('/')))
+		)),
+		_ => {
+			if let Some((_, prefix, source)) = PROXIED_SITES
+				.iter()
+				.find(|(site, prefix, _)| *site == host && path.starts_with(prefix))
+			{
+				let slug = path[prefix.len()..].split('/').next().unwrap_or_default();
+				// imgur galleries end their urls with the id after the title
+				let slug = if *source == "imgur" {
+					slug.rsplit('-').next().unwrap_or_default()
+				} else {
+					slug
+				};
+				(!slug.is_empty()).then(|| format!("{}/{}", source, slug))
+			} else if CUBARI_SOURCES.contains(&host) {
+				let slug = path.split('/').next().unwrap_or_default();
+				(!slug.is_empty()).then(|| format!("{}/{}", host, slug))
+			} else {
+				None
+			}
+		}
+	}
+}
+
+pub fn content_rating(id: &str) -> MangaContentRating {
+	if id.starts_with("nhentai/") {
+		MangaContentRating::Nsfw
+	} else {
+		MangaContentRating::Safe
+	}
+}
+
+/// Saved series are kept as `id\ttitle\tcover` so that the listing doesn't
+/// have to fetch every series again.
+fn saved_entries() -> Vec<String> {
+	match defaults_get(SAVED_KEY).and_then(|value| value.as_array()) {
+		Ok(array) => array
+			.filter_map(|entry| entry.as_string().ok())
+			.map(|entry| entry.read())
+			.collect(),
+		Err(_) => Vec::new(),
+	}
+}
+
+pub fn saved_series() -> Vec<Manga> {
+	saved_entries()
+		.iter()
+		.filter_map(|entry| {
+			let mut fields = entry.splitn(3, '\t');
+			let id = String::from(fields.next()?);
+			let title = String::from(fields.next()?);
+			let cover = String::from(fields.next().unwrap_or_default());
+			Some(Manga {
+				nsfw: content_rating(&id),
+				id,
+				title,
+				cover,
+				status: MangaStatus::Unknown,
+				viewer: MangaViewer::Rtl,
+				..Default::default()
+			})
+		})
+		.collect()
+}
+
+/// Moves the series to the top of the saved listing, adding it if needed.
+pub fn save_series(manga: &Manga) {
+	let prefix = format!("{}\t", manga.id);
+	let mut arr = ArrayRef::new();
+	arr.insert(StringRef::from(format!("{}{}\t{}", prefix, manga.title, manga.cover)).0);
+	for entry in saved_entries() {
+		if !entry.starts_with(&prefix) {
+			arr.insert(StringRef::from(entry).0);
+		}
+	}
+	defaults_set(SAVED_KEY, arr.0);
+}
+
+pub fn clear_saved() {
+	defaults_set(SAVED_KEY, ArrayRef::new().0);
+}
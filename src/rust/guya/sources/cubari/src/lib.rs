@@ -0,0 +1,116 @@
+#![no_std]
+use aidoku::{
+	error::{AidokuError, AidokuErrorKind, Result},
+	prelude::*,
+	std::String,
+	std::{ObjectRef, Vec},
+	Chapter, DeepLink, Filter, FilterType, Listing, Manga, MangaPageResult, Page,
+};
+
+use guya_template::template;
+
+mod helper;
+use helper::*;
+
+fn data() -> template::GuyaSiteData {
+	template::GuyaSiteData {
+		base_url: String::from("https://cubari.moe"),
+		proxy: true,
+		..Default::default()
+	}
+}
+
+/// Cubari has no catalogue of its own: searching with a link opens that
+/// series, anything else searches the saved ones.
+#[get_manga_list]
+fn get_manga_list(filters: Vec<Filter>, _: i32) -> Result<MangaPageResult> {
+	let mut query = String::new();
+	for filter in filters {
+		if filter.kind == FilterType::Title {
+			query = filter.value.as_string()?.read();
+		}
+	}
+
+	let mut manga_arr: Vec<Manga> = Vec::new();
+	if let Some(id) = parse_series_id(&query) {
+		manga_arr.push(get_manga_details(id)?);
+	} else {
+		let query = query.to_lowercase();
+		manga_arr = saved_series()
+			.into_iter()
+			.filter(|manga| manga.title.to_lowercase().contains(&query))
+			.collect();
+	}
+
+	Ok(MangaPageResult {
+		manga: manga_arr,
+		has_more: false,
+	})
+}
+
+#[get_manga_listing]
+fn get_manga_listing(_: Listing, _: i32) -> Result<MangaPageResult> {
+	Ok(MangaPageResult {
+		manga: saved_series(),
+		has_more: false,
+	})
+}
+
+#[get_manga_details]
+pub fn get_manga_details(slug: String) -> Result<Manga> {
+	let nsfw = content_rating(&slug);
+	let manga = template::get_manga_details(data(), slug, nsfw)?;
+	save_series(&manga);
+	Ok(manga)
+}
+
+#[get_chapter_list]
+pub fn get_chapter_list(slug: String) -> Result<Vec<Chapter>> {
+	template::get_chapter_list(data(), slug)
+}
+
+/// # Safety
+///
+/// I have no clue why this is unsafe tbh, took this from aidoku-rs as I needed
+/// the full chapter obj. Clippy making me put a safety comment here.
+#[no_mangle]
+#[export_name = "get_page_list"]
+pub unsafe extern "C" fn __wasm_get_page_list(rid: i32) -> i32 {
+	let obj = aidoku::std::ObjectRef(aidoku::std::ValueRef::new(rid));
+	let resp: Result<Vec<Page>> = get_page_list(obj);
+	match resp {
+		Ok(resp) => {
+			let mut arr = aidoku::std::ArrayRef::new();
+			for item in resp {
+				let rid = item.create();
+				arr.insert(aidoku::std::ValueRef::new(rid));
+			}
+			let rid = arr.0 .0;
+			core::mem::forget(arr.0);
+			rid
+		}
+		Err(_) => -1,
+	}
+}
+
+pub fn get_page_list(chapter: ObjectRef) -> Result<Vec<Page>> {
+	template::get_page_list(data(), chapter)
+}
+
+#[handle_url]
+pub fn handle_url(url: String) -> Result<DeepLink> {
+	let id = parse_series_id(&url).ok_or(AidokuError {
+		reason: AidokuErrorKind::Unimplemented,
+	})?;
+	Ok(DeepLink {
+		manga: Some(get_manga_details(id)?),
+		chapter: None,
+	})
+}
+
+#[handle_notification]
+fn handle_notification(notification: String) {
+	if notification == "clearSaved" {
+		clear_saved();
+	}
+}
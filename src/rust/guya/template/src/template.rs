@@ -15,6 +15,9 @@ pub struct GuyaSiteData {
 	pub base_url: String,
 	pub nsfw: MangaContentRating,
 	pub language: String,
+	/// Series are served through Cubari's proxy, with slugs written as
+	/// `{source}/{slug}` (e.g. `gist/…`, `imgur/…`)
+	pub proxy: bool,
 }
 
 impl Default for GuyaSiteData {
@@ -23,6 +26,36 @@ impl Default for GuyaSiteData {
 			base_url: String::new(),
 			nsfw: MangaContentRating::Safe,
 			language: String::from("en"),
+			proxy: false,
+		}
+	}
+}
+
+impl GuyaSiteData {
+	pub fn series_url(&self, slug: &str) -> String {
+		match slug.split_once('/') {
+			Some((source, slug)) if self.proxy => {
+				format!("{}/read/api/{}/series/{}/", &self.base_url, source, slug)
+			}
+			_ => format!("{}/api/series/{}/", &self.base_url, slug),
+		}
+	}
+
+	pub fn reader_url(&self, slug: &str) -> String {
+		if self.proxy {
+			format!("{}/read/{}/", &self.base_url, slug)
+		} else {
+			format!("{}/read/manga/{}/", &self.base_url, slug)
+		}
+	}
+
+	/// Proxied series link straight to their covers and images, hosted
+	/// ones are relative to the site.
+	pub fn absolute_url(&self, url: &str) -> String {
+		if url.starts_with("http") {
+			String::from(url)
+		} else {
+			format!("{}{}", &self.base_url, url)
 		}
 	}
 }
@@ -85,7 +118,7 @@ pub fn get_manga_details(
 	slug: String,
 	nsfw: MangaContentRating,
 ) -> Result<Manga> {
-	let url = format!("{}/api/series/{}/", &data.base_url, slug);
+	let url = data.series_url(&slug);
 	let request = Request::new(url, HttpMethod::Get).header("User-Agent", "Aidoku");
 	let json = request
 		.json()
@@ -96,18 +129,14 @@ pub fn get_manga_details(
 		.as_string()
 		.expect("Manga detail title not found")
 		.read();
-	let cover = format!(
-		"{}{}",
-		&data.base_url,
-		json.get("cover").as_string()?.read()
-	);
+	let cover = data.absolute_url(&json.get("cover").as_string()?.read());
 	let description_raw = json.get("description").as_string()?.read();
 	let description_node = Node::new_fragment(description_raw.as_bytes())?;
 	let description = match description_node.select("body").array().get(0).as_node() {
 		Ok(node) => node.own_text().read(),
 		Err(_) => String::from(""),
 	};
-	let user_url = format!("{}/read/manga/{}/", &data.base_url, slug);
+	let user_url = data.reader_url(&slug);
 	let author = match json.get("author").as_string() {
 		Ok(author) => author.read(),
 		Err(_) => String::from("Unknown Author"),
@@ -132,7 +161,7 @@ pub fn get_manga_details(
 }
 
 pub fn get_chapter_list(data: GuyaSiteData, slug: String) -> Result<Vec<Chapter>> {
-	let url = format!("{}/api/series/{}/", &data.base_url, slug);
+	let url = data.series_url(&slug);
 	let request = Request::new(url, HttpMethod::Get).header("User-Agent", "Aidoku");
 	let json = request
 		.json()
@@ -154,13 +183,19 @@ pub fn get_chapter_list(data: GuyaSiteData, slug: String) -> Result<Vec<Chapter>
 		let title = obj.get("title").as_string()?.read();
 		let volume = obj
 			.get("volume")
-			.as_string()?
-			.read()
+			.as_string()
+			.map(|volume| volume.read())
+			.unwrap_or_default()
 			.parse()
 			.unwrap_or(-1.0);
 		let chapter_int = chapter.parse().unwrap_or(1.0);
-		let user_url = format!("{}/read/manga/{}/{}/", &data.base_url, &slug, chapter);
-		let folder = obj.get("folder").as_string()?.read();
+		let user_url = format!("{}{}/", data.reader_url(&slug), chapter);
+		// proxied series have no folders
+		let folder = obj
+			.get("folder")
+			.as_string()
+			.map(|folder| folder.read())
+			.unwrap_or_default();
 		for groups in obj.get("groups").as_object()?.keys() {
 			let group_id = groups.as_string()?.read();
 			let mut group_name = String::new();
@@ -172,11 +207,12 @@ pub fn get_chapter_list(data: GuyaSiteData, slug: String) -> Result<Vec<Chapter>
 				}
 			}
 			let mut date_updated = 0.0;
-			let date_list = obj.get("release_date").as_object()?;
-			for dl_index in date_list.keys() {
-				let dl_id = dl_index.as_string()?.read();
-				if dl_id == group_id {
-					date_updated = date_list.get(&dl_id).as_float()?;
+			if let Ok(date_list) = obj.get("release_date").as_object() {
+				for dl_index in date_list.keys() {
+					let dl_id = dl_index.as_string()?.read();
+					if dl_id == group_id {
+						date_updated = date_list.get(&dl_id).as_float()?;
+					}
 				}
 			}
 			let id = format!("{}|{}", &folder, &group_id);
@@ -202,7 +238,7 @@ pub fn get_page_list(data: GuyaSiteData, chapter: ObjectRef) -> Result<Vec<Page>
 		.as_string()
 		.expect("Manga chapter object slug not found")
 		.read();
-	let url = format!("{}/api/series/{}/", &data.base_url, &slug);
+	let url = data.series_url(&slug);
 	let request = Request::new(url, HttpMethod::Get).header("User-Agent", "Aidoku");
 	let json = request
 		.json()
@@ -217,16 +253,35 @@ pub fn get_page_list(data: GuyaSiteData, chapter: ObjectRef) -> Result<Vec<Page>
 	let group_id = ids.split('|').collect::<Vec<&str>>()[1].to_string();
 	let chapters_obj = json.get("chapters").as_object()?;
 	let chapter_obj = chapters_obj.get(chapter_num.as_str()).as_object()?;
-	let folder = chapter_obj.get("folder").as_string()?.read();
+	let folder = chapter_obj
+		.get("folder")
+		.as_string()
+		.map(|folder| folder.read())
+		.unwrap_or_default();
 	let groups_obj = chapter_obj.get("groups").as_object()?;
-	let chapter_array = groups_obj.get(group_id.as_str()).as_array()?;
+	let group = groups_obj.get(group_id.as_str());
+	// proxies may give an api path to fetch the images from instead
+	let chapter_array = match group.as_string() {
+		Ok(path) => Request::new(data.absolute_url(&path.read()), HttpMethod::Get)
+			.header("User-Agent", "Aidoku")
+			.json()?
+			.as_array()?,
+		Err(_) => group.as_array()?,
+	};
 	let mut pages: Vec<Page> = Vec::new();
 	for (idx, page) in chapter_array.enumerate() {
-		let page_string = page.as_string()?.read();
-		let page_url = format!(
-			"{}/media/manga/{}/chapters/{}/{}/{}",
-			&data.base_url, &slug, folder, group_id, page_string
-		);
+		let page_string = match page.as_object() {
+			Ok(obj) => obj.get("src").as_string()?.read(),
+			Err(_) => page.as_string()?.read(),
+		};
+		let page_url = if page_string.starts_with("http") {
+			page_string
+		} else {
+			format!(
+				"{}/media/manga/{}/chapters/{}/{}/{}",
+				&data.base_url, &slug, folder, group_id, page_string
+			)
+		};
 		pages.push(Page {
 			index: idx as i32,
 			url: page_url,
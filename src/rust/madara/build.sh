@@ -1,13 +1,22 @@
 # template source build script
 # usage: ./build.sh [source_name/-a]
 
+# write the crates for the sources described by sites/*/site.toml
+(
+    WORKSPACE="$(pwd)"
+    cd ../../../lib/rust/site-generator
+    cargo run --release -q -- "$WORKSPACE"
+) || exit 1
+
 if [ "$1" != "-a" ] && [ "$1" != "" ]; then
     # compile specified source
     cargo +nightly build --release
     
+    dir=generated/$1
+
     echo "packaging $1";
     mkdir -p target/wasm32-unknown-unknown/release/Payload
-    cp sources/$1/res/* target/wasm32-unknown-unknown/release/Payload
+    cp $dir/res/* target/wasm32-unknown-unknown/release/Payload
     cd target/wasm32-unknown-unknown/release
     cp $1.wasm Payload/main.wasm
     zip -r $1.aix Payload
@@ -17,18 +26,21 @@ else
     # compile all sources
     cargo +nightly build --release
 
-    for dir in sources/*/
+    for dir in generated/*/
     do
+        if [ ! -d "$dir" ]; then
+            continue
+        fi
         dir=${dir%*/}
-        dir=${dir##*/}
-        echo "packaging $dir";
+        name=${dir##*/}
+        echo "packaging $name";
 
         mkdir -p target/wasm32-unknown-unknown/release/Payload
-        cp sources/$dir/res/* target/wasm32-unknown-unknown/release/Payload
+        cp $dir/res/* target/wasm32-unknown-unknown/release/Payload
         cd target/wasm32-unknown-unknown/release
-        cp $dir.wasm Payload/main.wasm
-        zip -r $dir.aix Payload >> /dev/null
-        mv $dir.aix ../../../$dir.aix
+        cp $name.wasm Payload/main.wasm
+        zip -r $name.aix Payload >> /dev/null
+        mv $name.aix ../../../$name.aix
         rm -rf Payload
         cd ../../../
     done
@@ -1,20 +1,29 @@
+// Generated from sites/aasq/site.toml, don't edit.
 #![no_std]
 use aidoku::{
-	error::Result, prelude::*, std::String, std::Vec, Chapter, DeepLink, Filter, Listing, Manga,
-	MangaPageResult, Page,
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	Page,
 };
 
 use madara_template::template;
 
 fn get_data() -> template::MadaraSiteData {
-	let data: template::MadaraSiteData = template::MadaraSiteData {
-		base_url: String::from("https://3asq.org"),
+	template::MadaraSiteData {
+		alt_ajax: true,
 		base_id_selector: String::from("h3.h5 > a:not([target])"),
+		base_url: String::from("https://3asq.org"),
 		description_selector: String::from("div.manga-excerpt > p"),
-		alt_ajax: true,
 		..Default::default()
-	};
-	data
+	}
 }
 
 #[get_manga_list]
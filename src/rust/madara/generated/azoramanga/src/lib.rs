@@ -1,19 +1,28 @@
+// Generated from sites/azoramanga/site.toml, don't edit.
 #![no_std]
 use aidoku::{
-	error::Result, prelude::*, std::String, std::Vec, Chapter, DeepLink, Filter, Listing, Manga,
-	MangaPageResult, Page,
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	Page,
 };
 
 use madara_template::template;
 
 fn get_data() -> template::MadaraSiteData {
-	let data: template::MadaraSiteData = template::MadaraSiteData {
+	template::MadaraSiteData {
+		alt_ajax: true,
 		base_url: String::from("https://azoranov.com"),
 		source_path: String::from("series"),
-		alt_ajax: true,
 		..Default::default()
-	};
-	data
+	}
 }
 
 #[get_manga_list]
@@ -1,18 +1,27 @@
+// Generated from sites/disasterscans/site.toml, don't edit.
 #![no_std]
 use aidoku::{
-	error::Result, prelude::*, std::String, std::Vec, Chapter, DeepLink, Filter, Listing, Manga,
-	MangaPageResult, Page,
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	Page,
 };
 
 use madara_template::template;
 
 fn get_data() -> template::MadaraSiteData {
-	let data: template::MadaraSiteData = template::MadaraSiteData {
-		base_url: String::from("https://disasterscans.com"),
+	template::MadaraSiteData {
 		alt_ajax: true,
+		base_url: String::from("https://disasterscans.com"),
 		..Default::default()
-	};
-	data
+	}
 }
 
 #[get_manga_list]
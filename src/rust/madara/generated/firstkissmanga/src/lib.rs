@@ -1,18 +1,28 @@
+// Generated from sites/firstkissmanga/site.toml, don't edit.
 #![no_std]
 use aidoku::{
-	error::Result, prelude::*, std::net::Request, std::String, std::Vec, Chapter, DeepLink, Filter,
-	Listing, Manga, MangaPageResult, Page,
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	std::net::Request,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	Page,
 };
 
 use madara_template::template;
 
 fn get_data() -> template::MadaraSiteData {
-	let data: template::MadaraSiteData = template::MadaraSiteData {
-		base_url: String::from("https://1st-kissmanga.net"),
+	template::MadaraSiteData {
 		alt_ajax: true,
+		base_url: String::from("https://1st-kissmanga.net"),
 		..Default::default()
-	};
-	data
+	}
 }
 
 #[get_manga_list]
@@ -40,12 +50,12 @@ fn get_page_list(_manga_id: String, chapter_id: String) -> Result<Vec<Page>> {
 	template::get_page_list(chapter_id, get_data())
 }
 
+#[modify_image_request]
+fn modify_image_request(request: Request) {
+	template::modify_image_request(String::from("https://1st-kissmanga.net"), request);
+}
+
 #[handle_url]
 pub fn handle_url(url: String) -> Result<DeepLink> {
 	template::handle_url(url, get_data())
 }
-
-#[modify_image_request]
-fn modify_image_request(request: Request) {
-	template::modify_image_request(get_data().base_url, request)
-}
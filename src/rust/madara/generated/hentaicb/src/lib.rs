@@ -1,55 +1,56 @@
+// Generated from sites/hentaicb/site.toml, don't edit.
 #![no_std]
 use aidoku::{
 	error::Result,
 	prelude::*,
 	std::String,
-	std::{net::Request, Vec},
-	Chapter, DeepLink, Filter, Listing, Manga, MangaContentRating, MangaPageResult, MangaStatus,
-	MangaViewer, Page,
+	std::Vec,
+	std::net::Request,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	MangaStatus,
+	Page,
 };
 
-use madara_template::template;
+use madara_template::{strategies, template};
 
 fn get_data() -> template::MadaraSiteData {
-	let data: template::MadaraSiteData = template::MadaraSiteData {
+	template::MadaraSiteData {
+		adult_string: String::from("Truyện 18+"),
+		alt_ajax: true,
+		artist_filter: String::from("Họa sĩ"),
 		base_url: String::from("https://hentaicb.top"),
-		lang: String::from("vi"),
+		genre_condition: String::from("Điều kiện lọc thể loại"),
+		genres_filter: String::from("Thể loại khác"),
 		image_selector: String::from("div.doc-truyen > img"),
-		alt_ajax: true,
-		viewer: |_, categories| {
-			for category in categories {
-				match category.as_str() {
-					"Manhwa" | "Manhua" | "Webtoon" => return MangaViewer::Scroll,
-					_ => continue,
-				}
-			}
-			MangaViewer::Rtl
-		},
+		lang: String::from("vi"),
+		nsfw: strategies::nsfw_always,
+		popular: String::from("Phổ biến"),
 		status: |html| {
-			let status = html
-				.select("div.post-content_item:contains(Tình trạng) div.summary-content")
-				.text()
-				.read();
-			match status.to_lowercase().trim() {
-				"hoàn thành" => MangaStatus::Completed,
-				"đang tiến hành" => MangaStatus::Ongoing,
-				"đã huỷ" => MangaStatus::Cancelled,
-				"tạm ngưng" => MangaStatus::Hiatus,
-				_ => MangaStatus::Unknown,
-			}
+			strategies::status_from_table(
+				html,
+				"div.post-content_item:contains(Tình trạng) div.summary-content",
+				&[
+					("đã huỷ", MangaStatus::Cancelled),
+					("hoàn thành", MangaStatus::Completed),
+					("tạm ngưng", MangaStatus::Hiatus),
+					("đang tiến hành", MangaStatus::Ongoing),
+				],
+			)
 		},
-		nsfw: |_, _| MangaContentRating::Nsfw,
-		status_filter_ongoing: String::from("Đang tiến hành"),
-		status_filter_completed: String::from("Hoàn thành"),
 		status_filter_cancelled: String::from("Đã huỷ"),
+		status_filter_completed: String::from("Hoàn thành"),
 		status_filter_on_hold: String::from("Tạm ngưng"),
-		adult_string: String::from("Truyện 18+"),
-		genre_condition: String::from("Điều kiện lọc thể loại"),
+		status_filter_ongoing: String::from("Đang tiến hành"),
 		trending: String::from("Truyện hot"),
-		popular: String::from("Phổ biến"),
+		viewer: strategies::viewer_from_categories,
+		year_filter: String::from("Năm"),
 		..Default::default()
-	};
-	data
+	}
 }
 
 #[get_manga_list]
@@ -72,16 +73,16 @@ fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
 	template::get_chapter_list(id, get_data())
 }
 
-#[modify_image_request]
-fn modify_image_request(request: Request) {
-	request.header("Referer", "https://hentaicb.top/");
-}
-
 #[get_page_list]
 fn get_page_list(_manga_id: String, chapter_id: String) -> Result<Vec<Page>> {
 	template::get_page_list(chapter_id, get_data())
 }
 
+#[modify_image_request]
+fn modify_image_request(request: Request) {
+	template::modify_image_request(String::from("https://hentaicb.top/"), request);
+}
+
 #[handle_url]
 pub fn handle_url(url: String) -> Result<DeepLink> {
 	template::handle_url(url, get_data())
@@ -1,23 +1,32 @@
+// Generated from sites/hiperdex/site.toml, don't edit.
 #![no_std]
 use aidoku::{
-	error::Result, prelude::*, std::net::Request, std::defaults::defaults_get, std::String, std::Vec, Chapter,
-	DeepLink, Filter, Listing, Manga, MangaPageResult, Page,
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	std::defaults::defaults_get,
+	std::net::Request,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	Page,
 };
 
 use madara_template::template;
 
 fn get_data() -> template::MadaraSiteData {
-	let url = defaults_get("sourceURL")
-		.expect("missing sourceURL")
-		.as_string()
-		.expect("missing sourceURL")
-		.read();
-	let data: template::MadaraSiteData = template::MadaraSiteData {
-		base_url: url,
+	template::MadaraSiteData {
 		alt_ajax: true,
+		base_url: defaults_get("sourceURL")
+			.and_then(|value| value.as_string())
+			.map(|value| value.read())
+			.unwrap_or_else(|_| String::from("https://hiperdex.com")),
 		..Default::default()
-	};
-	data
+	}
 }
 
 #[get_manga_list]
@@ -1,17 +1,27 @@
+// Generated from sites/lscomic/site.toml, don't edit.
 #![no_std]
 use aidoku::{
-	error::Result, prelude::*, std::String, std::Vec, Chapter, DeepLink, Filter, Listing, Manga,
-	MangaPageResult, Page,
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	Page,
 };
 
 use madara_template::template;
 
 fn get_data() -> template::MadaraSiteData {
 	template::MadaraSiteData {
+		alt_ajax: true,
+		author_selector: String::from("div.manga-authors a"),
 		base_url: String::from("https://lscomic.com"),
 		description_selector: String::from("div.manga-about p"),
-		author_selector: String::from("div.manga-authors a"),
-		alt_ajax: true,
 		..Default::default()
 	}
 }
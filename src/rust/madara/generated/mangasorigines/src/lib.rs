@@ -1,41 +1,49 @@
+// Generated from sites/mangasorigines/site.toml, don't edit.
 #![no_std]
 use aidoku::{
-	error::Result, prelude::*, std::net::Request, std::String, std::Vec, Chapter, DeepLink, Filter,
-	Listing, Manga, MangaPageResult, MangaStatus, Page,
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	std::net::Request,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	MangaStatus,
+	Page,
 };
-use madara_template::template;
+
+use madara_template::{strategies, template};
 
 fn get_data() -> template::MadaraSiteData {
-	let data: template::MadaraSiteData = template::MadaraSiteData {
+	template::MadaraSiteData {
+		alt_ajax: true,
 		base_url: String::from("https://mangas-origines.fr"),
-		lang: String::from("fr"),
 		description_selector: String::from("div.manga-excerpt p"),
-		status_filter_ongoing: String::from("En cours"),
-		status_filter_completed: String::from("Terminé"),
-		status_filter_cancelled: String::from("Annulé"),
-		status_filter_on_hold: String::from("En pause"),
+		lang: String::from("fr"),
 		popular: String::from("Populaire"),
-		trending: String::from("Tendance"),
 		status: |html| {
-			let status_str = html
-				.select("div.post-content_item:contains(Statut) div.summary-content")
-				.text()
-				.read()
-				.to_lowercase();
-			let mut status_str = status_str.chars();
-			status_str.next();
-			match status_str.as_str() {
-				"en cours" => MangaStatus::Ongoing,
-				"complété" => MangaStatus::Completed,
-				"annulé" => MangaStatus::Cancelled,
-				"en pause" => MangaStatus::Hiatus,
-				_ => MangaStatus::Unknown,
-			}
+			strategies::status_from_table(
+				html,
+				"div.post-content_item:contains(Statut) div.summary-content",
+				&[
+					("annulé", MangaStatus::Cancelled),
+					("complété", MangaStatus::Completed),
+					("en pause", MangaStatus::Hiatus),
+					("en cours", MangaStatus::Ongoing),
+				],
+			)
 		},
-		alt_ajax: true,
+		status_filter_cancelled: String::from("Annulé"),
+		status_filter_completed: String::from("Terminé"),
+		status_filter_on_hold: String::from("En pause"),
+		status_filter_ongoing: String::from("En cours"),
+		trending: String::from("Tendance"),
 		..Default::default()
-	};
-	data
+	}
 }
 
 #[get_manga_list]
@@ -1,19 +1,29 @@
+// Generated from sites/manhuaplus/site.toml, don't edit.
 #![no_std]
 use aidoku::{
-	error::Result, prelude::*, std::net::Request, std::String, std::Vec, Chapter, DeepLink, Filter,
-	Listing, Manga, MangaPageResult, Page,
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	std::net::Request,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	Page,
 };
 
 use madara_template::template;
 
 fn get_data() -> template::MadaraSiteData {
-	let data: template::MadaraSiteData = template::MadaraSiteData {
-		base_url: String::from("https://manhuaplus.com"),
+	template::MadaraSiteData {
 		alt_ajax: true,
+		base_url: String::from("https://manhuaplus.com"),
 		image_selector: String::from("li.blocks-gallery-item > figure > img, div.page-break > img, div#chapter-video-frame > p > img, div.text-left > p > img"),
 		..Default::default()
-	};
-	data
+	}
 }
 
 #[get_manga_list]
@@ -41,12 +51,12 @@ fn get_page_list(_manga_id: String, chapter_id: String) -> Result<Vec<Page>> {
 	template::get_page_list(chapter_id, get_data())
 }
 
-#[handle_url]
-pub fn handle_url(url: String) -> Result<DeepLink> {
-	template::handle_url(url, get_data())
-}
-
 #[modify_image_request]
 fn modify_image_request(request: Request) {
 	template::modify_image_request(String::from("https://manhuaplus.com"), request);
 }
+
+#[handle_url]
+pub fn handle_url(url: String) -> Result<DeepLink> {
+	template::handle_url(url, get_data())
+}
@@ -1,27 +1,32 @@
+// Generated from sites/mmscans/site.toml, don't edit.
 #![no_std]
 use aidoku::{
-	error::Result, prelude::*, std::String, std::Vec, Chapter, DeepLink, Filter, Listing, Manga,
-	MangaPageResult, Page,
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	Page,
 };
 
 use madara_template::template;
 
 fn get_data() -> template::MadaraSiteData {
-	let data: template::MadaraSiteData = template::MadaraSiteData {
-		// TODO: Fix search
-		// The problem is mm-scans wants search queries like this https://mm-scans.org/?s=
-		// get_filtered_url in the helper wants it to be like this https://mm-scans.org/SEARCHPATH/PAGE/?s=
-		// So some overriding will be necessary, and the search selector possibly needs to be
-		// changed as well
-		base_url: String::from("https://mm-scans.org"),
+	template::MadaraSiteData {
+		alt_ajax: true,
 		base_id_selector: String::from("div.item-summary > a"),
+		base_url: String::from("https://mm-scans.org"),
+		chapter_selector: String::from("li.chapter-li"),
 		description_selector: String::from("div#summary > .summary-text > p"),
+		drop_credit_pages: true,
 		genre_selector: String::from("div.genres-content"),
-		chapter_selector: String::from("li.chapter-li"),
-		alt_ajax: true,
 		..Default::default()
-	};
-	data
+	}
 }
 
 #[get_manga_list]
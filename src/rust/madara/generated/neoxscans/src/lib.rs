@@ -1,35 +1,44 @@
+// Generated from sites/neoxscans/site.toml, don't edit.
 #![no_std]
 use aidoku::{
-	error::Result, prelude::*, std::net::Request, std::String, std::Vec, Chapter, DeepLink, Filter,
-	Listing, Manga, MangaPageResult, MangaStatus, Page,
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	std::net::Request,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	MangaStatus,
+	Page,
 };
 
-use madara_template::template;
+use madara_template::{strategies, template};
 
 fn get_data() -> template::MadaraSiteData {
-	let data: template::MadaraSiteData = template::MadaraSiteData {
+	template::MadaraSiteData {
+		alt_ajax: true,
 		base_url: String::from("https://neoxscans.net"),
+		description_selector: String::from("div.manga-excerpt p"),
+		ignore_class: String::from(".manga-title-badges.custom.novel"),
 		lang: String::from("pt-br"),
 		status: |html| {
-			let status_str = html
-				.select("div.post-content_item:contains(Status) div.summary-content")
-				.text()
-				.read()
-				.to_lowercase();
-			match status_str.as_str() {
-				"em lançamento" => MangaStatus::Ongoing,
-				"completo" => MangaStatus::Completed,
-				"cancelado" => MangaStatus::Cancelled,
-				"em pausa" => MangaStatus::Hiatus,
-				_ => MangaStatus::Unknown,
-			}
+			strategies::status_from_table(
+				html,
+				"div.post-content_item:contains(Status) div.summary-content",
+				&[
+					("cancelado", MangaStatus::Cancelled),
+					("completo", MangaStatus::Completed),
+					("em pausa", MangaStatus::Hiatus),
+					("em lançamento", MangaStatus::Ongoing),
+				],
+			)
 		},
-		description_selector: String::from("div.manga-excerpt p"),
-		alt_ajax: true,
-		ignore_class: String::from(".manga-title-badges.custom.novel"),
 		..Default::default()
-	};
-	data
+	}
 }
 
 #[get_manga_list]
@@ -57,12 +66,12 @@ fn get_page_list(_manga_id: String, chapter_id: String) -> Result<Vec<Page>> {
 	template::get_page_list(chapter_id, get_data())
 }
 
-#[handle_url]
-pub fn handle_url(url: String) -> Result<DeepLink> {
-	template::handle_url(url, get_data())
-}
-
 #[modify_image_request]
 fn modify_image_request(request: Request) {
 	template::modify_image_request(String::from("https://neoxscans.net"), request);
 }
+
+#[handle_url]
+pub fn handle_url(url: String) -> Result<DeepLink> {
+	template::handle_url(url, get_data())
+}
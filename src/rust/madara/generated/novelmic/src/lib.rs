@@ -1,18 +1,29 @@
+// Generated from sites/novelmic/site.toml, don't edit.
 #![no_std]
 use aidoku::{
-	error::Result, prelude::*, std::String, std::Vec, Chapter, DeepLink, Filter, Listing, Manga,
-	MangaPageResult, Page,
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	Page,
 };
 
 use madara_template::template;
 
 fn get_data() -> template::MadaraSiteData {
-	let data: template::MadaraSiteData = template::MadaraSiteData {
+	template::MadaraSiteData {
 		base_url: String::from("https://novelmic.com"),
+		ignore_class: String::from(""),
 		source_path: String::from("comic"),
+		text_chapters: true,
 		..Default::default()
-	};
-	data
+	}
 }
 
 #[get_manga_list]
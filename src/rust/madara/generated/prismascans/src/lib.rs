@@ -1,16 +1,26 @@
+// Generated from sites/prismascans/site.toml, don't edit.
 #![no_std]
 use aidoku::{
-	error::Result, prelude::*, std::String, std::Vec, Chapter, DeepLink, Filter, Listing, Manga,
-	MangaPageResult, Page,
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	Page,
 };
 
 use madara_template::template;
 
 fn get_data() -> template::MadaraSiteData {
 	template::MadaraSiteData {
+		alt_ajax: true,
 		base_url: String::from("https://prismacomics.com"),
 		description_selector: String::from("div.summary_content_wrap div p"),
-		alt_ajax: true,
 		..Default::default()
 	}
 }
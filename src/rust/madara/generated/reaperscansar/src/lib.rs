@@ -1,37 +1,45 @@
+// Generated from sites/reaperscansar/site.toml, don't edit.
 #![no_std]
 use aidoku::{
-	error::Result, prelude::*, std::String, std::Vec, Chapter, DeepLink, Filter, Listing, Manga,
-	MangaPageResult, MangaStatus, Page,
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	MangaStatus,
+	Page,
 };
 
-use madara_template::template;
+use madara_template::{strategies, template};
 
 fn get_data() -> template::MadaraSiteData {
-	let data: template::MadaraSiteData = template::MadaraSiteData {
+	template::MadaraSiteData {
+		alt_ajax: true,
 		base_url: String::from("https://reaperscansar.com"),
 		source_path: String::from("series"),
-		alt_ajax: true,
-		status_filter_ongoing: String::from("مستمر"),
-		status_filter_completed: String::from("مكتمل"),
-		status_filter_cancelled: String::from("ملغى"),
-		status_filter_on_hold: String::from("متوقفة"),
 		status: |html| {
-			let status_str = html
-				.select("div.post-content_item:contains(الحالة) div.summary-content")
-				.text()
-				.read()
-				.to_lowercase();
-			match status_str.as_str() {
-				"مستمر" => MangaStatus::Ongoing,
-				"مكتمل" => MangaStatus::Completed,
-				"ملغى" => MangaStatus::Cancelled,
-				"On Hold" => MangaStatus::Hiatus,
-				_ => MangaStatus::Unknown,
-			}
+			strategies::status_from_table(
+				html,
+				"div.post-content_item:contains(الحالة) div.summary-content",
+				&[
+					("ملغى", MangaStatus::Cancelled),
+					("مكتمل", MangaStatus::Completed),
+					("On Hold", MangaStatus::Hiatus),
+					("مستمر", MangaStatus::Ongoing),
+				],
+			)
 		},
+		status_filter_cancelled: String::from("ملغى"),
+		status_filter_completed: String::from("مكتمل"),
+		status_filter_on_hold: String::from("متوقفة"),
+		status_filter_ongoing: String::from("مستمر"),
 		..Default::default()
-	};
-	data
+	}
 }
 
 #[get_manga_list]
@@ -0,0 +1,77 @@
+// Generated from sites/reaperscansfr/site.toml, don't edit.
+#![no_std]
+use aidoku::{
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	MangaStatus,
+	Page,
+};
+
+use madara_template::{strategies, template};
+
+fn get_data() -> template::MadaraSiteData {
+	template::MadaraSiteData {
+		alt_ajax: true,
+		base_url: String::from("https://reaperscans.fr"),
+		lang: String::from("fr"),
+		popular: String::from("Populaire"),
+		source_path: String::from("serie"),
+		status: |html| {
+			strategies::status_from_table(
+				html,
+				"div.post-content_item:contains(Statut) div.summary-content",
+				&[
+					("canceled", MangaStatus::Cancelled),
+					("dropped", MangaStatus::Cancelled),
+					("completed", MangaStatus::Completed),
+					("on hold", MangaStatus::Hiatus),
+					("ongoing", MangaStatus::Ongoing),
+				],
+			)
+		},
+		status_filter_cancelled: String::from("Annulé"),
+		status_filter_completed: String::from("Terminé"),
+		status_filter_on_hold: String::from("En pause"),
+		status_filter_ongoing: String::from("En cours"),
+		trending: String::from("Tendance"),
+		..Default::default()
+	}
+}
+
+#[get_manga_list]
+fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
+	template::get_manga_list(filters, page, get_data())
+}
+
+#[get_manga_listing]
+fn get_manga_listing(listing: Listing, page: i32) -> Result<MangaPageResult> {
+	template::get_manga_listing(get_data(), listing, page)
+}
+
+#[get_manga_details]
+fn get_manga_details(id: String) -> Result<Manga> {
+	template::get_manga_details(id, get_data())
+}
+
+#[get_chapter_list]
+fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
+	template::get_chapter_list(id, get_data())
+}
+
+#[get_page_list]
+fn get_page_list(_manga_id: String, chapter_id: String) -> Result<Vec<Page>> {
+	template::get_page_list(chapter_id, get_data())
+}
+
+#[handle_url]
+pub fn handle_url(url: String) -> Result<DeepLink> {
+	template::handle_url(url, get_data())
+}
@@ -1,70 +1,63 @@
+// Generated from sites/yocomic/site.toml, don't edit.
 #![no_std]
 use aidoku::{
-	error::Result, prelude::*, std::String, std::Vec, Chapter, DeepLink, Filter, Listing, Manga,
-	MangaContentRating, MangaPageResult, MangaStatus, MangaViewer, Page,
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	MangaStatus,
+	Page,
 };
 
-use madara_template::template;
+use madara_template::{strategies, template};
 
 fn get_data() -> template::MadaraSiteData {
-	let data: template::MadaraSiteData = template::MadaraSiteData {
+	template::MadaraSiteData {
+		adult_string: String::from("Truyện 18+"),
+		alt_ajax: true,
+		artist_filter: String::from("Họa sĩ"),
 		base_url: String::from("https://yocomic.com"),
-		lang: String::from("vi"),
-		source_path: String::from("truyen"),
+		genre_condition: String::from("Điều kiện lọc thể loại"),
 		genre_selector: String::from("div.genres > a"),
-		alt_ajax: true,
-		viewer: |_, categories| {
-			for category in categories {
-				match category.as_str() {
-					"Manhwa" | "Manhua" | "Webtoon" => return MangaViewer::Scroll,
-					_ => continue,
-				}
-			}
-			MangaViewer::Rtl
+		genres_filter: String::from("Thể loại khác"),
+		lang: String::from("vi"),
+		nsfw: |html, categories| {
+			strategies::nsfw_from_table(
+				html,
+				categories,
+				&["smut", "mature", "adult", "truyện 18+"],
+				&["ecchi", "16+"],
+			)
 		},
+		popular: String::from("Phổ biến"),
+		source_path: String::from("truyen"),
 		status: |html| {
-			let status = html.select("div.post-status").text().read();
-			match status.trim() {
-				"completed" => MangaStatus::Completed,
-				"ongoing" => MangaStatus::Ongoing,
-				"canceled" => MangaStatus::Cancelled,
-				"on hold" => MangaStatus::Hiatus,
-				_ => MangaStatus::Unknown,
-			}
+			strategies::status_from_table(
+				html,
+				"div.post-status",
+				&[
+					("canceled", MangaStatus::Cancelled),
+					("completed", MangaStatus::Completed),
+					("on hold", MangaStatus::Hiatus),
+					("ongoing", MangaStatus::Ongoing),
+				],
+			)
 		},
-		nsfw: |html, categories| {
-			if !html
-				.select(".manga-title-badges.adult")
-				.text()
-				.read()
-				.is_empty()
-			{
-				MangaContentRating::Nsfw
-			} else {
-				let mut nsfw = MangaContentRating::Safe;
-				for category in categories {
-					match category.to_lowercase().as_str() {
-						"smut" | "mature" | "adult" | "truyện 18+" => {
-							return MangaContentRating::Nsfw
-						}
-						"ecchi" | "16+" => nsfw = MangaContentRating::Suggestive,
-						_ => continue,
-					}
-				}
-				nsfw
-			}
-		},
-		status_filter_ongoing: String::from("Đang tiến hành"),
-		status_filter_completed: String::from("Đã hoàn thành"),
 		status_filter_cancelled: String::from("Đã bị huỷ/Ngừng dịch"),
+		status_filter_completed: String::from("Đã hoàn thành"),
 		status_filter_on_hold: String::from("Tạm ngưng/Ngang raw"),
-		adult_string: String::from("Truyện 18+"),
-		genre_condition: String::from("Điều kiện lọc thể loại"),
+		status_filter_ongoing: String::from("Đang tiến hành"),
 		trending: String::from("Truyện hot"),
-		popular: String::from("Phổ biến"),
+		viewer: strategies::viewer_from_categories,
+		year_filter: String::from("Năm"),
 		..Default::default()
-	};
-	data
+	}
 }
 
 #[get_manga_list]
@@ -0,0 +1,99 @@
+//! Genres read from the site's advanced search form, so that searches keep
+//! working when a site changes a genre's id. This doesn't change the genres
+//! users pick from, which are still the ones in `filters.json`: only their
+//! ids are looked up here, along with the names typed into `Other Genres`.
+use aidoku::{
+	prelude::format,
+	std::defaults::{defaults_get, defaults_set},
+	std::net::{HttpMethod, Request},
+	std::{current_date, ArrayRef, String, StringRef, Vec},
+};
+
+use crate::template::MadaraSiteData;
+
+const GENRES_KEY: &str = "madaraGenres";
+const UPDATED_KEY: &str = "madaraGenresUpdated";
+
+/// How long the genres are cached for, a week.
+const MAX_AGE: f64 = 7.0 * 24.0 * 60.0 * 60.0;
+
+pub struct Genre {
+	pub id: String,
+	pub name: String,
+}
+
+/// The site's genres, from the cache if it's recent enough.
+pub fn get_genres(data: &MadaraSiteData) -> Vec<Genre> {
+	let cached = cached_genres();
+	let updated = defaults_get(UPDATED_KEY)
+		.and_then(|value| value.as_string())
+		.ok()
+		.and_then(|value| value.read().parse::<f64>().ok())
+		.unwrap_or(0.0);
+	if !cached.is_empty() && current_date() - updated < MAX_AGE {
+		return cached;
+	}
+
+	let genres = fetch_genres(data);
+	if genres.is_empty() {
+		return cached;
+	}
+	let mut arr = ArrayRef::new();
+	for genre in &genres {
+		arr.insert(StringRef::from(format!("{}\t{}", genre.id, genre.name)).0);
+	}
+	defaults_set(GENRES_KEY, arr.0);
+	defaults_set(
+		UPDATED_KEY,
+		StringRef::from(format!("{}", current_date() as i64)).0,
+	);
+	genres
+}
+
+/// Looks a genre up by its name or id, ignoring case.
+pub fn find_genre<'a>(genres: &'a [Genre], name: &str) -> Option<&'a Genre> {
+	let name = name.trim().to_lowercase();
+	genres
+		.iter()
+		.find(|genre| genre.name.to_lowercase() == name || genre.id.to_lowercase() == name)
+}
+
+fn fetch_genres(data: &MadaraSiteData) -> Vec<Genre> {
+	let url = format!("{}/?s=&post_type={}", data.base_url, data.post_type);
+	let Ok(html) = Request::new(url, HttpMethod::Get).html() else {
+		return Vec::new();
+	};
+	html.select(&data.search_genre_selector)
+		.array()
+		.filter_map(|item| item.as_node().ok())
+		.filter_map(|node| {
+			let id = node.select("input").attr("value").read();
+			let name = node.select("label").text().read();
+			if id.is_empty() || name.trim().is_empty() {
+				None
+			} else {
+				Some(Genre {
+					id,
+					name: String::from(name.trim()),
+				})
+			}
+		})
+		.collect()
+}
+
+fn cached_genres() -> Vec<Genre> {
+	match defaults_get(GENRES_KEY).and_then(|value| value.as_array()) {
+		Ok(array) => array
+			.filter_map(|entry| entry.as_string().ok())
+			.filter_map(|entry| {
+				let entry = entry.read();
+				let (id, name) = entry.split_once('\t')?;
+				Some(Genre {
+					id: String::from(id),
+					name: String::from(name),
+				})
+			})
+			.collect(),
+		Err(_) => Vec::new(),
+	}
+}
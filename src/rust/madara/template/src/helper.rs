@@ -3,7 +3,10 @@ use aidoku::{
 	std::String, std::Vec, Filter, FilterType,
 };
 
-use crate::template::MadaraSiteData;
+use crate::{
+	genres::{find_genre, get_genres},
+	template::MadaraSiteData,
+};
 
 extern crate alloc;
 use alloc::string::ToString;
@@ -63,6 +66,15 @@ pub fn img_url_encode(string: String) -> String {
 	String::from_utf8(result).unwrap_or_default()
 }
 
+/// Madara's genre ids are slugs of their names.
+fn genre_slug(name: &str) -> String {
+	name.trim()
+		.to_lowercase()
+		.split_whitespace()
+		.collect::<Vec<_>>()
+		.join("-")
+}
+
 pub fn get_image_url(obj: Node) -> String {
 	let mut img = obj.attr("data-src").read();
 	if img.is_empty() {
@@ -106,6 +118,8 @@ pub fn get_filtered_url(filters: Vec<Filter>, page: i32, data: &MadaraSiteData)
 	let mut search_string = String::new();
 	let mut url = data.base_url.clone();
 	let post_type = String::from("&post_type=") + &data.post_type.clone();
+	let mut genre_filters: Vec<(String, String)> = Vec::new();
+	let mut typed_genres: Vec<String> = Vec::new();
 
 	for filter in filters {
 		match filter.kind {
@@ -117,8 +131,13 @@ pub fn get_filtered_url(filters: Vec<Filter>, page: i32, data: &MadaraSiteData)
 			}
 			FilterType::Author => {
 				if let Ok(filter_value) = filter.value.as_string() {
+					let author = filter_value.read();
+					if author.trim().is_empty() {
+						continue;
+					}
 					query.push_str("&author=");
-					query.push_str(&urlencode(filter_value.read()));
+					query.push_str(&urlencode(author));
+					is_searching = true;
 				}
 			}
 			FilterType::Check => {
@@ -138,12 +157,33 @@ pub fn get_filtered_url(filters: Vec<Filter>, page: i32, data: &MadaraSiteData)
 				is_searching = true;
 			}
 			FilterType::Genre => {
-				query.push_str("&genre[]=");
 				if let Ok(filter_id) = filter.object.get("id").as_string() {
-					query.push_str(filter_id.read().as_str());
+					genre_filters.push((filter_id.read(), filter.name));
 					is_searching = true;
 				}
 			}
+			FilterType::Text => {
+				let value = match filter.value.as_string() {
+					Ok(value) => value.read(),
+					Err(_) => continue,
+				};
+				if value.trim().is_empty() {
+					continue;
+				}
+				if filter.name == data.artist_filter {
+					query.push_str("&artist=");
+					query.push_str(&urlencode(value));
+				} else if filter.name == data.year_filter {
+					// Madara calls the year a series started its release
+					query.push_str("&release=");
+					query.push_str(&urlencode(String::from(value.trim())));
+				} else if filter.name == data.genres_filter {
+					typed_genres.extend(value.split(',').map(String::from));
+				} else {
+					continue;
+				}
+				is_searching = true;
+			}
 			FilterType::Select => {
 				if filter.name == data.genre_condition {
 					match filter.value.as_int().unwrap_or(-1) {
@@ -171,6 +211,30 @@ pub fn get_filtered_url(filters: Vec<Filter>, page: i32, data: &MadaraSiteData)
 		}
 	}
 
+	if !genre_filters.is_empty() || !typed_genres.is_empty() {
+		// the site's current ids, in case they changed since filters.json was made
+		let genres = get_genres(data);
+		for (id, name) in genre_filters {
+			query.push_str("&genre[]=");
+			match find_genre(&genres, &name) {
+				Some(genre) => query.push_str(&genre.id),
+				None => query.push_str(&id),
+			}
+		}
+		for name in typed_genres {
+			if name.trim().is_empty() {
+				continue;
+			}
+			query.push_str("&genre[]=");
+			match find_genre(&genres, &name) {
+				Some(genre) => query.push_str(&genre.id),
+				// sent as a slug rather than left out, so that a genre the site
+				// doesn't have finds nothing instead of everything else
+				None => query.push_str(&urlencode(genre_slug(&name))),
+			}
+		}
+	}
+
 	if is_searching {
 		url.push('/');
 		url.push_str(&data.search_path);
@@ -187,15 +251,68 @@ pub fn get_filtered_url(filters: Vec<Filter>, page: i32, data: &MadaraSiteData)
 pub fn get_int_manga_id(manga_id: String, base_url: String, path: String) -> String {
 	let url = base_url + "/" + path.as_str() + "/" + manga_id.as_str();
 	if let Ok(html) = Request::new(url.as_str(), HttpMethod::Get).html() {
+		// newer versions don't have the script, they list chapters without the id
 		let id_html = html.select("script#wp-manga-js-extra").html().read();
-		let id = &id_html[id_html.find("manga_id").expect("Could not find manga_id") + 11
-			..id_html.find("\"}").expect("Could not find end of manga_id")];
-		String::from(id)
+		match (id_html.find("manga_id"), id_html.find("\"}")) {
+			(Some(start), Some(end)) if start + 11 <= end => {
+				String::from(&id_html[start + 11..end])
+			}
+			_ => String::new(),
+		}
 	} else {
 		String::new()
 	}
 }
 
+/// Chapter ids are their URL without the base URL and source path, which is
+/// `{manga_id}/{chapter}/`.
+pub fn get_chapter_id(url: &str, data: &MadaraSiteData) -> String {
+	url.replace(&(data.base_url.clone() + "/"), "")
+		.replace(&(data.source_path.clone() + "/"), "")
+}
+
+/// Chapter number is first occourance of a number in the last element of url
+/// when split with "/"
+/// e.g.
+/// one-piece-color-jk-english/volume-20-showdown-at-alubarna/chapter-177-30-million-vs-81-million/
+/// will return 177
+/// parasite-chromatique-french/volume-10/chapitre-062-5/
+/// will return 62.5
+pub fn get_chapter_number(id: &str) -> f32 {
+	let last_segment = id.trim_end_matches('/').rsplit('/').next().unwrap_or(id);
+	let dash_vec = last_segment.split('-').collect::<Vec<&str>>();
+
+	let mut is_decimal = false;
+	let mut chapter = 0.0;
+	for obj in dash_vec {
+		let mut item = {
+			let mut obj = obj;
+			if obj.contains('_') {
+				obj = obj.split('_').next().unwrap_or(obj);
+			}
+			obj.replace('/', "").parse::<f32>().unwrap_or(-1.0)
+		};
+		if item == -1.0 {
+			item = obj
+				.chars()
+				.next()
+				.and_then(|c| c.to_digit(10))
+				.map(|digit| digit as f32)
+				.unwrap_or(-1.0);
+		}
+		if item != -1.0 {
+			if is_decimal {
+				chapter += item / 10.0;
+				break;
+			} else {
+				chapter = item;
+				is_decimal = true;
+			}
+		}
+	}
+	chapter
+}
+
 pub fn get_lang_code() -> Option<String> {
 	if let Ok(languages_val) = defaults_get("languages") {
 		if let Ok(languages) = languages_val.as_array() {
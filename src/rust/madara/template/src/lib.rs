@@ -1,3 +1,7 @@
 #![no_std]
+mod genres;
 pub mod helper;
+mod novel;
+mod protector;
+pub mod strategies;
 pub mod template;
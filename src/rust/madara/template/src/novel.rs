@@ -0,0 +1,102 @@
+//! Text chapters of web novel sites, read from the chapter's HTML into
+//! `Page.text` pages.
+use aidoku::{
+	std::{defaults::defaults_get, html::Node, String, Vec},
+	Page,
+};
+use page_filter::PageFilter;
+
+use crate::{helper::get_image_url, template::MadaraSiteData};
+
+/// Roughly how many characters go on a page. Paragraphs are never split, so
+/// a page can be longer.
+const PAGE_LENGTH: usize = 4000;
+
+/// The chapter text in paragraphs, with its images as image pages where
+/// they are in the text.
+pub fn get_text_pages(html: &Node, data: &MadaraSiteData) -> Vec<Page> {
+	let content = html.select(data.text_selector.as_str()).first();
+	let setting = defaults_get("dropCreditPages")
+		.and_then(|value| value.as_bool())
+		.ok();
+	let filter = PageFilter::for_source(data.page_blocklist, data.drop_credit_pages, setting);
+	let mut pages = TextPages::default();
+
+	let elements = content.select("p, img").array();
+	if elements.is_empty() {
+		// no paragraphs, only lines separated by <br>
+		let markup = content
+			.html()
+			.read()
+			.replace("<br />", "<br>")
+			.replace("<br/>", "<br>");
+		for line in markup.split("<br>") {
+			if let Ok(line) = Node::new(line.as_bytes()) {
+				pages.push_paragraph(line.text().read());
+			}
+		}
+	} else {
+		for element in elements {
+			let Ok(element) = element.as_node() else {
+				continue;
+			};
+			if element.outer_html().read().starts_with("<img") {
+				let url = get_image_url(element);
+				if !url.is_empty() && !filter.is_blocked(&url) {
+					pages.push_image(url);
+				}
+			} else {
+				pages.push_paragraph(element.text().read());
+			}
+		}
+	}
+	pages.finish()
+}
+
+#[derive(Default)]
+struct TextPages {
+	pages: Vec<Page>,
+	text: String,
+}
+
+impl TextPages {
+	fn push_paragraph(&mut self, paragraph: String) {
+		let paragraph = paragraph.trim();
+		if paragraph.is_empty() {
+			return;
+		}
+		if !self.text.is_empty() {
+			if self.text.chars().count() + paragraph.chars().count() > PAGE_LENGTH {
+				self.flush();
+			} else {
+				self.text.push_str("\n\n");
+			}
+		}
+		self.text.push_str(paragraph);
+	}
+
+	fn push_image(&mut self, url: String) {
+		self.flush();
+		self.pages.push(Page {
+			index: self.pages.len() as i32,
+			url,
+			..Default::default()
+		});
+	}
+
+	fn flush(&mut self) {
+		if self.text.is_empty() {
+			return;
+		}
+		self.pages.push(Page {
+			index: self.pages.len() as i32,
+			text: core::mem::take(&mut self.text),
+			..Default::default()
+		});
+	}
+
+	fn finish(mut self) -> Vec<Page> {
+		self.flush();
+		self.pages
+	}
+}
@@ -0,0 +1,181 @@
+//! Chapters of sites using the WP-Manga Chapter Protector plugin, which
+//! leaves the images out of the page and puts their URLs in a script
+//! instead, encrypted with CryptoJS and a per-chapter nonce:
+//!
+//! ```js
+//! var wpmangaprotectornonce='…';var chapter_data='{"ct":"…","iv":"…","s":"…"}';
+//! ```
+use aidoku::{
+	error::{AidokuError, AidokuErrorKind, NodeError, Result},
+	std::{html::Node, json::parse, String, Vec},
+};
+use crypto_helpers::{
+	cryptojs,
+	encoding::{base64_decode, hex_decode, to_utf8},
+};
+
+const SCRIPT_SELECTOR: &str = "script#chapter-protector-data";
+const DATA_URI: &str = "data:text/javascript;base64,";
+
+/// The image URLs of a protected chapter, or none if the page doesn't have
+/// the protector script.
+pub fn get_protected_images(html: &Node) -> Result<Vec<String>> {
+	let script = html.select(SCRIPT_SELECTOR);
+	let code =
+		script_code(&script.attr("src").read(), script.data().read()).ok_or_else(parse_error)?;
+	if code.trim().is_empty() {
+		return Ok(Vec::new());
+	}
+
+	let (password, chapter_data) = payload(&code).ok_or_else(parse_error)?;
+	let chapter_data = parse(chapter_data)?.as_object()?;
+	let plaintext = decrypt(
+		password,
+		&chapter_data.get("ct").as_string()?.read(),
+		&chapter_data.get("s").as_string()?.read(),
+	)
+	.map_err(|_| parse_error())?;
+
+	// the URL list is JSON encoded twice
+	let images = parse(parse(plaintext)?.as_string()?.read())?.as_array()?;
+	Ok(images
+		.filter_map(|image| image.as_string().ok())
+		.map(|image| String::from(image.read().trim()))
+		.filter(|image| !image.is_empty())
+		.collect())
+}
+
+/// The code of the protector script, which is either inline or a base64
+/// `data:` URI in its `src`.
+fn script_code(src: &str, inline: String) -> Option<String> {
+	match src.strip_prefix(DATA_URI) {
+		Some(encoded) => base64_decode(encoded).and_then(to_utf8).ok(),
+		None => Some(inline),
+	}
+}
+
+/// The nonce and the `chapter_data` JSON in the protector script's code.
+fn payload(code: &str) -> Option<(&str, &str)> {
+	Some((
+		between(code, "wpmangaprotectornonce='", "';")?,
+		between(code, "chapter_data='", "';")?,
+	))
+}
+
+/// Decrypts the base64 `ct` of the chapter data with its hex salt `s`, into
+/// the JSON encoded URL list.
+fn decrypt(password: &str, ciphertext: &str, salt: &str) -> crypto_helpers::Result<String> {
+	let ciphertext = base64_decode(ciphertext)?;
+	let salt = hex_decode(salt)?;
+	cryptojs::decrypt_with_salt(&ciphertext, &salt, password.as_bytes()).and_then(to_utf8)
+}
+
+fn between<'a>(text: &'a str, start: &str, end: &str) -> Option<&'a str> {
+	let (_, rest) = text.split_once(start)?;
+	rest.split_once(end).map(|(value, _)| value)
+}
+
+fn parse_error() -> AidokuError {
+	AidokuError {
+		reason: AidokuErrorKind::NodeError(NodeError::ParseError),
+	}
+}
+
+/// `json::parse` and `Node` need the Aidoku runtime, so these cover what
+/// happens around them, on chapter pages saved from a protected site.
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use aidoku::prelude::format;
+
+	const INLINE: &str = include_str!("../tests/fixtures/protector-inline.html");
+	const DATA_URI_PAGE: &str = include_str!("../tests/fixtures/protector-data-uri.html");
+	const UNPROTECTED: &str = include_str!("../tests/fixtures/unprotected.html");
+
+	/// The `src` and the inline code of the protector script, which is what
+	/// `get_protected_images` reads from the page.
+	fn script(html: &str) -> (&str, String) {
+		let Some(tag) = between(html, "chapter-protector-data", "</script>") else {
+			return ("", String::new());
+		};
+		let (attributes, inline) = tag.split_once('>').unwrap();
+		let src = between(attributes, "src=\"", "\"").unwrap_or_default();
+		(src, String::from(inline))
+	}
+
+	/// A string of the chapter data, without the JSON escapes of its `/`.
+	fn field(chapter_data: &str, key: &str) -> String {
+		between(chapter_data, &format!("\"{key}\":\""), "\"")
+			.unwrap()
+			.replace("\\/", "/")
+	}
+
+	#[test]
+	fn reads_inline_and_data_uri_scripts() {
+		let (src, inline) = script(INLINE);
+		let code = script_code(src, inline).unwrap();
+		assert!(code
+			.trim()
+			.starts_with("var wpmangaprotectornonce='5f1e2d3c4b';"));
+
+		let (src, inline) = script(DATA_URI_PAGE);
+		assert!(src.starts_with(DATA_URI));
+		assert_eq!(script_code(src, inline).unwrap(), code.trim());
+	}
+
+	#[test]
+	fn skips_pages_without_protector() {
+		let (src, inline) = script(UNPROTECTED);
+		assert_eq!(script_code(src, inline), Some(String::new()));
+		assert_eq!(payload(""), None);
+	}
+
+	#[test]
+	fn rejects_broken_data_uri() {
+		assert_eq!(
+			script_code("data:text/javascript;base64,%%%", String::new()),
+			None
+		);
+	}
+
+	#[test]
+	fn decrypts_the_url_list() {
+		for html in [INLINE, DATA_URI_PAGE] {
+			let (src, inline) = script(html);
+			let code = script_code(src, inline).unwrap();
+			let (password, chapter_data) = payload(&code).unwrap();
+			assert_eq!(password, "5f1e2d3c4b");
+
+			let plaintext = decrypt(
+				password,
+				&field(chapter_data, "ct"),
+				&field(chapter_data, "s"),
+			)
+			.unwrap();
+			assert_eq!(
+				plaintext,
+				r#""[\"https:\\/\\/example.com\\/wp-content\\/uploads\\/WP-manga\\/data\\/manga_1\\/ch_1\\/01.jpg\",\"https:\\/\\/example.com\\/wp-content\\/uploads\\/WP-manga\\/data\\/manga_1\\/ch_1\\/02.jpg\"]""#
+			);
+		}
+	}
+
+	#[test]
+	fn rejects_missing_or_wrong_data() {
+		let (src, inline) = script(INLINE);
+		let code = script_code(src, inline).unwrap();
+		assert_eq!(
+			payload(&code.replace("wpmangaprotectornonce", "nonce")),
+			None
+		);
+		assert_eq!(payload(&code.replace("chapter_data", "data")), None);
+
+		let (_, chapter_data) = payload(&code).unwrap();
+		let ct = field(chapter_data, "ct");
+		let s = field(chapter_data, "s");
+		assert_eq!(decrypt("wrong", &ct, &s), Err(crypto_helpers::Error::Unpad));
+		assert_eq!(
+			decrypt("5f1e2d3c4b", &ct, "salt"),
+			Err(crypto_helpers::Error::Hex)
+		);
+	}
+}
@@ -0,0 +1,113 @@
+//! Named implementations of the `MadaraSiteData` callbacks. Sources
+//! generated from a `site.toml` pick them by name instead of writing their
+//! own closures.
+use aidoku::{
+	std::{html::Node, String, Vec},
+	MangaContentRating, MangaStatus, MangaViewer,
+};
+
+/// For sites whose chapter list endpoint takes the slug, so the post id
+/// doesn't need an extra request.
+pub fn slug_manga_id(manga_id: String, _: String, _: String) -> String {
+	manga_id
+}
+
+/// Scroll for manhwa, manhua and webtoons, right to left for the rest.
+pub fn viewer_from_categories(_: &Node, categories: &Vec<String>) -> MangaViewer {
+	let webtoon_tags = ["manhwa", "manhua", "webtoon"];
+	if categories
+		.iter()
+		.any(|category| webtoon_tags.contains(&category.to_lowercase().as_str()))
+	{
+		MangaViewer::Scroll
+	} else {
+		MangaViewer::Rtl
+	}
+}
+
+/// Scroll when the text of `selector` is one of `scroll_types`, ignoring
+/// case, for sites that show the type of a series instead of tagging it.
+pub fn viewer_from_table(html: &Node, selector: &str, scroll_types: &[&str]) -> MangaViewer {
+	let type_str = html.select(selector).text().read();
+	if scroll_types
+		.iter()
+		.any(|name| name.eq_ignore_ascii_case(type_str.trim()))
+	{
+		MangaViewer::Scroll
+	} else {
+		MangaViewer::Rtl
+	}
+}
+
+pub fn viewer_scroll(_: &Node, _: &Vec<String>) -> MangaViewer {
+	MangaViewer::Scroll
+}
+
+pub fn viewer_rtl(_: &Node, _: &Vec<String>) -> MangaViewer {
+	MangaViewer::Rtl
+}
+
+pub fn viewer_ltr(_: &Node, _: &Vec<String>) -> MangaViewer {
+	MangaViewer::Ltr
+}
+
+pub fn nsfw_safe(_: &Node, _: &Vec<String>) -> MangaContentRating {
+	MangaContentRating::Safe
+}
+
+pub fn nsfw_suggestive(_: &Node, _: &Vec<String>) -> MangaContentRating {
+	MangaContentRating::Suggestive
+}
+
+pub fn nsfw_always(_: &Node, _: &Vec<String>) -> MangaContentRating {
+	MangaContentRating::Nsfw
+}
+
+/// Nsfw if the series has the adult badge or one of `nsfw_tags`, suggestive
+/// if it has one of `suggestive_tags`. Tags are compared ignoring case.
+pub fn nsfw_from_table(
+	html: &Node,
+	categories: &Vec<String>,
+	nsfw_tags: &[&str],
+	suggestive_tags: &[&str],
+) -> MangaContentRating {
+	let has_tag = |tags: &[&str]| {
+		categories.iter().any(|category| {
+			tags.iter()
+				.any(|tag| tag.to_lowercase() == category.to_lowercase())
+		})
+	};
+	if !html
+		.select(".manga-title-badges.adult")
+		.text()
+		.read()
+		.is_empty()
+		|| has_tag(nsfw_tags)
+	{
+		MangaContentRating::Nsfw
+	} else if has_tag(suggestive_tags) {
+		MangaContentRating::Suggestive
+	} else {
+		MangaContentRating::Safe
+	}
+}
+
+/// Looks the text of `selector` up in a table of localized status names,
+/// ignoring case and the icons some sites put around it.
+pub fn status_from_table(
+	html: &Node,
+	selector: &str,
+	table: &[(&str, MangaStatus)],
+) -> MangaStatus {
+	let status_str = html
+		.select(selector)
+		.text()
+		.read()
+		.trim_matches(|c: char| !c.is_alphanumeric())
+		.to_lowercase();
+	table
+		.iter()
+		.find(|(name, _)| name.to_lowercase() == status_str)
+		.map(|(_, status)| *status)
+		.unwrap_or(MangaStatus::Unknown)
+}
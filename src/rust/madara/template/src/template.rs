@@ -2,6 +2,7 @@ use aidoku::{
 	error::Result,
 	prelude::*,
 	std::current_date,
+	std::defaults::defaults_get,
 	std::net::HttpMethod,
 	std::net::Request,
 	std::String,
@@ -11,7 +12,8 @@ use aidoku::{
 	MangaViewer, Page,
 };
 
-use crate::helper::*;
+use crate::{helper::*, novel::get_text_pages, protector::get_protected_images};
+use page_filter::PageFilter;
 
 extern crate alloc;
 use alloc::string::ToString;
@@ -29,9 +31,11 @@ pub struct MadaraSiteData {
 	pub search_selector: String,
 	pub image_selector: String,
 	pub genre_selector: String,
+	pub search_genre_selector: String,
 	pub author_selector: String,
 	pub description_selector: String,
 	pub chapter_selector: String,
+	pub chapter_next_page_selector: String,
 	pub base_id_selector: String,
 
 	pub status_filter_ongoing: String,
@@ -40,17 +44,33 @@ pub struct MadaraSiteData {
 	pub status_filter_on_hold: String,
 	pub adult_string: String,
 	pub genre_condition: String,
+	pub artist_filter: String,
+	pub year_filter: String,
+	pub genres_filter: String,
 	pub popular: String,
 	pub trending: String,
 
 	pub alt_ajax: bool,
 
+	pub date_format: String,
+	pub date_locale: String,
+
 	pub get_manga_id: fn(String, String, String) -> String,
 	pub viewer: fn(&Node, &Vec<String>) -> MangaViewer,
 	pub status: fn(&Node) -> MangaStatus,
 	pub nsfw: fn(&Node, &Vec<String>) -> MangaContentRating,
 
 	pub ignore_class: String,
+
+	// extra patterns for images to leave out of chapters
+	pub page_blocklist: &'static [&'static str],
+	// leave out credit and recruitment pages unless the user turns it off
+	pub drop_credit_pages: bool,
+	// decrypt the images of the WP-Manga Chapter Protector plugin
+	pub chapter_protector: bool,
+	// read chapters without images as text, for web novels
+	pub text_chapters: bool,
+	pub text_selector: String,
 }
 
 impl Default for MadaraSiteData {
@@ -75,14 +95,23 @@ impl Default for MadaraSiteData {
 			author_selector: String::from("div.author-content a"),
 			// selector for chapter list
 			chapter_selector: String::from("li.wp-manga-chapter"),
+			// link to the next page of a paginated chapter list
+			chapter_next_page_selector: String::from(
+				"div.pagination a.next, a.nextpostslink, a.next.page-numbers",
+			),
 			// a to get the base id from requests to admin-ajax.php
 			base_id_selector: String::from("h3.h5 > a"),
 			// div to select images from a chapter
 			image_selector: String::from("div.page-break > img"),
 			// div to select all the genres
 			genre_selector: String::from("div.genres-content > a"),
+			// the genre checkboxes of the advanced search form, with their label
+			search_genre_selector: String::from("div.checkbox:has(input[name='genre[]'])"),
 			// choose between two options for chapter list POST request
 			alt_ajax: false,
+			// format and locale of chapter release dates
+			date_format: String::from("MMM d, yyyy"),
+			date_locale: String::from("en"),
 			// get the manga id from script tag
 			get_manga_id: get_int_manga_id,
 			// default viewer
@@ -172,8 +201,16 @@ impl Default for MadaraSiteData {
 					MangaContentRating::Safe
 				}
 			},
-			// Ignore MangaPageResult manga with this class from a listing. Usually used for novels.
+			// Ignore MangaPageResult manga with this class from a listing. Usually used for novels,
+			// empty for sites with text_chapters.
 			ignore_class: String::from(".web-novel"),
+			// regexes matched against image urls, added to the shared blocklist
+			page_blocklist: &[],
+			drop_credit_pages: false,
+			chapter_protector: false,
+			// the element holding a novel chapter's paragraphs
+			text_chapters: false,
+			text_selector: String::from("div.text-left, div.reading-content"),
 			// Localization stuff
 			status_filter_ongoing: String::from("Ongoing"),
 			status_filter_completed: String::from("Completed"),
@@ -181,6 +218,10 @@ impl Default for MadaraSiteData {
 			status_filter_on_hold: String::from("On Hold"),
 			adult_string: String::from("Adult Content"),
 			genre_condition: String::from("Genre Condition"),
+			artist_filter: String::from("Artist"),
+			year_filter: String::from("Year"),
+			// comma separated genres that aren't in filters.json
+			genres_filter: String::from("Other Genres"),
 			popular: String::from("Popular"),
 			trending: String::from("Trending"),
 		}
@@ -263,7 +304,8 @@ pub fn get_series_page(data: MadaraSiteData, listing: &str, page: i32) -> Result
 	for item in html.select("div.page-item-detail").array() {
 		let obj = item.as_node().expect("node array");
 
-		if !obj.select(&data.ignore_class).text().read().is_empty() {
+		if !data.ignore_class.is_empty() && !obj.select(&data.ignore_class).text().read().is_empty()
+		{
 			continue;
 		}
 
@@ -366,35 +408,122 @@ pub fn get_manga_details(manga_id: String, data: MadaraSiteData) -> Result<Manga
 	})
 }
 
+/// Where a site serves its chapter list, from the oldest Madara versions to
+/// the newest.
+#[derive(Clone, Copy, PartialEq)]
+enum ChapterEndpoint {
+	/// `POST /wp-admin/admin-ajax.php` with the post id.
+	AdminAjax,
+	/// `POST {manga_url}/ajax/chapters/`, paginated with `?t=` on some sites.
+	MangaAjax,
+	/// The chapters are in the manga page itself.
+	MangaPage,
+}
+
+/// Sites can disable chapter list pagination, so stop at some point if every
+/// page looks like it has a next one.
+const MAX_CHAPTER_PAGES: i32 = 50;
+
 pub fn get_chapter_list(manga_id: String, data: MadaraSiteData) -> Result<Vec<Chapter>> {
-	let mut url = data.base_url.clone() + "/wp-admin/admin-ajax.php";
-	if data.alt_ajax {
-		url = data.base_url.clone()
-			+ "/" + data.source_path.as_str()
-			+ "/" + manga_id.as_str()
-			+ "/ajax/chapters";
+	// alt_ajax sites use the newer endpoint, but try every style in case the
+	// site was updated since
+	let endpoints = if data.alt_ajax {
+		[
+			ChapterEndpoint::MangaAjax,
+			ChapterEndpoint::AdminAjax,
+			ChapterEndpoint::MangaPage,
+		]
+	} else {
+		[
+			ChapterEndpoint::AdminAjax,
+			ChapterEndpoint::MangaAjax,
+			ChapterEndpoint::MangaPage,
+		]
+	};
+
+	// an endpoint that fails doesn't mean the manga has no chapters, so if
+	// none of them has any, a failed request is an error rather than an empty
+	// list that would make the app drop the chapters it has
+	let mut error = None;
+	for endpoint in endpoints {
+		match get_chapters_from(endpoint, &manga_id, &data) {
+			Ok(chapters) if !chapters.is_empty() => return Ok(chapters),
+			Ok(_) => continue,
+			Err(err) => {
+				error.get_or_insert(err);
+			}
+		}
+	}
+	match error {
+		Some(err) => Err(err),
+		None => Ok(Vec::new()),
 	}
+}
 
-	let int_id = (data.get_manga_id)(manga_id, data.base_url.clone(), data.source_path.clone());
-	let body_content = format!("action=manga_get_chapters&manga={}", int_id);
+fn get_chapters_from(
+	endpoint: ChapterEndpoint,
+	manga_id: &str,
+	data: &MadaraSiteData,
+) -> Result<Vec<Chapter>> {
+	let manga_url = format!("{}/{}/{}", data.base_url, data.source_path, manga_id);
+	let mut chapters: Vec<Chapter> = Vec::new();
 
-	let req = Request::new(url.as_str(), HttpMethod::Post)
-		.body(body_content.as_bytes())
-		.header("Referer", &data.base_url)
-		.header("Content-Type", "application/x-www-form-urlencoded");
+	for page in 1..=MAX_CHAPTER_PAGES {
+		let request = match endpoint {
+			ChapterEndpoint::AdminAjax => {
+				let int_id = (data.get_manga_id)(
+					String::from(manga_id),
+					data.base_url.clone(),
+					data.source_path.clone(),
+				);
+				let body_content = format!("action=manga_get_chapters&manga={}", int_id);
+				Request::new(
+					format!("{}/wp-admin/admin-ajax.php", data.base_url),
+					HttpMethod::Post,
+				)
+				.body(body_content.as_bytes())
+			}
+			ChapterEndpoint::MangaAjax if page > 1 => Request::new(
+				format!("{}/ajax/chapters/?t={}", manga_url, page),
+				HttpMethod::Post,
+			),
+			ChapterEndpoint::MangaAjax => {
+				Request::new(format!("{}/ajax/chapters/", manga_url), HttpMethod::Post)
+			}
+			ChapterEndpoint::MangaPage => Request::new(manga_url.as_str(), HttpMethod::Get),
+		};
+		let html = request
+			.header("Referer", &data.base_url)
+			.header("Content-Type", "application/x-www-form-urlencoded")
+			.html()?;
+
+		let mut added = false;
+		for chapter in parse_chapters(&html, data) {
+			if !chapters.iter().any(|existing| existing.id == chapter.id) {
+				chapters.push(chapter);
+				added = true;
+			}
+		}
 
-	let html = req.html()?;
+		// only the newer endpoint is paginated, and sites that ignore `t`
+		// return the first page again
+		let has_next_page = !html
+			.select(&data.chapter_next_page_selector)
+			.array()
+			.is_empty();
+		if endpoint != ChapterEndpoint::MangaAjax || !added || !has_next_page {
+			break;
+		}
+	}
+	Ok(chapters)
+}
 
+fn parse_chapters(html: &Node, data: &MadaraSiteData) -> Vec<Chapter> {
 	let mut chapters: Vec<Chapter> = Vec::new();
 	for item in html.select(&data.chapter_selector).array() {
 		let obj = item.as_node().expect("node array");
 
-		let id = obj
-			.select("a")
-			.attr("href")
-			.read()
-			.replace(&(data.base_url.clone() + "/"), "")
-			.replace(&(data.source_path.clone() + "/"), "");
+		let id = get_chapter_id(&obj.select("a").attr("href").read(), data);
 
 		let mut title = String::new();
 		let t_tag = obj.select("a").text().read();
@@ -402,50 +531,12 @@ pub fn get_chapter_list(manga_id: String, data: MadaraSiteData) -> Result<Vec<Ch
 			title.push_str(t_tag[t_tag.find('-').unwrap() + 1..].trim());
 		}
 
-		/*  Chapter number is first occourance of a number in the last element of url
-			when split with "/"
-			e.g.
-			one-piece-color-jk-english/volume-20-showdown-at-alubarna/chapter-177-30-million-vs-81-million/
-			will return 177
-			parasite-chromatique-french/volume-10/chapitre-062-5/
-			will return 62.5
-		*/
-
-		let slash_vec = id.as_str().split('/').collect::<Vec<&str>>();
-
-		let dash_split = slash_vec[slash_vec.len() - 2].split('-');
-		let dash_vec = dash_split.collect::<Vec<&str>>();
-
-		let mut is_decimal = false;
-		let mut chapter = 0.0;
-		for obj in dash_vec {
-			let mut item = {
-				let mut obj = obj;
-				if obj.contains('_') {
-					obj = obj.split('_').next().unwrap_or(obj);
-				}
-				obj.replace('/', "").parse::<f32>().unwrap_or(-1.0)
-			};
-			if item == -1.0 {
-				item = String::from(obj.chars().next().unwrap())
-					.parse::<f32>()
-					.unwrap_or(-1.0);
-			}
-			if item != -1.0 {
-				if is_decimal {
-					chapter += item / 10.0;
-					break;
-				} else {
-					chapter = item;
-					is_decimal = true;
-				}
-			}
-		}
+		let chapter = get_chapter_number(&id);
 
 		let date_str = obj.select("span.chapter-release-date > i").text().read();
 		let mut date_updated = StringRef::from(&date_str)
 			.0
-			.as_date("MMM d, yyyy", Some("en"), None)
+			.as_date(&data.date_format, Some(&data.date_locale), None)
 			.unwrap_or(-1.0);
 		if date_updated < -1.0 {
 			date_updated = StringRef::from(&date_str)
@@ -454,7 +545,15 @@ pub fn get_chapter_list(manga_id: String, data: MadaraSiteData) -> Result<Vec<Ch
 				.unwrap_or(-1.0);
 		}
 		if date_updated == -1.0 {
-			date_updated = current_date();
+			// new chapters show "2 days ago" in a link instead of a date
+			let now = current_date();
+			let ago_str = obj
+				.select("span.chapter-release-date a")
+				.attr("title")
+				.read();
+			date_updated = relative_date::parse(&date_str, now)
+				.or_else(|| relative_date::parse(&ago_str, now))
+				.unwrap_or(now);
 		}
 
 		let url = obj.select("a").attr("href").read();
@@ -471,22 +570,37 @@ pub fn get_chapter_list(manga_id: String, data: MadaraSiteData) -> Result<Vec<Ch
 			lang,
 		});
 	}
-	Ok(chapters)
+	chapters
 }
 
 pub fn get_page_list(chapter_id: String, data: MadaraSiteData) -> Result<Vec<Page>> {
 	let url = data.base_url.clone() + "/" + data.source_path.as_str() + "/" + chapter_id.as_str();
 	let html = Request::new(url.as_str(), HttpMethod::Get).html()?;
 
-	let mut pages: Vec<Page> = Vec::new();
-	for (index, item) in html
+	let mut urls = html
 		.select(data.image_selector.as_str())
 		.array()
+		.map(|item| get_image_url(item.as_node().expect("node array")))
+		.collect::<Vec<String>>();
+	if urls.is_empty() && data.chapter_protector {
+		urls = get_protected_images(&html)?;
+	}
+	if urls.is_empty() && data.text_chapters {
+		return Ok(get_text_pages(&html, &data));
+	}
+
+	let setting = defaults_get("dropCreditPages")
+		.and_then(|value| value.as_bool())
+		.ok();
+	let mut pages: Vec<Page> = Vec::new();
+	for (index, url) in PageFilter::for_source(data.page_blocklist, data.drop_credit_pages, setting)
+		.filter(urls)
+		.into_iter()
 		.enumerate()
 	{
 		pages.push(Page {
 			index: index as i32,
-			url: get_image_url(item.as_node().expect("node array")),
+			url,
 			base64: String::new(),
 			text: String::new(),
 		});
@@ -499,13 +613,39 @@ pub fn modify_image_request(base_url: String, request: Request) {
 }
 
 pub fn handle_url(url: String, data: MadaraSiteData) -> Result<DeepLink> {
-	let mut manga_id = String::new();
-	let parse_url = url.as_str().split('/').collect::<Vec<&str>>();
-	if parse_url.len() >= 4 {
-		manga_id.push_str(parse_url[4]);
-	}
+	// {base_url}/{source_path}/{manga_id}/{chapter}/
+	let path = url
+		.split(['?', '#'])
+		.next()
+		.unwrap_or_default()
+		.split("://")
+		.last()
+		.unwrap_or_default();
+	let segments = path
+		.split('/')
+		.skip(1)
+		.filter(|segment| !segment.is_empty())
+		.collect::<Vec<&str>>();
+	let manga_index = segments
+		.iter()
+		.position(|segment| *segment == data.source_path)
+		.map(|index| index + 1)
+		.unwrap_or(1);
+
+	let manga_id = String::from(segments.get(manga_index).copied().unwrap_or_default());
+	let chapter = segments.get(manga_index + 1).map(|chapter| {
+		let id = format!("{}/{}/", manga_id, chapter);
+		Chapter {
+			chapter: get_chapter_number(&id),
+			url: format!("{}/{}/{}", data.base_url, data.source_path, id),
+			lang: data.lang.clone(),
+			id,
+			..Default::default()
+		}
+	});
+
 	Ok(DeepLink {
 		manga: Some(get_manga_details(manga_id, data)?),
-		chapter: None,
+		chapter,
 	})
 }
@@ -1,4 +1,4 @@
-use aidoku::{std::ArrayRef, std::String, std::Vec, MangaStatus};
+use aidoku::{std::defaults::defaults_get, std::ArrayRef, std::String, std::Vec, MangaStatus};
 
 pub fn urlencode(string: String) -> String {
 	let mut result: Vec<u8> = Vec::with_capacity(string.len() * 3);
@@ -210,3 +210,14 @@ pub fn stupidencode(string: String) -> String {
 	}
 	result
 }
+
+/// The image server the user picked in the `imageServer` setting, 1 or 2.
+pub fn get_image_server() -> i32 {
+	match defaults_get("imageServer")
+		.and_then(|value| value.as_string())
+		.map(|value| value.read())
+	{
+		Ok(server) if server == "2" => 2,
+		_ => 1,
+	}
+}
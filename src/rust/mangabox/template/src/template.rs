@@ -1,226 +1,298 @@
 use aidoku::{
-	error::Result, std::net::HttpMethod, std::net::Request, std::ObjectRef, std::String, std::Vec,
-	Chapter, DeepLink, Filter, FilterType, Listing, Manga, MangaContentRating, MangaPageResult,
-	MangaStatus, MangaViewer, Page,
+	error::Result, std::defaults::defaults_get, std::net::HttpMethod, std::net::Request,
+	std::ObjectRef, std::String, std::Vec, Chapter, DeepLink, Filter, FilterType, Listing, Manga,
+	MangaContentRating, MangaPageResult, MangaStatus, MangaViewer, Page,
 };
 
 use crate::helper::*;
 
-pub fn get_manga_list(
-	base_url: String,
-	selector: String,
-	filters: Vec<Filter>,
-	page: i32,
-) -> Result<MangaPageResult> {
-	let mut included_tags: Vec<String> = Vec::new();
-	let mut excluded_tags: Vec<String> = Vec::new();
-	let mut sort: String = String::new();
-	let mut title: String = String::new();
-	for filter in filters {
-		match filter.kind {
-			FilterType::Title => {
-				title = filter.value.as_string()?.read();
-			}
-			FilterType::Genre => match filter.value.as_int().unwrap_or(-1) {
-				0 => excluded_tags.push(get_tag_id(String::from(&filter.name))),
-				1 => included_tags.push(get_tag_id(String::from(&filter.name))),
-				_ => continue,
-			},
-			FilterType::Sort => {
-				let value = match filter.value.as_object() {
-					Ok(value) => value,
-					Err(_) => continue,
-				};
-				let index = value.get("index").as_int().unwrap_or(0);
-				let option = match index {
-					0 => "",
-					1 => "newest",
-					2 => "topview",
-					3 => "az",
+pub struct MangaBoxSource {
+	/// Where listings and searches come from
+	pub base_url: &'static str,
+	/// Where manga and chapters are read from, unless the user has set
+	/// `sourceURL`
+	pub manga_url: &'static str,
+	/// Every host manga and chapters have been on, so that ids saved before
+	/// the site moved still work
+	pub hosts: &'static [&'static str],
+	pub manga_selector: &'static str,
+	pub date_format: &'static str,
+}
+
+impl MangaBoxSource {
+	fn manga_url(&self) -> String {
+		let setting = defaults_get("sourceURL")
+			.and_then(|value| value.as_string())
+			.map(|value| value.read())
+			.ok();
+		site_url::base_url(setting.as_deref(), self.manga_url)
+	}
+
+	fn get_id(&self, url: &str) -> String {
+		site_url::get_id(url, &self.manga_url(), self.hosts)
+	}
+
+	fn get_url(&self, id: &str) -> String {
+		site_url::get_url(id, &self.manga_url(), self.hosts)
+	}
+
+	pub fn get_manga_list(&self, filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
+		let mut included_tags: Vec<String> = Vec::new();
+		let mut excluded_tags: Vec<String> = Vec::new();
+		let mut sort: String = String::new();
+		let mut title: String = String::new();
+		for filter in filters {
+			match filter.kind {
+				FilterType::Title => {
+					title = filter.value.as_string()?.read();
+				}
+				FilterType::Genre => match filter.value.as_int().unwrap_or(-1) {
+					0 => excluded_tags.push(get_tag_id(String::from(&filter.name))),
+					1 => included_tags.push(get_tag_id(String::from(&filter.name))),
 					_ => continue,
-				};
-				sort = String::from(option)
+				},
+				FilterType::Sort => {
+					let value = match filter.value.as_object() {
+						Ok(value) => value,
+						Err(_) => continue,
+					};
+					let index = value.get("index").as_int().unwrap_or(0);
+					let option = match index {
+						0 => "",
+						1 => "newest",
+						2 => "topview",
+						3 => "az",
+						_ => continue,
+					};
+					sort = String::from(option)
+				}
+				_ => continue,
 			}
-			_ => continue,
 		}
+
+		let mut mangas: Vec<Manga> = Vec::new();
+		let url = get_search_url(
+			String::from(self.base_url),
+			title,
+			page,
+			included_tags,
+			excluded_tags,
+			sort,
+		);
+		let html = Request::new(url.as_str(), HttpMethod::Get).html()?;
+		for item in html.select(self.manga_selector).array() {
+			let item_node = match item.as_node() {
+				Ok(node) => node,
+				Err(_) => continue,
+			};
+			let title = item_node.select("a").first().attr("title").read();
+			let id = self.get_id(&item_node.select("a").first().attr("href").read());
+			let cover = item_node.select("img").first().attr("src").read();
+			mangas.push(Manga {
+				id,
+				cover,
+				title,
+				author: String::new(),
+				artist: String::new(),
+				description: String::new(),
+				url: String::new(),
+				categories: Vec::new(),
+				status: MangaStatus::Unknown,
+				nsfw: MangaContentRating::Safe,
+				viewer: MangaViewer::Rtl,
+			});
+		}
+		let last_page_string = html.select("a.page-last").text().read();
+		let mut last_page = 1;
+		if !last_page_string.is_empty() {
+			last_page = String::from(&last_page_string[5..last_page_string.len() - 1])
+				.parse::<i32>()
+				.unwrap_or(1);
+		}
+		Ok(MangaPageResult {
+			manga: mangas,
+			has_more: page < last_page,
+		})
 	}
 
-	let mut mangas: Vec<Manga> = Vec::new();
-	let url = get_search_url(base_url, title, page, included_tags, excluded_tags, sort);
-	let html = Request::new(url.as_str(), HttpMethod::Get).html()?;
-	for item in html.select(&selector).array() {
-		let item_node = match item.as_node() {
-			Ok(node) => node,
-			Err(_) => continue,
-		};
-		let title = item_node.select("a").first().attr("title").read();
-		let id = item_node.select("a").first().attr("href").read();
-		let cover = item_node.select("img").first().attr("src").read();
-		mangas.push(Manga {
+	pub fn get_manga_listing(&self, listing: Listing, page: i32) -> Result<MangaPageResult> {
+		let mut filters: Vec<Filter> = Vec::new();
+		let mut selection = ObjectRef::new();
+
+		selection.set("ascending", false.into());
+		selection.set(
+			"index",
+			match listing.name.as_str() {
+				"Latest Updates" => 0i32.into(),
+				"New Manga" => 1i32.into(),
+				"Top Manga" => 2i32.into(),
+				&_ => 0i32.into(),
+			},
+		);
+		filters.push(Filter {
+			kind: FilterType::Sort,
+			name: String::from("Sort"),
+			value: selection.0.clone(),
+			object: ObjectRef(selection.0),
+		});
+
+		self.get_manga_list(filters, page)
+	}
+
+	pub fn get_manga_details(&self, id: String) -> Result<Manga> {
+		let url = self.get_url(&id);
+		let html = Request::new(url.as_str(), HttpMethod::Get).html()?;
+		let details = html.select("div.panel-story-info");
+		let title = details.select("h1").text().read();
+		let cover = details.select("span.info-image > img").attr("src").read();
+		let author = join_string_array(
+			details.select("td:contains(Author) + td a").array(),
+			String::from(", "),
+		);
+		let description = details
+			.select("div.panel-story-info-description")
+			.text()
+			.read();
+		let mut categories = Vec::new();
+		let mut nsfw = MangaContentRating::Safe;
+		let mut viewer = MangaViewer::Rtl;
+		for node in details.select("td:contains(Genre) + td a").array() {
+			let category = match node.as_node() {
+				Ok(node) => node.text().read(),
+				Err(_) => continue,
+			};
+			match category.as_str() {
+				"Adult" | "Ecchi" | "Mature" | "Smut" => nsfw = MangaContentRating::Nsfw,
+				"Webtoons" => viewer = MangaViewer::Scroll,
+				_ => (),
+			}
+			categories.push(category);
+		}
+		let status = status_from_string(details.select("td:contains(Status) + td").text().read());
+		Ok(Manga {
 			id,
 			cover,
 			title,
-			author: String::new(),
+			author,
 			artist: String::new(),
-			description: String::new(),
-			url: String::new(),
-			categories: Vec::new(),
-			status: MangaStatus::Unknown,
-			nsfw: MangaContentRating::Safe,
-			viewer: MangaViewer::Rtl,
-		});
+			description,
+			url,
+			categories,
+			status,
+			nsfw,
+			viewer,
+		})
 	}
-	let last_page_string = html.select("a.page-last").text().read();
-	let mut last_page = 1;
-	if !last_page_string.is_empty() {
-		last_page = String::from(&last_page_string[5..last_page_string.len() - 1])
-			.parse::<i32>()
-			.unwrap_or(1);
+
+	pub fn get_chapter_list(&self, id: String) -> Result<Vec<Chapter>> {
+		let mut chapters: Vec<Chapter> = Vec::new();
+		let url = self.get_url(&id);
+		let html = Request::new(url.as_str(), HttpMethod::Get).html()?;
+		for chapter in html
+			.select("div.panel-story-chapter-list > ul.row-content-chapter > li")
+			.array()
+		{
+			let chapter_node = match chapter.as_node() {
+				Ok(node) => node,
+				Err(_) => continue,
+			};
+			let title = chapter_node.select("a").text().read();
+			let chapter_url = chapter_node.select("a").attr("href").read();
+			let chapter_id = self.get_id(&chapter_url);
+			let chapter_number = get_chapter_number(chapter_id.clone());
+			let date_updated = chapter_node
+				.select("span.chapter-time")
+				.attr("title")
+				.0
+				.as_date(self.date_format, None, None)
+				.unwrap_or(0.0);
+			chapters.push(Chapter {
+				id: chapter_id,
+				title,
+				volume: -1.0,
+				chapter: chapter_number,
+				date_updated,
+				scanlator: String::new(),
+				url: chapter_url,
+				lang: String::from("en"),
+			});
+		}
+		Ok(chapters)
 	}
-	Ok(MangaPageResult {
-		manga: mangas,
-		has_more: page < last_page,
-	})
-}
 
-pub fn get_manga_listing(
-	base_url: String,
-	selector: String,
-	listing: Listing,
-	page: i32,
-) -> Result<MangaPageResult> {
-	let mut filters: Vec<Filter> = Vec::new();
-	let mut selection = ObjectRef::new();
-
-	selection.set("ascending", false.into());
-	selection.set(
-		"index",
-		match listing.name.as_str() {
-			"Latest Updates" => 0i32.into(),
-			"New Manga" => 1i32.into(),
-			"Top Manga" => 2i32.into(),
-			&_ => 0i32.into(),
-		},
-	);
-	filters.push(Filter {
-		kind: FilterType::Sort,
-		name: String::from("Sort"),
-		value: selection.0.clone(),
-		object: ObjectRef(selection.0),
-	});
-
-	get_manga_list(base_url, selector, filters, page)
-}
+	/// The chapter's images from the user's image server, or from the other
+	/// one if the chapter doesn't load or has no images there. The servers are
+	/// separate CDNs that often go down on their own.
+	pub fn get_page_list(&self, id: String) -> Result<Vec<Page>> {
+		let url = self.get_url(&id);
+		let server = get_image_server();
+		let pages = self.get_pages(&url, server);
+		if matches!(&pages, Ok(pages) if !pages.is_empty()) {
+			return pages;
+		}
+		let alternate = if server == 1 { 2 } else { 1 };
+		match self.get_pages(&url, alternate) {
+			Ok(alternate_pages) if !alternate_pages.is_empty() => Ok(alternate_pages),
+			// the other server has no images either, keep the chosen one
+			alternate_pages => pages.or(alternate_pages),
+		}
+	}
 
-pub fn get_manga_details(id: String) -> Result<Manga> {
-	let html = Request::new(id.as_str(), HttpMethod::Get).html()?;
-	let details = html.select("div.panel-story-info");
-	let title = details.select("h1").text().read();
-	let cover = details.select("span.info-image > img").attr("src").read();
-	let author = join_string_array(
-		details.select("td:contains(Author) + td a").array(),
-		String::from(", "),
-	);
-	let description = details
-		.select("div.panel-story-info-description")
-		.text()
-		.read();
-	let mut categories = Vec::new();
-	let mut nsfw = MangaContentRating::Safe;
-	let mut viewer = MangaViewer::Rtl;
-	for node in details.select("td:contains(Genre) + td a").array() {
-		let category = match node.as_node() {
-			Ok(node) => node.text().read(),
-			Err(_) => continue,
-		};
-		match category.as_str() {
-			"Adult" | "Ecchi" | "Mature" | "Smut" => nsfw = MangaContentRating::Nsfw,
-			"Webtoons" => viewer = MangaViewer::Scroll,
-			_ => (),
+	fn get_pages(&self, url: &str, server: i32) -> Result<Vec<Page>> {
+		let mut pages: Vec<Page> = Vec::new();
+		// the site picks the server from a cookie and defaults to the first
+		// one, so only ask for the second, as a `Cookie` header would replace
+		// the cookies the app has for the site
+		let mut request = Request::new(url, HttpMethod::Get);
+		if server == 2 {
+			request = request.header("Cookie", "content_server=server2");
 		}
-		categories.push(category);
+		let html = request.html()?;
+		for (i, page) in html
+			.select("div.container-chapter-reader img")
+			.array()
+			.enumerate()
+		{
+			let page_node = match page.as_node() {
+				Ok(node) => node,
+				Err(_) => continue,
+			};
+			let mut page_url = page_node.attr("src").read();
+			if page_url.is_empty() || page_url.starts_with("data:") {
+				page_url = page_node.attr("data-src").read();
+			}
+			pages.push(Page {
+				index: i as i32,
+				url: page_url,
+				base64: String::new(),
+				text: String::new(),
+			});
+		}
+		Ok(pages)
 	}
-	let status = status_from_string(details.select("td:contains(Status) + td").text().read());
-	Ok(Manga {
-		id: id.clone(),
-		cover,
-		title,
-		author,
-		artist: String::new(),
-		description,
-		url: id,
-		categories,
-		status,
-		nsfw,
-		viewer,
-	})
-}
 
-pub fn get_chapter_list(id: String, date_format: String) -> Result<Vec<Chapter>> {
-	let mut chapters: Vec<Chapter> = Vec::new();
-	let html = Request::new(id.as_str(), HttpMethod::Get).html()?;
-	for chapter in html
-		.select("div.panel-story-chapter-list > ul.row-content-chapter > li")
-		.array()
-	{
-		let chapter_node = match chapter.as_node() {
-			Ok(node) => node,
-			Err(_) => continue,
-		};
-		let title = chapter_node.select("a").text().read();
-		let chapter_id = chapter_node.select("a").attr("href").read();
-		let chapter_url = chapter_node.select("a").attr("href").read();
-		let chapter_number = get_chapter_number(chapter_id.clone());
-		let date_updated = chapter_node
-			.select("span.chapter-time")
-			.attr("title")
-			.0
-			.as_date(date_format.as_str(), None, None)
-			.unwrap_or(0.0);
-		chapters.push(Chapter {
-			id: chapter_id,
-			title,
-			volume: -1.0,
-			chapter: chapter_number,
-			date_updated,
-			scanlator: String::new(),
-			url: chapter_url,
-			lang: String::from("en"),
-		});
+	pub fn modify_image_request(&self, request: Request) {
+		request.header("Referer", &self.manga_url());
 	}
-	Ok(chapters)
-}
 
-pub fn get_page_list(id: String) -> Result<Vec<Page>> {
-	let mut pages: Vec<Page> = Vec::new();
-	let html = Request::new(id.as_str(), HttpMethod::Get).html()?;
-	for (i, page) in html
-		.select("div.container-chapter-reader > img")
-		.array()
-		.enumerate()
-	{
-		let page_node = match page.as_node() {
-			Ok(node) => node,
-			Err(_) => continue,
+	pub fn handle_url(&self, url: String) -> Result<DeepLink> {
+		let id = self.get_id(&url);
+		// chapters are at `/manga-id/chapter-1` or `/read-id-chap-1`
+		let (manga_id, chapter) = match id.split_once("/chapter-").or(id.split_once("-chap-")) {
+			Some((manga_id, _)) => (
+				String::from(manga_id),
+				Some(Chapter {
+					id: id.clone(),
+					chapter: get_chapter_number(id.clone()),
+					url: self.get_url(&id),
+					..Default::default()
+				}),
+			),
+			None => (id, None),
 		};
-		let page_url = page_node.attr("src").read();
-		pages.push(Page {
-			index: i as i32,
-			url: page_url,
-			base64: String::new(),
-			text: String::new(),
-		});
+		Ok(DeepLink {
+			manga: self.get_manga_details(manga_id).ok(),
+			chapter,
+		})
 	}
-	Ok(pages)
-}
-
-pub fn modify_image_request(base_url: String, request: Request) {
-	request.header("Referer", &base_url);
-}
-
-pub fn handle_url(url: String) -> Result<DeepLink> {
-	Ok(DeepLink {
-		manga: get_manga_details(url).ok(),
-		chapter: None,
-	})
 }
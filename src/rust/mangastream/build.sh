@@ -1,6 +1,13 @@
 # template source build script
 # usage: ./build.sh [source_name/-a]
 
+# write the crates for the sources described by sites/*/site.toml
+(
+    WORKSPACE="$(pwd)"
+    cd ../../../lib/rust/site-generator
+    cargo run --release -q -- "$WORKSPACE"
+) || exit 1
+
 if [ "$1" != "-a" ] && [ "$1" != "" ]; then
     # compile specified source
     cargo +nightly build --release
@@ -8,7 +15,7 @@ if [ "$1" != "-a" ] && [ "$1" != "" ]; then
     echo "packaging $1";
     mkdir -p target/wasm32-unknown-unknown/release/Payload
     cp res/* target/wasm32-unknown-unknown/release/Payload
-    cp sources/$1/res/* target/wasm32-unknown-unknown/release/Payload
+    cp generated/$1/res/* target/wasm32-unknown-unknown/release/Payload
     cd target/wasm32-unknown-unknown/release
     cp $1.wasm Payload/main.wasm
     zip -r $1.aix Payload
@@ -17,14 +24,14 @@ if [ "$1" != "-a" ] && [ "$1" != "" ]; then
 else
     # compile all sources
     cargo +nightly build --release
-    for dir in sources/*/
+    for dir in generated/*/
     do
         dir=${dir%*/}
         dir=${dir##*/}
         echo "packaging $dir";
         mkdir -p target/wasm32-unknown-unknown/release/Payload
         cp res/* target/wasm32-unknown-unknown/release/Payload
-        cp sources/$dir/res/* target/wasm32-unknown-unknown/release/Payload
+        cp generated/$dir/res/* target/wasm32-unknown-unknown/release/Payload
         cd target/wasm32-unknown-unknown/release
         cp $dir.wasm Payload/main.wasm
         zip -r $dir.aix Payload >> /dev/null
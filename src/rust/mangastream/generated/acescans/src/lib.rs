@@ -1,18 +1,29 @@
+// Generated from sites/acescans/site.toml, don't edit.
 #![no_std]
 use aidoku::{
-	error::Result, prelude::*, std::net::Request, std::String, std::Vec, Chapter, DeepLink, Filter,
-	Listing, Manga, MangaPageResult, Page,
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	std::net::Request,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	Page,
 };
 
 use mangastream_template::template::MangaStreamSource;
 
 fn get_instance() -> MangaStreamSource {
 	MangaStreamSource {
+		alt_pages: true,
 		base_url: String::from("https://acescans.xyz"),
+		manga_details_categories: ".seriestugenre a",
 		manga_details_cover_src: "data-src",
 		manga_details_type: "tr:contains(Type) td:eq(1)",
-		alt_pages: true,
-		manga_details_categories: ".seriestugenre a",
 		..Default::default()
 	}
 }
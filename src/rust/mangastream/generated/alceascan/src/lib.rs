@@ -1,20 +1,31 @@
+// Generated from sites/alceascan/site.toml, don't edit.
 #![no_std]
 use aidoku::{
-	error::Result, prelude::*, std::net::Request, std::String, std::Vec, Chapter, DeepLink, Filter,
-	Listing, Manga, MangaPageResult, Page,
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	std::net::Request,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	Page,
 };
 
 use mangastream_template::template::MangaStreamSource;
 
 fn get_instance() -> MangaStreamSource {
 	MangaStreamSource {
+		alt_pages: true,
 		base_url: String::from("https://alceascan.my.id"),
-		manga_details_author: "td:contains(Author)+td",
+		locale: "en_US",
 		manga_details_artist: "td:contains(Artist)+td",
-		manga_details_type: "td:contains(Type)+td",
+		manga_details_author: "td:contains(Author)+td",
 		manga_details_categories: ".seriestugenre a",
-		locale: "en_US",
-		alt_pages: true,
+		manga_details_type: "td:contains(Type)+td",
 		..Default::default()
 	}
 }
@@ -1,16 +1,27 @@
+// Generated from sites/anigliscans/site.toml, don't edit.
 #![no_std]
 use aidoku::{
-	error::Result, prelude::*, std::net::Request, std::String, std::Vec, Chapter, DeepLink, Filter,
-	Listing, Manga, MangaPageResult, Page,
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	std::net::Request,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	Page,
 };
 
 use mangastream_template::template::MangaStreamSource;
 
 fn get_instance() -> MangaStreamSource {
 	MangaStreamSource {
+		alt_pages: true,
 		base_url: String::from("https://anigliscans.com"),
 		traverse_pathname: "series",
-		alt_pages: true,
 		..Default::default()
 	}
 }
@@ -46,6 +57,6 @@ fn modify_image_request(request: Request) {
 }
 
 #[handle_url]
-fn handle_url(url: String) -> Result<DeepLink> {
+pub fn handle_url(url: String) -> Result<DeepLink> {
 	get_instance().handle_url(url)
 }
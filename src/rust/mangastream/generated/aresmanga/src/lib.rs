@@ -1,20 +1,31 @@
+// Generated from sites/aresmanga/site.toml, don't edit.
 #![no_std]
 use aidoku::{
-	error::Result, prelude::*, std::net::Request, std::String, std::Vec, Chapter, DeepLink, Filter,
-	Listing, Manga, MangaPageResult, Page,
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	std::net::Request,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	Page,
 };
 
 use mangastream_template::template::MangaStreamSource;
 
 fn get_instance() -> MangaStreamSource {
 	MangaStreamSource {
-		listing: ["الرائج", "آخر", "جَديد"],
 		base_url: String::from("https://aresnov.org"),
-		last_page_text: "التالي",
-		traverse_pathname: "series",
-		manga_details_author: ".imptdt:contains(المؤلف) i",
 		chapter_date_format: "MMMM d, yyyy",
+		last_page_text: "التالي",
+		listing: ["الرائج", "آخر", "جَديد"],
 		locale: "ar_EH",
+		manga_details_author: ".imptdt:contains(المؤلف) i",
+		traverse_pathname: "series",
 		..Default::default()
 	}
 }
@@ -0,0 +1,147 @@
+// Generated from sites/asurascans/site.toml, don't edit.
+#![no_std]
+use aidoku::{
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	std::net::Request,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	Page,
+};
+
+use mangastream_template::{helper::get_lang_code, template::MangaStreamSource};
+
+fn get_instance() -> MangaStreamSource {
+	let lang = get_lang_code();
+	MangaStreamSource {
+		alt_pages: true,
+		base_url: match lang.as_str() {
+			"tr" => String::from("https://asurascanstr.com"),
+			_ => String::from("https://asuratoon.com"),
+		},
+		chapter_date_format_2: "MMMM d, yyyy",
+		date_string: "asurascanstr",
+		drop_credit_pages: true,
+		language_2: "tr",
+		last_page_text_2: "Sonraki",
+		locale_2: "tr_TR",
+		status_options_2: ["Devam Ediyor", "Tamamlandı", "Durduruldu", "CANCELLED", "Bırakıldı"],
+		tagid_mapping: |tag| {
+			String::from(match tag.as_str() {
+				"Action" => "aksiyon",
+				"Adaptation" => "adaptasyon",
+				"Adult" => "yetiskin",
+				"Adventure" => "macera",
+				"Another chance" => "another-chance",
+				"Comedy" => "komedi",
+				"Coming Soon" => "coming-soon",
+				"Cultivation" => "cultivation",
+				"Demon" => "demon",
+				"Discord" => "discord",
+				"Drama" => "dram",
+				"Dungeons" => "zindan",
+				"Ecchi" => "ecchi",
+				"Fantasy" => "fantezi",
+				"Game" => "game",
+				"Genius" => "genius",
+				"Harem" => "harem",
+				"Hero" => "hero",
+				"Historical" => "historical",
+				"Isekai" => "isekai",
+				"Josei" => "josei",
+				"Kool Kids" => "kool-kids",
+				"Loli" => "loli",
+				"Magic" => "buyu",
+				"Martial Arts" => "dovus-sanatlari",
+				"Mature" => "mature",
+				"Mecha" => "mecha",
+				"Modern Setting" => "modern-setting",
+				"Monsters" => "canavar",
+				"Murim" => "murim",
+				"Mystery" => "gizem",
+				"Necromancer" => "necromancer",
+				"Noble" => "noble",
+				"Overpowered" => "overpowered",
+				"Pets" => "pets",
+				"Post-Apocalyptic" => "kiyamet-sonrasi",
+				"Psychological" => "psikoloji",
+				"Rebirth" => "rebirth",
+				"Reincarnation" => "reenkarnasyon",
+				"Return" => "return",
+				"Returned" => "geri-donen",
+				"Returner" => "returner",
+				"Revenge" => "intikam",
+				"Romance" => "romantizm",
+				"School Life" => "okul-hayati",
+				"Sci-fi" => "bilim-kurgu",
+				"Seinen" => "seinen",
+				"Shoujo" => "shoujo",
+				"Shounen" => "shounen",
+				"Slice of Life" => "yasamdan-kesitler",
+				"Super Hero" => "super-hero",
+				"Superhero" => "superkahraman",
+				"Supernatural" => "dogaustu",
+				"Survival" => "hayatta-kalma",
+				"System" => "sistem",
+				"Time Travel" => "zamanda-yolculuk",
+				"Time Travel (Future)" => "time-travel-future",
+				"Tragedy" => "trajedi",
+				"Video Game" => "video-game",
+				"Video Games" => "video-oyunlari",
+				"Villain" => "villain",
+				"Virtual Game" => "virtual-game",
+				"Virtual Reality" => "sanal-gerceklik",
+				"Virtual World" => "vr",
+				"Webtoon" => "webtoon",
+				"Wuxia" => "wuxia",
+				"apocalypse" => "apocalypse",
+				"tower" => "kule",
+				_ => "",
+			})
+		},
+		use_chapter_postids: false,
+		use_manga_postids: true,
+		..Default::default()
+	}
+}
+
+#[get_manga_list]
+fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
+	get_instance().parse_manga_list(filters, page)
+}
+
+#[get_manga_listing]
+fn get_manga_listing(listing: Listing, page: i32) -> Result<MangaPageResult> {
+	get_instance().parse_manga_listing(get_instance().base_url, listing.name, page)
+}
+
+#[get_manga_details]
+fn get_manga_details(id: String) -> Result<Manga> {
+	get_instance().parse_manga_details(id)
+}
+
+#[get_chapter_list]
+fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
+	get_instance().parse_chapter_list(id)
+}
+
+#[get_page_list]
+fn get_page_list(_manga_id: String, id: String) -> Result<Vec<Page>> {
+	get_instance().parse_page_list(id)
+}
+
+#[modify_image_request]
+fn modify_image_request(request: Request) {
+	get_instance().modify_image_request(request)
+}
+
+#[handle_url]
+pub fn handle_url(url: String) -> Result<DeepLink> {
+	get_instance().handle_url(url)
+}
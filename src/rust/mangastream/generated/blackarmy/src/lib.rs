@@ -1,26 +1,31 @@
+// Generated from sites/blackarmy/site.toml, don't edit.
 #![no_std]
 use aidoku::{
-	error::Result, prelude::*, std::net::Request, std::String, std::Vec, Chapter, DeepLink, Filter,
-	Listing, Manga, MangaPageResult, Page,
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	std::net::Request,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	Page,
 };
 
 use mangastream_template::template::MangaStreamSource;
 
 fn get_instance() -> MangaStreamSource {
 	MangaStreamSource {
+		alt_pages: true,
 		base_url: String::from("https://blackarmy.fr"),
-		listing: ["Dernières", "Populaire", "Nouveau"],
-		nsfw_genres: [
-			"Adulte".into(),
-			"Ecchi".into(),
-			"Mature".into(),
-			"Hentai".into(),
-		]
-		.to_vec(),
 		chapter_date_format: "MMMM dd,yyyy",
 		language: "fr",
+		listing: ["Dernières", "Populaire", "Nouveau"],
 		locale: "fr",
-		alt_pages: true,
+		nsfw_genres: [String::from("Adulte"), String::from("Ecchi"), String::from("Mature"), String::from("Hentai")].to_vec(),
 		..Default::default()
 	}
 }
@@ -0,0 +1,135 @@
+// Generated from sites/flamescans/site.toml, don't edit.
+#![no_std]
+use aidoku::{
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	std::net::Request,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	Page,
+};
+
+use mangastream_template::{helper::get_lang_code, template::MangaStreamSource};
+
+fn get_instance() -> MangaStreamSource {
+	let lang = get_lang_code();
+	MangaStreamSource {
+		base_url: match lang.as_str() {
+			"ar" => String::from("https://ar.flamescans.org"),
+			_ => String::from("https://flamescans.org"),
+		},
+		chapter_date_format: "MMMM dd, yyyy",
+		drop_credit_pages: true,
+		has_permanent_chapter_url: true,
+		has_permanent_manga_url: true,
+		language_2: "ar",
+		last_page_text_2: "التالي",
+		manga_details_status: ".imptdt:contains(Status) i, r:contains(الحالة) td:eq(1)",
+		status_options_2: ["مستمر", "مكتمل", "متوقف", "ملغي", "متروك"],
+		tagid_mapping: |tag| {
+			String::from(match tag.as_str() {
+				"Action" => "17",
+				"Adventure" => "11",
+				"Apocalypse" => "55",
+				"Betrayal" => "190",
+				"Calm Protagonist" => "191",
+				"Comedy" => "45",
+				"Coming Soon" => "85",
+				"Cultivation" => "110",
+				"Dragons" => "193",
+				"Drama" => "26",
+				"Dungeons" => "22",
+				"Ecchi" => "19",
+				"Fantasy" => "131",
+				"Fusion Fantasy" => "88",
+				"Games" => "183",
+				"Harem" => "36",
+				"Historical" => "44",
+				"Horror" => "67",
+				"Hunter" => "215",
+				"Isekai" => "36",
+				"Josei" => "43",
+				"Leveling" => "170",
+				"Magic" => "13",
+				"Martial Arts" => "29",
+				"Mature" => "98",
+				"Military" => "136",
+				"Monster" => "10",
+				"Murim" => "138",
+				"Mystery" => "122",
+				"Novel" => "50",
+				"Official" => "60",
+				"Pokemon" => "96",
+				"Post-Apocalyptic" => "168",
+				"Psychological" => "81",
+				"Pyschological" => "105",
+				"Reincarnation" => "38",
+				"Revenge" => "125",
+				"Romance" => "40",
+				"School Life" => "132",
+				"Sci-fi" => "97",
+				"Seinen" => "77",
+				"Shoujo" => "41",
+				"Shoujo Ai" => "174",
+				"Shounen" => "24",
+				"Slice of Life" => "138",
+				"Sports" => "28",
+				"Supernatural" => "15",
+				"Survival" => "133",
+				"Sword and Magic" => "192",
+				"System" => "122",
+				"Thriller" => "82",
+				"Time Travel" => "211",
+				"Tragedy" => "64",
+				"Transmigration" => "185",
+				"VR" => "162",
+				"Video Games" => "29",
+				"Zombies" => "68",
+				_ => "",
+			})
+		},
+		traverse_pathname: "series",
+		..Default::default()
+	}
+}
+
+#[get_manga_list]
+fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
+	get_instance().parse_manga_list(filters, page)
+}
+
+#[get_manga_listing]
+fn get_manga_listing(listing: Listing, page: i32) -> Result<MangaPageResult> {
+	get_instance().parse_manga_listing(get_instance().base_url, listing.name, page)
+}
+
+#[get_manga_details]
+fn get_manga_details(id: String) -> Result<Manga> {
+	get_instance().parse_manga_details(id)
+}
+
+#[get_chapter_list]
+fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
+	get_instance().parse_chapter_list(id)
+}
+
+#[get_page_list]
+fn get_page_list(_manga_id: String, id: String) -> Result<Vec<Page>> {
+	get_instance().parse_page_list(id)
+}
+
+#[modify_image_request]
+fn modify_image_request(request: Request) {
+	get_instance().modify_image_request(request)
+}
+
+#[handle_url]
+pub fn handle_url(url: String) -> Result<DeepLink> {
+	get_instance().handle_url(url)
+}
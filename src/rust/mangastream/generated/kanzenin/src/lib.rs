@@ -1,18 +1,29 @@
+// Generated from sites/kanzenin/site.toml, don't edit.
 #![no_std]
 use aidoku::{
-	error::Result, prelude::*, std::net::Request, std::String, std::Vec, Chapter, DeepLink, Filter,
-	Listing, Manga, MangaPageResult, Page,
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	std::net::Request,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	Page,
 };
 
 use mangastream_template::template::MangaStreamSource;
 
 fn get_instance() -> MangaStreamSource {
 	MangaStreamSource {
+		alt_pages: true,
 		base_url: String::from("https://kanzenin.info"),
-		is_nsfw: true,
 		chapter_date_format: "MMMM d, yyyy",
+		is_nsfw: true,
 		locale: "id",
-		alt_pages: true,
 		..Default::default()
 	}
 }
@@ -1,7 +1,18 @@
+// Generated from sites/komikindo/site.toml, don't edit.
 #![no_std]
 use aidoku::{
-	error::Result, prelude::*, std::net::Request, std::String, std::Vec, Chapter, DeepLink, Filter,
-	Listing, Manga, MangaPageResult, Page,
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	std::net::Request,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	Page,
 };
 
 use mangastream_template::template::MangaStreamSource;
@@ -10,11 +21,11 @@ fn get_instance() -> MangaStreamSource {
 	MangaStreamSource {
 		base_url: String::from("https://komikindo.co"),
 		chapter_date_format: "MMMM d, yyyy",
-		manga_details_author: "td:contains(Author)+td",
+		locale: "id",
 		manga_details_artist: "td:contains(Artist)+td",
-		manga_details_type: "td:contains(Type)+td",
+		manga_details_author: "td:contains(Author)+td",
 		manga_details_categories: ".seriestugenre a",
-		locale: "id",
+		manga_details_type: "td:contains(Type)+td",
 		..Default::default()
 	}
 }
@@ -1,22 +1,33 @@
+// Generated from sites/komiktap/site.toml, don't edit.
 #![no_std]
 use aidoku::{
-	error::Result, prelude::*, std::net::Request, std::String, std::Vec, Chapter, DeepLink, Filter,
-	Listing, Manga, MangaPageResult, Page,
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	std::net::Request,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	Page,
 };
 
 use mangastream_template::template::MangaStreamSource;
 
 fn get_instance() -> MangaStreamSource {
 	MangaStreamSource {
+		alt_pages: true,
 		base_url: String::from("https://komiktap.me"),
-		is_nsfw: true,
 		chapter_date_format: "MMMM d, yyyy",
-		manga_details_author: "td:contains(Author)+td",
+		is_nsfw: true,
+		locale: "id",
 		manga_details_artist: "td:contains(Artist)+td",
-		manga_details_type: "td:contains(Type)+td",
+		manga_details_author: "td:contains(Author)+td",
 		manga_details_categories: ".seriestugenre a",
-		locale: "id",
-		alt_pages: true,
+		manga_details_type: "td:contains(Type)+td",
 		..Default::default()
 	}
 }
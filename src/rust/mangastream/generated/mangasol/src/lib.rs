@@ -1,22 +1,33 @@
+// Generated from sites/mangasol/site.toml, don't edit.
 #![no_std]
 use aidoku::{
-	error::Result, prelude::*, std::net::Request, std::String, std::Vec, Chapter, DeepLink, Filter,
-	Listing, Manga, MangaPageResult, Page,
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	std::net::Request,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	Page,
 };
 
 use mangastream_template::template::MangaStreamSource;
 
 fn get_instance() -> MangaStreamSource {
 	MangaStreamSource {
-		listing: ["الرائج", "آخر", "جَديد"],
+		alt_pages: true,
 		base_url: String::from("https://www.mangasol.com"),
-		last_page_text: "التالي",
-		chapter_selector: ".chapter li",
 		chapter_date_format: "MMMM d, yyyy",
-		manga_details_description: "#story p",
-		manga_details_categories: ".manga-genres a",
+		chapter_selector: ".chapter li",
+		last_page_text: "التالي",
+		listing: ["الرائج", "آخر", "جَديد"],
 		locale: "ar_EH",
-		alt_pages: true,
+		manga_details_categories: ".manga-genres a",
+		manga_details_description: "#story p",
 		..Default::default()
 	}
 }
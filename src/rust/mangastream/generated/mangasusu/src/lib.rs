@@ -1,23 +1,34 @@
+// Generated from sites/mangasusu/site.toml, don't edit.
 #![no_std]
 use aidoku::{
-	error::Result, prelude::*, std::net::Request, std::String, std::Vec, Chapter, DeepLink, Filter,
-	Listing, Manga, MangaPageResult, Page,
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	std::net::Request,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	Page,
 };
 
 use mangastream_template::template::MangaStreamSource;
 
 fn get_instance() -> MangaStreamSource {
 	MangaStreamSource {
+		alt_pages: true,
 		base_url: String::from("https://mangasusuku.xyz"),
-		traverse_pathname: "komik",
+		chapter_date_format: "MMMM d, yyyy",
 		is_nsfw: true,
-		manga_details_author: "td:contains(Author)+td",
+		locale: "en",
 		manga_details_artist: "td:contains(Artist)+td",
-		manga_details_type: "td:contains(Type)+td",
+		manga_details_author: "td:contains(Author)+td",
 		manga_details_categories: ".seriestugenre a",
-		chapter_date_format: "MMMM d, yyyy",
-		locale: "en",
-		alt_pages: true,
+		manga_details_type: "td:contains(Type)+td",
+		traverse_pathname: "komik",
 		..Default::default()
 	}
 }
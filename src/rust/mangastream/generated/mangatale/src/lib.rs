@@ -1,7 +1,18 @@
+// Generated from sites/mangatale/site.toml, don't edit.
 #![no_std]
 use aidoku::{
-	error::Result, prelude::*, std::net::Request, std::String, std::Vec, Chapter, DeepLink, Filter,
-	Listing, Manga, MangaPageResult, Page,
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	std::net::Request,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	Page,
 };
 
 use mangastream_template::template::MangaStreamSource;
@@ -9,8 +20,8 @@ use mangastream_template::template::MangaStreamSource;
 fn get_instance() -> MangaStreamSource {
 	MangaStreamSource {
 		base_url: String::from("https://mangatale.co"),
-		manga_title_trim: [" Bahasa Indonesia".into()].to_vec(),
 		chapter_date_format: "MMMM d, yyyy",
+		manga_title_trim: [String::from(" Bahasa Indonesia")].to_vec(),
 		protocol: true,
 		..Default::default()
 	}
@@ -1,26 +1,37 @@
+// Generated from sites/manhwafreak/site.toml, don't edit.
 #![no_std]
 use aidoku::{
-	error::Result, prelude::*, std::net::Request, std::String, std::Vec, Chapter, DeepLink, Filter,
-	Listing, Manga, MangaPageResult, Page,
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	std::net::Request,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	Page,
 };
 
 use mangastream_template::template::MangaStreamSource;
 
 fn get_instance() -> MangaStreamSource {
 	MangaStreamSource {
+		alt_pages: true,
 		base_url: String::from("https://manhwafreak.com"),
-		manga_selector: ".listupd .lastest-serie",
-		manga_details_title: ".wrapper .series h1.title",
+		chapter_date: ".chapter-info > p:nth-child(2)",
+		chapter_selector: ".chapter-li > a",
+		chapter_title: ".chapter-info > p:first-child",
 		manga_details_artist: ".wrapper .series #info div:contains(Artist) > p+p",
 		manga_details_author: ".wrapper .series #info div:contains(Author) > p+p",
-		manga_details_status: ".wrapper .series #info div:contains(Status) > p+p",
-		manga_details_description: ".wrapper .series #summary",
 		manga_details_categories: ".wrapper .series #info div:contains(Genre) > p+p",
+		manga_details_description: ".wrapper .series #summary",
+		manga_details_status: ".wrapper .series #info div:contains(Status) > p+p",
+		manga_details_title: ".wrapper .series h1.title",
 		manga_details_type: ".wrapper .series #info div:contains(Type) > p+p",
-		chapter_selector: ".chapter-li > a",
-		chapter_title: ".chapter-info > p:first-child",
-		chapter_date: ".chapter-info > p:nth-child(2)",
-		alt_pages: true,
+		manga_selector: ".listupd .lastest-serie",
 		..Default::default()
 	}
 }
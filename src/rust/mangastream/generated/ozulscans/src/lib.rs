@@ -1,19 +1,30 @@
+// Generated from sites/ozulscans/site.toml, don't edit.
 #![no_std]
 use aidoku::{
-	error::Result, prelude::*, std::net::Request, std::String, std::Vec, Chapter, DeepLink, Filter,
-	Listing, Manga, MangaPageResult, Page,
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	std::net::Request,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	Page,
 };
 
 use mangastream_template::template::MangaStreamSource;
 
 fn get_instance() -> MangaStreamSource {
 	MangaStreamSource {
+		alt_pages: true,
 		base_url: String::from("https://ozulscans.com"),
 		chapter_date_format: "MMMM d, yyyy",
-		manga_details_author: ".imptdt:contains(المؤلف) i",
-		locale: "ar_EH",
 		last_page_text: "التالي",
-		alt_pages: true,
+		locale: "ar_EH",
+		manga_details_author: ".imptdt:contains(المؤلف) i",
 		..Default::default()
 	}
 }
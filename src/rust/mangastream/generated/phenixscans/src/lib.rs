@@ -1,20 +1,31 @@
+// Generated from sites/phenixscans/site.toml, don't edit.
 #![no_std]
 use aidoku::{
-	error::Result, prelude::*, std::net::Request, std::String, std::Vec, Chapter, DeepLink, Filter,
-	Listing, Manga, MangaPageResult, Page,
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	std::net::Request,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	Page,
 };
 
 use mangastream_template::template::MangaStreamSource;
 
 fn get_instance() -> MangaStreamSource {
 	MangaStreamSource {
-		use_manga_postids: true,
-		use_chapter_postids: true,
+		alt_pages: true,
 		base_url: String::from("https://phenixscans.fr/"),
 		listing: ["Dernières", "Populaire", "Nouveau"],
 		manga_details_author: ".imptdt:contains(Auteur) i, .fmed b:contains(Auteur)+span",
 		traverse_pathname: "manga",
-		alt_pages: true,
+		use_chapter_postids: true,
+		use_manga_postids: true,
 		..Default::default()
 	}
 }
@@ -1,7 +1,18 @@
+// Generated from sites/senpaiediciones/site.toml, don't edit.
 #![no_std]
 use aidoku::{
-	error::Result, prelude::*, std::net::Request, std::String, std::Vec, Chapter, DeepLink, Filter,
-	Listing, Manga, MangaPageResult, Page,
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	std::net::Request,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	Page,
 };
 
 use mangastream_template::template::MangaStreamSource;
@@ -9,14 +20,13 @@ use mangastream_template::template::MangaStreamSource;
 fn get_instance() -> MangaStreamSource {
 	MangaStreamSource {
 		base_url: String::from("https://senpaiediciones.com"),
-		listing: ["ultimo", "Populares", "nueva"],
-		//alt_pages: true,
+		chapter_date_format: "MMMM d, yyyy",
 		last_page_text: "Siguiente",
-		manga_details_author: "td:contains(Autor)+td",
+		listing: ["ultimo", "Populares", "nueva"],
+		locale: "es",
 		manga_details_artist: "td:contains(Artista)+td",
+		manga_details_author: "td:contains(Autor)+td",
 		manga_details_type: "td:contains(Tipo)+td",
-		chapter_date_format: "MMMM d, yyyy",
-		locale: "es",
 		..Default::default()
 	}
 }
@@ -1,7 +1,18 @@
+// Generated from sites/sushiscan/site.toml, don't edit.
 #![no_std]
 use aidoku::{
-	error::Result, prelude::*, std::net::Request, std::String, std::Vec, Chapter, DeepLink, Filter,
-	Listing, Manga, MangaPageResult, Page,
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	std::net::Request,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	Page,
 };
 
 use mangastream_template::template::MangaStreamSource;
@@ -9,12 +20,13 @@ use mangastream_template::template::MangaStreamSource;
 fn get_instance() -> MangaStreamSource {
 	MangaStreamSource {
 		base_url: String::from("https://sushiscan.net"),
-		traverse_pathname: "catalogue",
-		listing: ["Dernières", "Populaire", "Nouveau"],
-		status_options: ["En Cours", "Terminé", "", "", ""],
-		manga_details_author: ".imptdt:contains(Auteur) i, .fmed b:contains(Auteur)+span",
+		https_pages: true,
 		language: "fr",
+		listing: ["Dernières", "Populaire", "Nouveau"],
 		locale: "fr-FR",
+		manga_details_author: ".imptdt:contains(Auteur) i, .fmed b:contains(Auteur)+span",
+		status_options: ["En Cours", "Terminé", "", "", ""],
+		traverse_pathname: "catalogue",
 		..Default::default()
 	}
 }
@@ -41,15 +53,7 @@ fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
 
 #[get_page_list]
 fn get_page_list(_manga_id: String, id: String) -> Result<Vec<Page>> {
-	let pages = get_instance().parse_page_list(id)?;
-	// fix http -> https
-	let mut new_pages = Vec::new();
-	for page in pages {
-		let mut new_page = page;
-		new_page.url = new_page.url.replace("http://", "https://");
-		new_pages.push(new_page);
-	}
-	Ok(new_pages)
+	get_instance().parse_page_list(id)
 }
 
 #[modify_image_request]
@@ -1,27 +1,37 @@
+// Generated from sites/swatmanga/site.toml, don't edit.
 #![no_std]
-
 use aidoku::{
-	error::Result, prelude::*, std::net::Request, std::String, std::Vec, Chapter, DeepLink, Filter,
-	Listing, Manga, MangaPageResult, Page,
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	std::net::Request,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	Page,
 };
 
 use mangastream_template::template::MangaStreamSource;
 
 fn get_instance() -> MangaStreamSource {
 	MangaStreamSource {
+		alt_pages: true,
+		base_url: String::from("https://swatop.club"),
+		chapter_date_format: "yyyy-MM-dd",
+		chapter_selector: ".bxcl ul li",
+		chapter_title: "span.lchx",
 		has_random_chapter_prefix: true,
+		last_page_text: "التالي",
 		listing: ["الرائج", "آخر", "جَديد"],
-		base_url: String::from("https://swatop.club"),
-		manga_details_title: ".infox h1",
 		manga_details_author: "span:contains(المؤلف)",
 		manga_details_categories: ".spe a",
 		manga_details_cover: ".ime noscript img",
+		manga_details_title: ".infox h1",
 		manga_details_type: "span:contains(النوع) a",
-		last_page_text: "التالي",
-		chapter_selector: ".bxcl ul li",
-		chapter_title: "span.lchx",
-		chapter_date_format: "yyyy-MM-dd",
-		alt_pages: true,
 		..Default::default()
 	}
 }
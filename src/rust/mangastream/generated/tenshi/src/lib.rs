@@ -1,18 +1,29 @@
+// Generated from sites/tenshi/site.toml, don't edit.
 #![no_std]
 use aidoku::{
-	error::Result, prelude::*, std::net::Request, std::String, std::Vec, Chapter, DeepLink, Filter,
-	Listing, Manga, MangaPageResult, Page,
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	std::net::Request,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	Page,
 };
 
 use mangastream_template::template::MangaStreamSource;
 
 fn get_instance() -> MangaStreamSource {
 	MangaStreamSource {
+		alt_pages: true,
 		base_url: String::from("https://tenshi.id"),
 		chapter_date_format: "MMMM d, yyyy",
-		traverse_pathname: "komik",
-		alt_pages: true,
 		locale: "id",
+		traverse_pathname: "komik",
 		..Default::default()
 	}
 }
@@ -1,19 +1,30 @@
+// Generated from sites/walpurgiscan/site.toml, don't edit.
 #![no_std]
 use aidoku::{
-	error::Result, prelude::*, std::net::Request, std::String, std::Vec, Chapter, DeepLink, Filter,
-	Listing, Manga, MangaPageResult, Page,
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	std::net::Request,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	Page,
 };
 
 use mangastream_template::template::MangaStreamSource;
 
 fn get_instance() -> MangaStreamSource {
 	MangaStreamSource {
+		alt_pages: true,
 		base_url: String::from("https://www.walpurgiscan.it"),
-		listing: ["Ultimi", "Popolarità", "Novità"],
-		manga_details_author: ".fmed b:contains(Autore)+span",
 		language: "it",
+		listing: ["Ultimi", "Popolarità", "Novità"],
 		locale: "it_IT",
-		alt_pages: true,
+		manga_details_author: ".fmed b:contains(Autore)+span",
 		..Default::default()
 	}
 }
@@ -49,6 +60,6 @@ fn modify_image_request(request: Request) {
 }
 
 #[handle_url]
-fn handle_url(url: String) -> Result<DeepLink> {
+pub fn handle_url(url: String) -> Result<DeepLink> {
 	get_instance().handle_url(url)
 }
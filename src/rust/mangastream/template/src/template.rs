@@ -1,6 +1,7 @@
 use aidoku::{
 	error::{AidokuError, AidokuErrorKind, Result},
 	prelude::format,
+	std::defaults::defaults_get,
 	std::json::parse,
 	std::net::{HttpMethod, Request},
 	std::{String, Vec},
@@ -9,6 +10,7 @@ use aidoku::{
 };
 
 use crate::helper::*;
+use page_filter::PageFilter;
 
 pub struct MangaStreamSource {
 	/// Use static post ids instead of dynamic ids parsed from urls \
@@ -22,6 +24,9 @@ pub struct MangaStreamSource {
 	pub is_nsfw: bool,
 	pub tagid_mapping: fn(String) -> String,
 	pub listing: [&'static str; 3],
+	/// Extra query parameters for the listings, like
+	/// `genre%5B%5D=raw&type=manhwa` to only list raws
+	pub listing_query: &'static str,
 	pub base_url: String,
 	pub traverse_pathname: &'static str,
 	pub next_page: &'static str,
@@ -59,9 +64,20 @@ pub struct MangaStreamSource {
 	pub date_string: &'static str,
 
 	pub alt_pages: bool,
+	/// Selector of the external script that has the page list, for
+	/// `alt_pages` sites that don't inline it
+	pub pages_script: &'static str,
 	pub page_selector: &'static str,
 	pub page_url: &'static str,
 	pub protocol: bool,
+	/// Load `http://` images over https
+	pub https_pages: bool,
+	/// Extra regexes for images to leave out of chapters, on top of the
+	/// shared blocklist
+	pub page_blocklist: &'static [&'static str],
+	/// Leave out credit and recruitment pages, unless the source has a
+	/// `dropCreditPages` setting turned off
+	pub drop_credit_pages: bool,
 }
 impl Default for MangaStreamSource {
 	fn default() -> Self {
@@ -75,6 +91,7 @@ impl Default for MangaStreamSource {
 			is_nsfw: false,
 			tagid_mapping: |str| str,
 			listing: ["Latest", "Popular", "New"],
+			listing_query: "",
 			base_url: String::new(),
 			traverse_pathname: "manga",
 			next_page: ".hpage a.r",
@@ -112,9 +129,13 @@ impl Default for MangaStreamSource {
 			locale_2: "",
 
 			alt_pages: false,
+			pages_script: "",
 			page_selector: "#readerarea img",
 			page_url: "src",
-			protocol: false
+			protocol: false,
+			https_pages: false,
+			page_blocklist: &[],
+			drop_credit_pages: false,
 		}
 	}
 }
@@ -180,13 +201,18 @@ impl MangaStreamSource {
 		page: i32,
 	) -> Result<MangaPageResult> {
 		let url = if base_url == self.base_url {
-			get_listing_url(
+			let url = get_listing_url(
 				self.listing,
 				base_url,
 				String::from(self.traverse_pathname),
 				listing_name,
 				page,
-			)
+			);
+			if self.listing_query.is_empty() {
+				url
+			} else {
+				format!("{}&{}", url, self.listing_query)
+			}
 		} else {
 			base_url
 		};
@@ -425,12 +451,25 @@ impl MangaStreamSource {
 			format!("{}/{}", self.base_url, id)
 		};
 
+		// svgs, trackers and ads are dropped here, as the page has them so
+		// patterns like `^data:` still match
+		let setting = defaults_get("dropCreditPages")
+			.and_then(|value| value.as_bool())
+			.ok();
+		let filter = PageFilter::for_source(self.page_blocklist, self.drop_credit_pages, setting);
+
 		let mut pages: Vec<Page> = Vec::new();
+		let mut urls: Vec<String> = Vec::new();
 		let html = Request::new(url, HttpMethod::Get)
 			.header("Referer", &self.base_url)
 			.html()?;
 		if self.alt_pages {
-			let raw_text = html.select("script").html().read();
+			let raw_text = if self.pages_script.is_empty() {
+				html.select("script").html().read()
+			} else {
+				let script_url = html.select(self.pages_script).attr("src").read();
+				Request::new(script_url, HttpMethod::Get).string()?
+			};
 
 			let trimmed_json = {
 				let mut trimmed_json = &raw_text[raw_text.find(r#":[{"s"#).unwrap_or(0) + 2
@@ -450,35 +489,41 @@ impl MangaStreamSource {
 
 			let json = parse(trimmed_json.as_bytes())?.as_object()?;
 			let images = json.get("images").as_array()?;
-			for (index, page) in images.enumerate() {
-				let page_url = urlencode(page.as_string()?.read());
-				pages.push(Page {
-					index: index as i32,
-					url: page_url,
-					..Default::default()
-				});
+			for page in images {
+				let page_url = page.as_string()?.read();
+				if !filter.is_blocked(&page_url) {
+					urls.push(urlencode(page_url));
+				}
 			}
-			Ok(pages)
 		} else {
-			for (at, page) in html.select(self.page_selector).array().enumerate() {
+			for page in html.select(self.page_selector).array() {
 				let page_node = page.as_node().expect("Failed to get page as node");
+				let page_url = page_node.attr(self.page_url).read();
+				if filter.is_blocked(&page_url) {
+					continue;
+				}
 				let page_url = if self.protocol {
-					format!("https:{}", urlencode(page_node.attr(self.page_url).read()))
+					format!("https:{}", urlencode(page_url))
 				} else {
-					urlencode(page_node.attr(self.page_url).read())
+					urlencode(page_url)
 				};
-				// avoid svgs
-				if page_url.starts_with("data") {
-					continue;
-				}
-				pages.push(Page {
-					index: at as i32,
-					url: page_url,
-					..Default::default()
-				});
+				urls.push(page_url);
 			}
-			Ok(pages)
 		}
+
+		for (index, url) in urls.into_iter().enumerate() {
+			let url = if self.https_pages {
+				url.replace("http://", "https://")
+			} else {
+				url
+			};
+			pages.push(Page {
+				index: index as i32,
+				url,
+				..Default::default()
+			});
+		}
+		Ok(pages)
 	}
 
 	pub fn modify_image_request(&self, request: Request) {
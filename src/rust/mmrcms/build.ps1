@@ -27,9 +27,11 @@ function Package-Source {
 	)
 	$Name | ForEach-Object  {
 		$source = $_
+		# hand-written sources are in sources/, the rest in generated/
+		$dir = if (Test-Path ./sources/$source) { "sources" } else { "generated" }
 		if ($Build) {
 			Write-Output "building $source"
-			Set-Location ./sources/$source
+			Set-Location ./$dir/$source
 			cargo +nightly build --release
 			Set-Location ../..
 		}
@@ -37,7 +39,7 @@ function Package-Source {
 		Write-Output "packaging $source"
 		New-Item -ItemType Directory -Path target/wasm32-unknown-unknown/release/Payload -Force | Out-Null
 		Copy-Item res/* target/wasm32-unknown-unknown/release/Payload -ErrorAction SilentlyContinue
-		Copy-Item sources/$source/res/* target/wasm32-unknown-unknown/release/Payload -ErrorAction SilentlyContinue
+		Copy-Item $dir/$source/res/* target/wasm32-unknown-unknown/release/Payload -ErrorAction SilentlyContinue
 		Set-Location target/wasm32-unknown-unknown/release
 		Copy-Item "$source.wasm" Payload/main.wasm
 		Compress-Archive -Force -Path Payload -DestinationPath "../../../$source.aix"
@@ -51,9 +53,19 @@ if ($help -or ($null -eq $PSBoundParameters.Keys)) {
 	break
 }
 
+# write the crates for the sources described by sites/*/site.toml
+$workspace = (Get-Location).Path
+Push-Location ../../../lib/rust/site-generator
+cargo run --release -q -- $workspace
+$generated = $?
+Pop-Location
+if (-not $generated) {
+	exit 1
+}
+
 if ($all) {
 	cargo +nightly build --release
-	Get-ChildItem ./sources | ForEach-Object {
+	Get-ChildItem ./sources, ./generated | ForEach-Object {
 		$source = (Split-Path -Leaf $_)
 		Package-Source $source
 	}
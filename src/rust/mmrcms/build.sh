@@ -1,6 +1,22 @@
 # template source build script
 # usage: ./build.sh [source_name/-a]
 
+# write the crates for the sources described by sites/*/site.toml
+(
+	WORKSPACE="$(pwd)"
+	cd ../../../lib/rust/site-generator
+	cargo run --release -q -- "$WORKSPACE"
+) || exit 1
+
+# hand-written sources are in sources/, the rest in generated/
+source_dir() {
+	if [ -d "sources/$1" ]; then
+		echo "sources/$1"
+	else
+		echo "generated/$1"
+	fi
+}
+
 if [ "$1" != "-a" ] && [ "$1" != "" ]; then
 	# compile specified source
 	cargo +nightly build --release
@@ -8,7 +24,7 @@ if [ "$1" != "-a" ] && [ "$1" != "" ]; then
 	echo "packaging $1";
 	mkdir -p target/wasm32-unknown-unknown/release/Payload
 	cp res/* target/wasm32-unknown-unknown/release/Payload
-	cp sources/$1/res/* target/wasm32-unknown-unknown/release/Payload
+	cp $(source_dir $1)/res/* target/wasm32-unknown-unknown/release/Payload
 	cd target/wasm32-unknown-unknown/release
 	cp $1.wasm Payload/main.wasm
 	zip -r $1.aix Payload
@@ -18,7 +34,7 @@ else
 	# compile all sources
 	cargo +nightly build --release
 
-	for dir in sources/*/
+	for dir in sources/*/ generated/*/
 	do
 		dir=${dir%*/}
 		dir=${dir##*/}
@@ -26,7 +42,7 @@ else
 
 		mkdir -p target/wasm32-unknown-unknown/release/Payload
 		cp res/* target/wasm32-unknown-unknown/release/Payload
-		cp sources/$dir/res/* target/wasm32-unknown-unknown/release/Payload
+		cp $(source_dir $dir)/res/* target/wasm32-unknown-unknown/release/Payload
 		cd target/wasm32-unknown-unknown/release
 		cp $dir.wasm Payload/main.wasm
 		zip -r $dir.aix Payload >> /dev/null
@@ -3,7 +3,7 @@ use aidoku::{
 	error::Result,
 	prelude::*,
 	std::{net::Request, String, Vec},
-	Chapter, DeepLink, Filter, Manga, MangaContentRating, MangaPageResult, Page,
+	Chapter, DeepLink, Filter, Listing, Manga, MangaContentRating, MangaPageResult, Page,
 };
 use lazy_static::lazy_static;
 use mmrcms_template::template::{cache_manga_page, MMRCMSSource, CACHED_MANGA};
@@ -33,6 +33,17 @@ fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
 	Ok(result)
 }
 
+#[get_manga_listing]
+fn get_manga_listing(listing: Listing, page: i32) -> Result<MangaPageResult> {
+	let mut result = INSTANCE.get_manga_listing(listing, page)?;
+	result
+		.manga
+		.iter_mut()
+		.for_each(|manga| manga.title = manga.title.replace(" (pt-br)", ""));
+
+	Ok(result)
+}
+
 #[get_manga_details]
 fn get_manga_details(id: String) -> Result<Manga> {
 	let url = format!("{}/{}/{}", INSTANCE.base_url, INSTANCE.manga_path, id);
@@ -1,4 +1,7 @@
-use aidoku::std::String;
+use aidoku::{
+	std::{String, Vec},
+	MangaContentRating, MangaViewer,
+};
 
 pub fn extract_f32_from_string(title: String, text: String) -> f32 {
 	text.replace(&title, "")
@@ -21,3 +24,37 @@ pub fn append_protocol(url: String) -> String {
 		url
 	}
 }
+
+/// The id a select filter searches with for the option at `idx`, from the
+/// ids of all its options. Out of range options search for nothing.
+pub fn id_from_list(ids: &[&str], idx: i64) -> String {
+	usize::try_from(idx)
+		.ok()
+		.and_then(|idx| ids.get(idx))
+		.map(|id| String::from(*id))
+		.unwrap_or_default()
+}
+
+/// A `category_parser` for sites that only differ from the default one in
+/// the names of their categories: any of `nsfw` makes a manga NSFW, any of
+/// `suggestive` suggestive, and any of `scroll` reads it as a webtoon.
+pub fn parse_categories(
+	categories: Vec<String>,
+	nsfw: &[&str],
+	suggestive: &[&str],
+	scroll: &[&str],
+) -> (MangaContentRating, MangaViewer) {
+	let mut rating = MangaContentRating::Safe;
+	let mut viewer = MangaViewer::Rtl;
+	for category in categories {
+		let category = category.trim();
+		if nsfw.contains(&category) {
+			rating = MangaContentRating::Nsfw;
+		} else if suggestive.contains(&category) && !matches!(rating, MangaContentRating::Nsfw) {
+			rating = MangaContentRating::Suggestive;
+		} else if scroll.contains(&category) {
+			viewer = MangaViewer::Scroll;
+		}
+	}
+	(rating, viewer)
+}
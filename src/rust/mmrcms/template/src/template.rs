@@ -3,16 +3,18 @@ use aidoku::{
 	helpers::{cfemail::decode_cfemail, substring::Substring, uri::encode_uri_component},
 	prelude::format,
 	std::{
+		defaults::defaults_get,
 		html::Node,
 		json,
 		net::{HttpMethod, Request},
 		ObjectRef, String, Vec,
 	},
-	Chapter, DeepLink, Filter, FilterType, Manga, MangaContentRating, MangaPageResult, MangaStatus,
-	MangaViewer, Page,
+	Chapter, DeepLink, Filter, FilterType, Listing, Manga, MangaContentRating, MangaPageResult,
+	MangaStatus, MangaViewer, Page,
 };
 
 use crate::helper::{append_protocol, extract_f32_from_string};
+use page_filter::PageFilter;
 
 pub static mut CACHED_MANGA: Option<Node> = None;
 static mut CACHED_MANGA_ID: Option<String> = None;
@@ -54,6 +56,13 @@ pub struct MMRCMSSource<'a> {
 	pub tags_mapper: fn(i64) -> String,
 
 	pub use_search_engine: bool,
+
+	/// Extra regexes for images to leave out of chapters, on top of the
+	/// shared blocklist
+	pub page_blocklist: &'a [&'a str],
+	/// Leave out credit and recruitment pages, unless the source has a
+	/// `dropCreditPages` setting turned off
+	pub drop_credit_pages: bool,
 }
 
 #[derive(Default)]
@@ -112,6 +121,8 @@ impl<'a> Default for MMRCMSSource<'a> {
 			}, // 0 is reserved for None
 			tags_mapper: |_| String::new(),
 			use_search_engine: true,
+			page_blocklist: &[],
+			drop_credit_pages: false,
 		}
 	}
 }
@@ -255,48 +266,108 @@ impl<'a> MMRCMSSource<'a> {
 				self.self_search(title)
 			}
 		} else {
-			let url = format!(
-				"{}/filterList?page={}&{}",
-				self.base_url,
-				itoa::Buffer::new().format(page),
-				query.join("&")
-			);
-			let html = Request::new(&url, HttpMethod::Get).html()?;
-			decode_cfemail(&html);
-			let node = html.select("div[class^=col-sm-]");
-			let elems = node.array();
-			let mut manga = Vec::with_capacity(elems.len());
-			let has_more: bool = !elems.is_empty();
-
-			for elem in elems {
-				if let Ok(manga_node) = elem.as_node() {
-					let url = manga_node
-						.select(format!("a[href*='{}/{}']", self.base_url, self.manga_path))
-						.attr("abs:href")
-						.read();
-					let id = url.replace(&format!("{}/{}/", self.base_url, self.manga_path), "");
-					let cover = self.guess_cover(
-						&manga_node
-							.select(format!(
-								"a[href*='{}/{}'] img",
-								self.base_url, self.manga_path
-							))
-							.attr("abs:src")
-							.read(),
-						&id,
-					);
-					let title = manga_node.select("a.chart-title strong").text().read();
-					manga.push(Manga {
-						id: id.clone(),
-						cover,
-						title,
-						url,
-						..Default::default()
-					});
+			self.filter_list(page, &query.join("&"))
+		}
+	}
+
+	fn filter_list(&self, page: i32, query: &str) -> Result<MangaPageResult> {
+		let url = format!(
+			"{}/filterList?page={}&{}",
+			self.base_url,
+			itoa::Buffer::new().format(page),
+			query
+		);
+		let html = Request::new(&url, HttpMethod::Get).html()?;
+		decode_cfemail(&html);
+		let node = html.select("div[class^=col-sm-]");
+		let elems = node.array();
+		let mut manga = Vec::with_capacity(elems.len());
+		let has_more: bool = !elems.is_empty();
+
+		for elem in elems {
+			if let Ok(manga_node) = elem.as_node() {
+				let url = manga_node
+					.select(format!("a[href*='{}/{}']", self.base_url, self.manga_path))
+					.attr("abs:href")
+					.read();
+				let id = url.replace(&format!("{}/{}/", self.base_url, self.manga_path), "");
+				let cover = self.guess_cover(
+					&manga_node
+						.select(format!(
+							"a[href*='{}/{}'] img",
+							self.base_url, self.manga_path
+						))
+						.attr("abs:src")
+						.read(),
+					&id,
+				);
+				let title = manga_node.select("a.chart-title strong").text().read();
+				manga.push(Manga {
+					id: id.clone(),
+					cover,
+					title,
+					url,
+					..Default::default()
+				});
+			}
+		}
+		Ok(MangaPageResult { manga, has_more })
+	}
+
+	/// The manga with new chapters, newest first. A manga can show up on
+	/// more than one page if it had several releases.
+	fn latest_release(&self, page: i32) -> Result<MangaPageResult> {
+		let url = format!(
+			"{}/latest-release?page={}",
+			self.base_url,
+			itoa::Buffer::new().format(page)
+		);
+		let html = Request::new(&url, HttpMethod::Get).html()?;
+		decode_cfemail(&html);
+		let mut manga: Vec<Manga> = Vec::new();
+		for elem in html.select("div.mangalist div.manga-item").array() {
+			if let Ok(manga_node) = elem.as_node() {
+				let link = manga_node
+					.select("h3.manga-heading a, a.chart-title")
+					.first();
+				let url = link.attr("abs:href").read();
+				let Some(id) = self.manga_id(&url) else {
+					continue;
+				};
+				if manga.iter().any(|manga| manga.id == id) {
+					continue;
 				}
+				manga.push(Manga {
+					cover: self.guess_cover("", &id),
+					id,
+					title: link.text().read(),
+					url,
+					..Default::default()
+				});
 			}
-			Ok(MangaPageResult { manga, has_more })
 		}
+		let has_more = !html.select("a[rel=next]").array().is_empty();
+		Ok(MangaPageResult { manga, has_more })
+	}
+
+	pub fn get_manga_listing(&self, listing: Listing, page: i32) -> Result<MangaPageResult> {
+		match listing.name.as_str() {
+			"Latest release" => self.latest_release(page),
+			"Most popular" => self.filter_list(page, "sortBy=views&asc=false"),
+			// titles with a recent release, once each unlike in the latest releases
+			"Hot" => self.filter_list(page, "sortBy=last_release&asc=false"),
+			_ => Err(AidokuError {
+				reason: aidoku::error::AidokuErrorKind::Unimplemented,
+			}),
+		}
+	}
+
+	/// The manga id in a manga or chapter URL, the part after the manga path.
+	fn manga_id(&self, url: &str) -> Option<String> {
+		url.substring_after(&format!("/{}/", self.manga_path))
+			.and_then(|path| path.split(['/', '?', '#']).next())
+			.filter(|id| !id.is_empty())
+			.map(String::from)
 	}
 
 	pub fn get_manga_details(&self, id: String) -> Result<Manga> {
@@ -429,9 +500,9 @@ impl<'a> MMRCMSSource<'a> {
 				.unwrap_or_default(),
 		)?
 		.as_array()?;
-		let mut pages = Vec::with_capacity(array.len());
+		let mut urls = Vec::with_capacity(array.len());
 
-		for (idx, page) in array.enumerate() {
+		for page in array {
 			if let Ok(pageobj) = page.as_object()
 			   && let Ok(page_image) = pageobj.get("page_image").as_string() {
 				let page_image = page_image.read();
@@ -440,14 +511,23 @@ impl<'a> MMRCMSSource<'a> {
 				} else {
 					page_image
 				};
-				pages.push(Page {
-					index: idx as i32,
-					url,
-					..Default::default()
-				});
+				urls.push(url);
 			}
 		}
-		Ok(pages)
+		let setting = defaults_get("dropCreditPages")
+			.and_then(|value| value.as_bool())
+			.ok();
+		let filter = PageFilter::for_source(self.page_blocklist, self.drop_credit_pages, setting);
+		Ok(filter
+			.filter(urls)
+			.into_iter()
+			.enumerate()
+			.map(|(idx, url)| Page {
+				index: idx as i32,
+				url,
+				..Default::default()
+			})
+			.collect())
 	}
 
 	pub fn modify_image_request(&self, request: Request) {
@@ -455,16 +535,24 @@ impl<'a> MMRCMSSource<'a> {
 	}
 
 	pub fn handle_url(&self, url: String) -> Result<DeepLink> {
-		// https://manga.fascans.com/manga/aharensan-wa-hakarenai/11/1
-		// ['https:', '', 'manga.fascans.com', 'manga', 'aharensan-wa-hakarenai', '11',
-		// '1']
-		let mut split = url.split('/');
-		if let Some(id) = split.nth(4).map(String::from) {
+		// {base_url}/{manga_path}/{manga_id}, or a chapter at
+		// {base_url}/{manga_path}/{manga_id}/{chapter_id}, maybe with a page
+		// number after it
+		if let Some(id) = self.manga_id(&url) {
+			let chapter = url
+				.substring_after(&format!("/{}/{}/", self.manga_path, id))
+				.and_then(|path| path.split(['/', '?', '#']).next())
+				.filter(|chapter_id| !chapter_id.is_empty())
+				.map(|chapter_id| Chapter {
+					id: String::from(chapter_id),
+					url: format!(
+						"{}/{}/{}/{}",
+						self.base_url, self.manga_path, id, chapter_id
+					),
+					lang: String::from(self.lang),
+					..Default::default()
+				});
 			let manga = Some(self.get_manga_details(id)?);
-			let chapter = split.next().map(String::from).map(|id| Chapter {
-				id,
-				..Default::default()
-			});
 			Ok(DeepLink { manga, chapter })
 		} else {
 			Err(AidokuError {
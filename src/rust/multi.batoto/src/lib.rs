@@ -8,7 +8,6 @@ use aidoku::{
 };
 
 mod crypto;
-mod evpkdf;
 mod helper;
 mod parser;
 mod substring;
@@ -271,7 +271,7 @@ pub fn get_page_list(obj: Node) -> Result<Vec<Page>> {
 			None => panic!(),
 		}
 		let img_arr = img_str.split("\",\"").collect::<Vec<&str>>();
-		let tkn_str = batojs_decrypt(String::from(server_token), String::from(bato_js));
+		let tkn_str = batojs_decrypt(String::from(server_token), String::from(bato_js))?;
 		let t = tkn_str.replace(['[', ']'], "");
 		let tkn_arr = t.split(',').collect::<Vec<&str>>();
 
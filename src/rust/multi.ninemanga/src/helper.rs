@@ -112,7 +112,14 @@ fn is_string_numeric(str: String) -> bool {
 	true
 }
 
+/// Reads the relative times of recent chapters with [`relative_date`]. The
+/// ones it can't read, like those without "ago", are dated now.
 pub fn get_date(node: Node, date_format: &str, locale: &str) -> f64 {
+	let date_str = node.select("span").text().read();
+	if let Some(date) = relative_date::parse(&date_str, current_date()) {
+		return date;
+	}
+
 	let time_en = ["min", "minute", "minutes", "hour", "hours"];
 	let time_es = ["minuto", "minutos", "hora", "horas"];
 	let time_ru = ["минута", "минуты", "час", "часы"];
@@ -120,7 +127,6 @@ pub fn get_date(node: Node, date_format: &str, locale: &str) -> f64 {
 	let time_it = ["minuto", "minuti", "ora", "ore"];
 	let time_pt_br = ["minuto", "atas", "hora", "horas"];
 	let time_fr = ["minute", "minutes", "heure", "heures"];
-	let date_str = node.select("span").text().read();
 	if time_en.iter().any(|value| date_str.contains(value))
 		|| time_es.iter().any(|value| date_str.contains(value))
 		|| time_ru.iter().any(|value| date_str.contains(value))
@@ -3,7 +3,7 @@ use aidoku::{
 	prelude::format,
 	std::defaults::defaults_get,
 	std::net::{HttpMethod, Request},
-	std::{String, Vec},
+	std::{ObjectRef, String, Vec},
 	Filter, FilterType,
 };
 
@@ -170,6 +170,50 @@ pub fn get_manga_url(manga_id: String, base_url: String) -> String {
 	}
 }
 
+/// Returns the URL of one page of the episode list of a manga.
+pub fn get_episode_list_url(manga_id: &str, cursor: Option<&str>) -> String {
+	// Example manga id: 3581
+	// return "https://m.webtoons.com/api/v1/webtoon/3581/episodes?pageSize=30"
+
+	// Canvas titles have their own endpoint
+	// Example manga id: 304446-canvas
+	// return "https://m.webtoons.com/api/v1/canvas/304446/episodes?pageSize=30"
+
+	let (kind, title_no) = match manga_id.strip_suffix("-canvas") {
+		Some(title_no) => ("canvas", title_no),
+		None => ("webtoon", manga_id),
+	};
+
+	let mut url = format!(
+		"https://m.webtoons.com/api/v1/{}/{}/episodes?pageSize=30",
+		kind, title_no
+	);
+	if let Some(cursor) = cursor {
+		url.push_str("&cursor=");
+		url.push_str(cursor);
+	}
+	url
+}
+
+/// Returns whether an episode from the episode list needs a pass to be read.
+pub fn is_episode_locked(episode: &ObjectRef) -> bool {
+	// Free episodes have a "SERVICE" status, paid ones are listed with
+	// another status until they become free
+	match episode.get("serviceStatus").as_string() {
+		Ok(status) => status.read() != "SERVICE",
+		Err(_) => false,
+	}
+}
+
+/// Returns the volume and the cleaned up title of an episode.
+///
+/// Only the season is read from the title, the episode number always comes
+/// from the episode list, as titles restart their numbering every season.
+pub fn parse_episode_title(raw_title: &str) -> (f32, String) {
+	let info = chapter_parser::parse(raw_title);
+	(info.volume, info.title)
+}
+
 /// Returns full URL of a chapter from a chapter ID and manga ID.
 pub fn get_chapter_url(chapter_id: String, manga_id: String, base_url: String) -> String {
 	// Example chapter id: 1
@@ -203,6 +247,53 @@ pub fn get_chapter_url(chapter_id: String, manga_id: String, base_url: String) -
 	}
 }
 
+/// Returns the value of a query parameter of a URL.
+pub fn get_query_param(url: &str, name: &str) -> Option<String> {
+	// Example Url: https://translate.webtoons.com/translate/episodeList?titleNo=3581&languageCode=SPA
+	// name: "languageCode"
+	// return "SPA"
+	let (_, query) = url.split_once('?')?;
+	query
+		.split(['&', '#'])
+		.filter_map(|param| param.split_once('='))
+		.find(|(key, _)| *key == name)
+		.map(|(_, value)| String::from(value))
+}
+
+/// The series picked in the filters
+pub enum Series {
+	Official,
+	Translations,
+	/// The fan translations of the title searched for
+	TitleTranslations,
+}
+
+/// Returns the series picked in the filters, and the title to look for in
+/// them if there is one
+pub fn check_for_translations(filters: &[Filter]) -> (Option<String>, Series) {
+	let mut title = None;
+	let mut series = Series::Official;
+
+	for filter in filters {
+		match filter.kind {
+			FilterType::Title => {
+				if let Ok(filter_value) = filter.value.as_string() {
+					title = Some(filter_value.read());
+				}
+			}
+			FilterType::Select if filter.name == "Series" => {
+				series = match filter.value.as_int().unwrap_or(0) {
+					1 => Series::Translations,
+					2 => Series::TitleTranslations,
+					_ => Series::Official,
+				};
+			}
+			_ => continue,
+		}
+	}
+	(title, series)
+}
+
 /// Returns the search status as a boolean and the search string if there is one
 pub fn check_for_search(filters: Vec<Filter>) -> (String, bool) {
 	let mut search_string = String::new();
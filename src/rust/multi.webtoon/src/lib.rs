@@ -7,34 +7,55 @@ use aidoku::{
 	Chapter, DeepLink, Filter, Listing, Manga, MangaPageResult, Page,
 };
 
-use helper::get_base_url;
+use helper::{check_for_translations, get_base_url, Series};
+use translate::Translation;
 
 mod helper;
 mod parser;
+mod translate;
 
 #[get_manga_list]
-fn get_manga_list(filters: Vec<Filter>, _page: i32) -> Result<MangaPageResult> {
+fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
+	let (title, series) = check_for_translations(&filters);
+	match series {
+		Series::Translations => return translate::parse_translation_list(title, page),
+		Series::TitleTranslations => {
+			return translate::parse_title_translations(title.unwrap_or_default(), None)
+		}
+		Series::Official => {}
+	}
 	parser::parse_manga_list(get_base_url(false), filters)
 }
 
 #[get_manga_listing]
 fn get_manga_listing(listing: Listing, page: i32) -> Result<MangaPageResult> {
+	if listing.name == "Fan Translations" {
+		return translate::parse_translation_list(None, page);
+	}
 	parser::parse_manga_listing(get_base_url(false), listing, page)
 }
 
 #[get_manga_details]
 fn get_manga_details(manga_id: String) -> Result<Manga> {
+	if manga_id.starts_with("translate-") {
+		return translate::parse_translation_details(manga_id);
+	}
 	parser::parse_manga_details(get_base_url(false), manga_id)
 }
 
 #[get_chapter_list]
 fn get_chapter_list(manga_id: String) -> Result<Vec<Chapter>> {
-	// Using the mobile site here as it returns all chapters in one request
-	parser::parse_chapter_list(get_base_url(true), manga_id)
+	if manga_id.starts_with("translate-") {
+		return translate::parse_translation_chapters(manga_id);
+	}
+	parser::parse_chapter_list(manga_id)
 }
 
 #[get_page_list]
 fn get_page_list(manga_id: String, chapter_id: String) -> Result<Vec<Page>> {
+	if manga_id.starts_with("translate-") {
+		return translate::parse_translation_pages(manga_id, chapter_id);
+	}
 	parser::parse_page_list(get_base_url(false), manga_id, chapter_id)
 }
 
@@ -45,5 +66,8 @@ fn modify_image_request(request: Request) {
 
 #[handle_url]
 pub fn handle_url(url: String) -> Result<DeepLink> {
+	if let Some(translation) = Translation::from_url(&url) {
+		return translate::handle_translation_url(translation, &url);
+	}
 	parser::handle_url(get_base_url(false), url)
 }
@@ -236,123 +236,88 @@ pub fn parse_manga_details(base_url: String, manga_id: String) -> Result<Manga>
 	})
 }
 
-pub fn parse_chapter_list(base_url: String, manga_id: String) -> Result<Vec<Chapter>> {
-	let url = get_manga_url(manga_id, base_url);
-
-	let html = request(&url, true).html().expect("Failed to get html");
-
+pub fn parse_chapter_list(manga_id: String) -> Result<Vec<Chapter>> {
 	let mut chapters: Vec<Chapter> = Vec::new();
 
-	for chapter in html.select("._episodeItem").array() {
-		let chapter_node = chapter.as_node().expect("Failed to get chapter node");
-
-		let mut volume = -1.0;
+	// Locked episodes are the ones behind Fast Pass / Daily Pass
+	let locked_episodes = defaults_get("lockedEpisodes")?.as_bool().unwrap_or(false);
+	let lang = get_lang_code().unwrap_or(String::from("en"));
 
-		let title = {
-			let raw_title = chapter_node.select(".sub_title > .ellipsis").text().read();
-			let mut title = raw_title.split_whitespace().collect::<Vec<&str>>();
+	// The episode list is paged with a cursor, keep requesting until the
+	// last page is reached
+	let mut cursor: Option<String> = None;
+	loop {
+		let url = get_episode_list_url(&manga_id, cursor.as_deref());
+		let json = request(&url, true).json()?.as_object()?;
+		let result = json.get("result").as_object()?;
 
-			// Remove leading volume text and set volume accordingly
-			// This is for titles like "(S1) Chapter 1 - PeePeePooPoo"
-			// or for titles like "(T1) Chapter 1 - PeePeePooPoo"
-			if !title.is_empty() {
-				let title_chars = title[0].chars().collect::<Vec<char>>();
-
-				if title_chars.len() >= 3
-					&& (title_chars[1] == 'S' || title_chars[1] == 'T')
-					&& String::from(title_chars[2]).parse::<f64>().is_ok()
-				{
-					volume = String::from(title_chars[2]).parse::<f32>().unwrap_or(-1.0);
-					title.remove(0);
-				}
+		for episode in result.get("episodeList").as_array()? {
+			let episode = episode.as_object()?;
 
-				// Remove leading episode text
-				// This is for titles like "Ep.1 - PeePeePooPoo"
-				if title_chars.len() >= 4
-					&& (title_chars[0] == 'E'
-						&& (title_chars[1] == 'p' || title_chars[1] == 'P')
-						&& title_chars[2] == '.')
-					&& title_chars[3..]
-						.iter()
-						.collect::<String>()
-						.parse::<f64>()
-						.is_ok()
-				{
-					title.remove(0);
-				}
+			let locked = is_episode_locked(&episode);
+			if locked && !locked_episodes {
+				continue;
 			}
 
-			// Remove leading season text and set volume accordingly
-			// This is for titles like "[Season 1] Chapter 1 - PeePeePooPoo"
-			if title.len() >= 2
-				&& (title[0] == "[Season")
-				&& title[1].replace(']', "").parse::<f64>().is_ok()
-			{
-				volume = title[1].replace(']', "").parse::<f32>().unwrap_or(-1.0);
-				title.remove(0);
-				title.remove(0);
+			// The episode number is the position of the episode in the series,
+			// it is never taken from the title, which only gives the season
+			let episode_no = episode.get("episodeNo").as_int().unwrap_or(-1);
+			if episode_no < 0 {
+				continue;
 			}
 
-			// Remove leading chapter/episode text
-			if title.len() >= 2
-				&& (title[0] == "Chapter"
-					|| title[0] == "Episode"
-					|| title[0] == "Ch." || title[0] == "CH."
-					|| title[0] == "Ep." || title[0] == "EP"
-					|| title[0] == "EP.")
-				&& title[1].replace(':', "").parse::<f64>().is_ok()
-			{
-				title.remove(0);
-				title.remove(0);
+			let raw_title = episode.get("episodeTitle").as_string()?.read();
+			let (volume, mut title) = parse_episode_title(&raw_title);
+			if locked {
+				title = format!("🔒 {}", title);
 			}
 
-			// Remove leading symbols
-			if !title.is_empty() && (title[0] == "-" || title[0] == ":") {
-				title.remove(0);
-			}
+			// Canvas chapters carry the same suffix as their title
+			let chapter_id = if manga_id.contains("-canvas") {
+				format!("{}-canvas", episode_no)
+			} else {
+				format!("{}", episode_no)
+			};
 
-			title.join(" ")
-		};
-
-		let chapter_number = {
-			let raw_chapter_number = chapter_node.attr("data-episode-no").read();
-			raw_chapter_number
-				.parse::<f32>()
-				.expect("Failed to parse chapter number")
-		};
-
-		let chapter_id = get_chapter_id(chapter_node.select("a").attr("href").read());
-		let chapter_url = chapter_node.select("a").attr("href").read();
-
-		let lang = get_lang_code().unwrap_or(String::from("en"));
-
-		let date_updated = {
-			let date = chapter_node.select(".sub_info > .date");
-
-			match lang.as_str() {
-				"en" => date.text().as_date("MMM dd, yyyy", Some("en-US"), None),
-				"zh-hant" => date.text().as_date("yyyy/mm/dd", Some("zh-Hant"), None),
-				"th" => date.text().as_date("dd MMM YYYY", Some("th-TH"), None),
-				"id" => date.text().as_date("yyyy MMM dd", Some("id-ID"), None),
-				"es" => date.text().as_date("dd-MMM-yyyy", Some("es-ES"), None),
-				"fr" => date.text().as_date("dd MMM yyyy", Some("fr-FR"), None),
-				"de" => date.text().as_date("dd.mm.yyyy", Some("de-DE"), None),
-				_ => date.text().as_date("MMM dd, yyyy", None, None),
-			}
-		};
+			let chapter_url = match episode.get("viewerLink").as_string() {
+				Ok(link) => format!("https://www.webtoons.com{}", link.read()),
+				Err(_) => {
+					get_chapter_url(chapter_id.clone(), manga_id.clone(), get_base_url(false))
+				}
+			};
+
+			let date_updated = episode
+				.get("exposureDateMillis")
+				.as_int()
+				.map(|millis| millis as f64 / 1000.0)
+				.unwrap_or(-1.0);
+
+			chapters.push(Chapter {
+				id: chapter_id,
+				title,
+				volume,
+				chapter: episode_no as f32,
+				date_updated,
+				url: chapter_url,
+				lang: lang.clone(),
+				..Default::default()
+			});
+		}
 
-		chapters.push(Chapter {
-			id: chapter_id,
-			title,
-			volume,
-			chapter: chapter_number,
-			date_updated,
-			url: chapter_url,
-			lang,
-			..Default::default()
-		});
+		let next_cursor = result.get("nextCursor");
+		cursor = match next_cursor.as_int() {
+			Ok(cursor) => Some(format!("{}", cursor)),
+			Err(_) => next_cursor.as_string().map(|cursor| cursor.read()).ok(),
+		}
+		.filter(|cursor| !cursor.is_empty());
+		if cursor.is_none() {
+			break;
+		}
 	}
 
+	// The api lists the oldest episode first
+	chapters.sort_by(|a, b| b.chapter.total_cmp(&a.chapter));
+
 	Ok(chapters)
 }
 
@@ -0,0 +1,353 @@
+use aidoku::{
+	error::Result,
+	prelude::format,
+	std::defaults::defaults_get,
+	std::{String, Vec},
+	Chapter, DeepLink, Manga, MangaPageResult, MangaViewer, Page,
+};
+
+use crate::helper::*;
+use crate::parser::parse_manga_details;
+
+const TRANSLATE_URL: &str = "https://translate.webtoons.com";
+const TRANSLATE_API_URL: &str = "https://global.apis.naver.com/lineWebtoon/ctrans";
+const TRANSLATE_IMAGE_URL: &str = "https://mwebtoon-phinf.pstatic.net";
+const PAGE_SIZE: i32 = 30;
+
+/// A fan translation of a title, by one team in one language.
+pub struct Translation {
+	pub title_no: String,
+	pub language: String,
+	pub team_version: String,
+}
+
+impl Translation {
+	/// Parses a manga id of a fan translation.
+	pub fn from_id(manga_id: &str) -> Option<Translation> {
+		// Example manga id: translate-3581-SPA-1
+		let mut split_id = manga_id.strip_prefix("translate-")?.split('-');
+		Some(Translation {
+			title_no: String::from(split_id.next()?),
+			language: String::from(split_id.next()?),
+			team_version: String::from(split_id.next()?),
+		})
+	}
+
+	/// Parses the url of a fan translation.
+	pub fn from_url(url: &str) -> Option<Translation> {
+		// Example Url: https://translate.webtoons.com/translate/episodeList?titleNo=3581&languageCode=SPA&teamVersion=1
+		// Example Url: https://translate.webtoons.com/translate/episodeView?titleNo=3581&episodeNo=1&languageCode=SPA&teamVersion=1
+		if !url.contains("translate.webtoons.com") {
+			return None;
+		}
+		Some(Translation {
+			title_no: get_query_param(url, "titleNo")?,
+			language: get_query_param(url, "languageCode")?,
+			team_version: get_query_param(url, "teamVersion").unwrap_or(String::from("0")),
+		})
+	}
+
+	pub fn id(&self) -> String {
+		format!(
+			"translate-{}-{}-{}",
+			self.title_no, self.language, self.team_version
+		)
+	}
+
+	pub fn url(&self) -> String {
+		format!(
+			"{}/translate/episodeList?titleNo={}&languageCode={}&teamVersion={}",
+			TRANSLATE_URL, self.title_no, self.language, self.team_version
+		)
+	}
+
+	fn api_params(&self) -> String {
+		format!(
+			"titleNo={}&languageCode={}&teamVersion={}",
+			self.title_no, self.language, self.team_version
+		)
+	}
+}
+
+/// Returns the language picked for fan translations
+fn get_translation_lang() -> String {
+	defaults_get("translationLanguage")
+		.and_then(|value| value.as_string())
+		.map(|value| value.read())
+		.unwrap_or(String::from("ENG"))
+}
+
+/// Lists the fan translations of the picked language. The API can't search
+/// by name, so a query is the link or number of a title, whose translations
+/// in that language are listed instead.
+pub fn parse_translation_list(query: Option<String>, page: i32) -> Result<MangaPageResult> {
+	if let Some(query) = query.filter(|query| !query.trim().is_empty()) {
+		return parse_title_translations(query, Some(get_translation_lang()));
+	}
+
+	let offset = (page - 1) * PAGE_SIZE;
+	let (manga, total) = get_translations(offset, PAGE_SIZE)?;
+	let has_more = !manga.is_empty() && offset + (manga.len() as i32) < total;
+	Ok(MangaPageResult { manga, has_more })
+}
+
+/// A page of the fan translations of the picked language, and how many
+/// there are in total.
+fn get_translations(offset: i32, size: i32) -> Result<(Vec<Manga>, i32)> {
+	let url = format!(
+		"{}/translatedWebtoons_jsonp.json?orderType=UPDATE&offset={}&size={}&languageCode={}",
+		TRANSLATE_API_URL,
+		offset,
+		size,
+		get_translation_lang()
+	);
+	let json = request(&url, false).json()?.as_object()?;
+	let result = json.get("result").as_object()?;
+
+	let mut mangas: Vec<Manga> = Vec::new();
+
+	for title in result.get("titleList").as_array()? {
+		let title = title.as_object()?;
+
+		let translation = Translation {
+			title_no: format!("{}", title.get("titleNo").as_int()?),
+			language: title.get("languageCode").as_string()?.read(),
+			team_version: format!("{}", title.get("teamVersion").as_int().unwrap_or(0)),
+		};
+		let cover = format!(
+			"{}{}",
+			TRANSLATE_IMAGE_URL,
+			title.get("thumbIPadUrl").as_string()?.read()
+		);
+
+		mangas.push(Manga {
+			id: translation.id(),
+			cover,
+			title: title.get("representTitle").as_string()?.read(),
+			url: translation.url(),
+			viewer: MangaViewer::Scroll,
+			..Default::default()
+		});
+	}
+
+	let total = result.get("totalCount").as_int().unwrap_or(0) as i32;
+	Ok((mangas, total))
+}
+
+/// Lists the fan translations of one official title, in `language` or in
+/// any language. `title` is the title's link or number; a translation's link
+/// also narrows the list down to its language and team.
+pub fn parse_title_translations(
+	title: String,
+	language: Option<String>,
+) -> Result<MangaPageResult> {
+	let title = title.trim();
+	let title_no = get_query_param(title, "titleNo").unwrap_or(String::from(title));
+	let language = get_query_param(title, "languageCode").or(language);
+	let team_version = get_query_param(title, "teamVersion");
+	if title_no.is_empty() || !title_no.chars().all(|c| c.is_ascii_digit()) {
+		return Ok(MangaPageResult {
+			manga: Vec::new(),
+			has_more: false,
+		});
+	}
+	let original = parse_manga_details(get_base_url(false), title_no.clone())?;
+
+	// The versions page links to the episode list of every translation
+	let url = format!(
+		"{}/webtoonVersion?webtoonNo={}&webtoonType=WEBTOON",
+		TRANSLATE_URL, title_no
+	);
+	let html = request(&url, false).html()?;
+
+	let mut mangas: Vec<Manga> = Vec::new();
+	for link in html.select("a[href*=episodeList]").array() {
+		let link = link.as_node()?;
+		let Some(translation) = Translation::from_url(&link.attr("abs:href").read()) else {
+			continue;
+		};
+		if translation.title_no != title_no
+			|| language
+				.as_ref()
+				.is_some_and(|language| *language != translation.language)
+			|| team_version
+				.as_ref()
+				.is_some_and(|team_version| *team_version != translation.team_version)
+			|| mangas.iter().any(|manga| manga.id == translation.id())
+		{
+			continue;
+		}
+		let team = link.text().read();
+		let team = if team.trim().is_empty() {
+			format!("Team {}", translation.team_version)
+		} else {
+			String::from(team.trim())
+		};
+		mangas.push(Manga {
+			id: translation.id(),
+			cover: original.cover.clone(),
+			title: format!("{} ({}, {})", original.title, translation.language, team),
+			url: translation.url(),
+			viewer: MangaViewer::Scroll,
+			..Default::default()
+		});
+	}
+
+	Ok(MangaPageResult {
+		manga: mangas,
+		has_more: false,
+	})
+}
+
+pub fn parse_translation_details(manga_id: String) -> Result<Manga> {
+	let translation = Translation::from_id(&manga_id).unwrap_or(Translation {
+		title_no: manga_id.clone(),
+		language: get_translation_lang(),
+		team_version: String::from("0"),
+	});
+
+	// Translations share the details of the original title
+	let manga = parse_manga_details(get_base_url(false), translation.title_no.clone())?;
+
+	Ok(Manga {
+		id: manga_id,
+		url: translation.url(),
+		..manga
+	})
+}
+
+pub fn parse_translation_chapters(manga_id: String) -> Result<Vec<Chapter>> {
+	let Some(translation) = Translation::from_id(&manga_id) else {
+		return Ok(Vec::new());
+	};
+
+	let url = format!(
+		"{}/translatedEpisodes_jsonp.json?{}&offset=0&limit=10000",
+		TRANSLATE_API_URL,
+		translation.api_params()
+	);
+	let json = request(&url, false).json()?.as_object()?;
+	let result = json.get("result").as_object()?;
+
+	let mut chapters: Vec<Chapter> = Vec::new();
+
+	for episode in result.get("episodes").as_array()? {
+		let episode = episode.as_object()?;
+
+		// Episodes are listed while their translation is still in progress
+		if !episode.get("translateCompleted").as_bool().unwrap_or(false) {
+			continue;
+		}
+
+		let episode_no = episode.get("episodeNo").as_int()?;
+		let (volume, title) = parse_episode_title(&episode.get("title").as_string()?.read());
+
+		let scanlator = episode
+			.get("translateTeamName")
+			.as_string()
+			.map(|name| name.read())
+			.unwrap_or_else(|_| format!("Team {}", translation.team_version));
+
+		let date_updated = episode
+			.get("updateYmdt")
+			.as_int()
+			.map(|millis| millis as f64 / 1000.0)
+			.unwrap_or(-1.0);
+
+		chapters.push(Chapter {
+			id: format!("{}", episode_no),
+			title,
+			volume,
+			chapter: episode_no as f32,
+			date_updated,
+			scanlator,
+			url: format!(
+				"{}/translate/episodeView?{}&episodeNo={}",
+				TRANSLATE_URL,
+				translation.api_params(),
+				episode_no
+			),
+			lang: language_code(&translation.language),
+			..Default::default()
+		});
+	}
+
+	chapters.sort_by(|a, b| b.chapter.total_cmp(&a.chapter));
+
+	Ok(chapters)
+}
+
+pub fn parse_translation_pages(manga_id: String, chapter_id: String) -> Result<Vec<Page>> {
+	let Some(translation) = Translation::from_id(&manga_id) else {
+		return Ok(Vec::new());
+	};
+
+	let url = format!(
+		"{}/translatedEpisodeDetail_jsonp.json?{}&episodeNo={}",
+		TRANSLATE_API_URL,
+		translation.api_params(),
+		chapter_id
+	);
+	let json = request(&url, false).json()?.as_object()?;
+	let result = json.get("result").as_object()?;
+
+	let mut pages: Vec<Page> = Vec::new();
+
+	for (index, image) in result.get("imageInfo").as_array()?.enumerate() {
+		let image = image.as_object()?;
+		pages.push(Page {
+			index: index as i32,
+			url: image.get("imageUrl").as_string()?.read(),
+			..Default::default()
+		});
+	}
+
+	Ok(pages)
+}
+
+pub fn handle_translation_url(translation: Translation, url: &str) -> Result<DeepLink> {
+	// Episode links also have the episode
+	let chapter = get_query_param(url, "episodeNo").map(|episode_no| Chapter {
+		chapter: episode_no.parse::<f32>().unwrap_or(-1.0),
+		url: format!(
+			"{}/translate/episodeView?{}&episodeNo={}",
+			TRANSLATE_URL,
+			translation.api_params(),
+			episode_no
+		),
+		lang: language_code(&translation.language),
+		id: episode_no,
+		..Default::default()
+	});
+	Ok(DeepLink {
+		manga: parse_translation_details(translation.id()).ok(),
+		chapter,
+	})
+}
+
+/// Maps the language codes of the translation site to the ones Aidoku uses
+fn language_code(language: &str) -> String {
+	String::from(match language {
+		"ENG" => "en",
+		"SPA" => "es",
+		"FRA" => "fr",
+		"DEU" => "de",
+		"ITA" => "it",
+		"POR" => "pt-br",
+		"RUS" => "ru",
+		"VIE" => "vi",
+		"THA" => "th",
+		"IND" => "id",
+		"JPN" => "ja",
+		"CMN" => "zh",
+		"CMT" => "zh-hant",
+		"KOR" => "ko",
+		"ARA" => "ar",
+		"TUR" => "tr",
+		"POL" => "pl",
+		"FIL" => "fil",
+		"MAY" => "ms",
+		"HIN" => "hi",
+		_ => "en",
+	})
+}
@@ -5,11 +5,11 @@ use aidoku::{
 	prelude::format,
 	std::{
 		net::{HttpMethod, Request},
-		String, Vec,
+		String, StringRef, Vec,
 	},
 	Chapter, Manga, MangaContentRating, MangaStatus, MangaViewer, Page,
 };
-use alloc::string::ToString;
+use alloc::{string::ToString, vec};
 
 pub fn comic_info() -> Manga {
 	Manga {
@@ -27,43 +27,100 @@ pub fn comic_info() -> Manga {
 	}
 }
 
+/// The archive has every comic's title and date, the API the number of the
+/// latest comic, which the archive can be a little behind on. Comics missing
+/// from the archive still get listed, the latest one with its title.
 pub fn get_chapter_list() -> Result<Vec<Chapter>> {
+	let archive = get_archive();
+	let latest = get_latest();
+	let mut chapters = match (archive, &latest) {
+		(Ok(chapters), _) => chapters,
+		(Err(_), Ok(_)) => Vec::new(),
+		(Err(error), Err(_)) => return Err(error),
+	};
+
+	if let Ok(latest) = latest {
+		let newest_archived = chapters.first().map_or(0, |chapter| chapter.chapter as i64);
+		let latest_num = latest.chapter as i64;
+		if newest_archived < latest_num {
+			let mut newer = vec![latest];
+			newer.extend(
+				(newest_archived + 1..latest_num)
+					.rev()
+					.filter(|num| *num != 404)
+					.map(|num| chapter(num, String::new(), -1.0)),
+			);
+			newer.append(&mut chapters);
+			chapters = newer;
+		}
+	}
+	Ok(chapters)
+}
+
+fn get_archive() -> Result<Vec<Chapter>> {
 	let html = Request::new("https://xkcd.com/archive", HttpMethod::Get).html()?;
 	Ok(html
 		.select("#middleContainer > a")
 		.array()
-		.filter_map(|elem| {
-			elem.as_node()
-				.map(|node| {
-					let url = node.attr("abs:href").read();
-					let date_updated = node
-						.attr("title")
-						.0
-						.as_date("yyyy-M-d", None, None)
-						.unwrap_or(-1.0);
-					let chapter = extract_f32_from_string(node.attr("href").read())[0];
-					Chapter {
-						id: chapter.to_string(),
-						title: node.text().read(),
-						volume: -1.0,
-						chapter,
-						date_updated,
-						scanlator: String::new(),
-						url,
-						lang: String::from("en"),
-					}
-				})
-				.ok()
+		.filter_map(|elem| elem.as_node().ok())
+		.filter_map(|node| {
+			let num = extract_f32_from_string(node.attr("href").read())
+				.first()
+				.copied()?;
+			let date_updated = node
+				.attr("title")
+				.0
+				.as_date("yyyy-M-d", None, None)
+				.unwrap_or(-1.0);
+			Some(chapter(num as i64, node.text().read(), date_updated))
 		})
 		.collect::<Vec<_>>())
 }
 
+fn get_latest() -> Result<Chapter> {
+	let json = Request::new("https://xkcd.com/info.0.json", HttpMethod::Get)
+		.json()?
+		.as_object()?;
+	let field = |key: &str| {
+		json.get(key)
+			.as_string()
+			.map(|v| v.read())
+			.unwrap_or_default()
+	};
+	let date_updated = StringRef::from(format!(
+		"{}-{}-{}",
+		field("year"),
+		field("month"),
+		field("day")
+	))
+	.0
+	.as_date("yyyy-M-d", None, None)
+	.unwrap_or(-1.0);
+	Ok(chapter(
+		json.get("num").as_int()?,
+		field("safe_title"),
+		date_updated,
+	))
+}
+
+fn chapter(num: i64, title: String, date_updated: f64) -> Chapter {
+	Chapter {
+		id: num.to_string(),
+		title,
+		volume: -1.0,
+		chapter: num as f32,
+		date_updated,
+		scanlator: String::new(),
+		url: format!("https://xkcd.com/{num}/"),
+		lang: String::from("en"),
+	}
+}
+
 pub fn get_page_list(id: String) -> Result<Vec<Page>> {
 	super::get_page_list(
         format!("https://xkcd.com/{id}"),
         String::from("#comic img"),
         false,
         format!("To experience the interactive version of this comic,\nopen it in a browser: https://xkcd.com/{id}/"),
-        super::ImageVariant::Latin,
     )
 }
@@ -74,6 +74,5 @@ pub fn get_page_list(id: String) -> Result<Vec<Page>> {
         // DeepL translated, sorry.
         false,
         format!("Para experimentar la versión interactiva de este cómic,\nábralo en un navegador: https://es.xkcd.com/strips/{id}/"),
-        super::ImageVariant::Latin,
     )
 }
@@ -1,4 +1,4 @@
-use super::{word_wrap, ToImageUrl, THUMBNAIL_URL};
+use super::{comic_pages, image_url, THUMBNAIL_URL};
 use crate::helper::extract_f32_from_string;
 use aidoku::{
 	error::Result,
@@ -10,7 +10,6 @@ use aidoku::{
 	},
 	Chapter, Manga, MangaContentRating, MangaStatus, MangaViewer, Page,
 };
-use alloc::vec;
 
 pub fn comic_info() -> Manga {
 	Manga {
@@ -79,19 +78,7 @@ pub fn get_page_list(id: String) -> Result<Vec<Page>> {
 		.map(|v| v.text().read())
 		.unwrap_or_default();
 
-	let image_node = html.select("#col1 img[title]");
-	let image_url = image_node.attr("abs:src").read();
+	let image_node = html.select("#col1 img[title]").first();
 	let alt = image_node.attr("alt").read();
-	Ok(vec![
-		Page {
-			index: 0,
-			url: image_url,
-			..Default::default()
-		},
-		Page {
-			index: 1,
-			url: word_wrap(title, alt).to_image_url(super::ImageVariant::Latin),
-			..Default::default()
-		},
-	])
+	Ok(comic_pages(image_url(&html, &image_node), title, alt))
 }
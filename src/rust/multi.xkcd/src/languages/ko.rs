@@ -62,6 +62,5 @@ pub fn get_page_list(id: String) -> Result<Vec<Page>> {
 		format!(
 			"이 만화의 대화형 버전을 경험하려면\n브라우저에서 엽니다. https://xkcdko.com/{id}/"
 		),
-		super::ImageVariant::Cjk,
 	)
 }
@@ -1,14 +1,15 @@
 use aidoku::{
 	error::Result,
-	helpers::uri::encode_uri_component,
 	prelude::format,
 	std::{
+		defaults::defaults_get,
+		html::Node,
 		net::{HttpMethod, Request},
 		String, Vec,
 	},
 	Page,
 };
-use alloc::{borrow::ToOwned, vec};
+use alloc::vec;
 
 pub mod en;
 pub mod es;
@@ -19,54 +20,102 @@ pub mod zh;
 
 static THUMBNAIL_URL: &str = "https://fakeimg.pl/550x780/ffffff/6e7b91/?font=museo&text=xkcd";
 
+/// Where the page with the title text of a comic goes.
 #[derive(PartialEq, Eq)]
-enum ImageVariant {
-	Latin,
-	Cjk,
+enum AltTextPosition {
+	Append,
+	Prepend,
+	Hidden,
 }
 
-trait ToImageUrl {
-	fn to_image_url(&self, variant: ImageVariant) -> String;
+fn alt_text_position() -> AltTextPosition {
+	match defaults_get("altText")
+		.and_then(|v| v.as_string())
+		.map(|v| v.read())
+		.unwrap_or_default()
+		.as_str()
+	{
+		"prepend" => AltTextPosition::Prepend,
+		"hidden" => AltTextPosition::Hidden,
+		_ => AltTextPosition::Append,
+	}
 }
 
-impl<S> ToImageUrl for S
-where
-	S: AsRef<str>,
-{
-	fn to_image_url(&self, variant: ImageVariant) -> String {
-		let text = encode_uri_component(self.as_ref());
-		match variant {
-            ImageVariant::Latin => format!("https://fakeimg.pl/1500x2126/ffffff/000000/?font=noto&font_size=42&text={text}"),
-            ImageVariant::Cjk => format!("https://placehold.jp/42/ffffff/000000/1500x2126.png?css=%7B%22padding%22%3A%22%200%20300px%22%2C%22text-align%22%3A%22left%22%7D&text={text}"),
-        }
+/// Returns the largest version of a comic image: the full size image the
+/// link around a large comic points to, the highest density of its `srcset`,
+/// or its `src`.
+fn image_url(html: &Node, node: &Node) -> String {
+	// there's no way to go up from a node, so find the link whose image is
+	// the comic rather than any linked image on the page
+	let src = node.attr("src").read();
+	let large_image = html
+		.select("a:has(> img)")
+		.array()
+		.filter_map(|link| link.as_node().ok())
+		.find(|link| !src.is_empty() && link.select("> img").attr("src").read() == src)
+		.map(|link| link.attr("abs:href").read())
+		.filter(|link| {
+			[".png", ".jpg", ".jpeg", ".gif"]
+				.iter()
+				.any(|ext| link.to_lowercase().ends_with(ext))
+		});
+	if let Some(large_image) = large_image {
+		return large_image;
+	}
+
+	// //imgs.xkcd.com/comics/barrel_cropped_(1).jpg 2x
+	let srcset = node.attr("srcset").read();
+	let best = srcset
+		.split(',')
+		.filter_map(|candidate| {
+			let mut parts = candidate.split_whitespace();
+			let url = parts.next()?;
+			let density = parts
+				.next()
+				.and_then(|v| v.trim_end_matches('x').parse::<f32>().ok())
+				.unwrap_or(1.0);
+			Some((url, density))
+		})
+		.fold(None, |best: Option<(&str, f32)>, candidate| match best {
+			Some(best) if best.1 >= candidate.1 => Some(best),
+			_ => Some(candidate),
+		});
+	match best {
+		Some((url, _)) if url.starts_with("//") => format!("https:{url}"),
+		Some((url, _)) if url.starts_with("http") => String::from(url),
+		_ => node.attr("abs:src").read(),
 	}
 }
 
-fn word_wrap<T: AsRef<str>>(title: T, alt: T) -> String {
-	let title = title.as_ref();
-	let alt = alt.as_ref();
-	let mut ret = String::new();
-	title.split(' ').enumerate().for_each(|(idx, val)| {
-		if idx != 0 && idx % 7 == 0 {
-			ret.push('\n');
-		}
-		ret.push_str(val);
-		ret.push(' ');
-	});
-	ret.push_str("\n\n");
+fn text_page(text: String) -> Page {
+	Page {
+		text,
+		..Default::default()
+	}
+}
 
-	let mut char_count = 0;
-	alt.replace("\r\n", " ").split(' ').for_each(|val| {
-		if char_count > 25 {
-			ret.push('\n');
-			char_count = 0;
-		}
-		ret.push_str(val);
-		ret.push(' ');
-		char_count += val.len() + 1;
-	});
+/// Puts a comic and the page with its title text together, in the order
+/// picked in the settings.
+fn comic_pages(image_url: String, title: String, alt: String) -> Vec<Page> {
+	let image = Page {
+		url: image_url,
+		..Default::default()
+	};
+	let alt_text = if title.is_empty() {
+		alt
+	} else {
+		format!("{title}\n\n{alt}")
+	};
 
-	ret
+	let mut pages = match alt_text_position() {
+		AltTextPosition::Append => vec![image, text_page(alt_text)],
+		AltTextPosition::Prepend => vec![text_page(alt_text), image],
+		AltTextPosition::Hidden => vec![image],
+	};
+	for (index, page) in pages.iter_mut().enumerate() {
+		page.index = index as i32;
+	}
+	pages
 }
 
 fn get_page_list<T: AsRef<str>>(
@@ -74,37 +123,21 @@ fn get_page_list<T: AsRef<str>>(
 	selector: T,
 	interactive_if_empty: bool,
 	open_in_browser_message: T,
-	variant: ImageVariant,
 ) -> Result<Vec<Page>> {
 	let html = Request::new(url, HttpMethod::Get).html()?;
 	let node = html.select(selector);
 	if (!interactive_if_empty && node.first().next().is_some())
 		|| (interactive_if_empty && node.array().is_empty())
 	{
-		Ok(vec![Page {
-			index: 0,
-			url: open_in_browser_message.to_image_url(variant),
-			..Default::default()
-		}])
+		Ok(vec![text_page(String::from(
+			open_in_browser_message.as_ref(),
+		))])
 	} else {
-		let url = if node.has_attr("srcset") {
-			let raw = node.attr("abs:srcset").read();
-			raw.split(' ').next().map(|v| v.to_owned()).unwrap_or(raw)
-		} else {
-			node.attr("abs:src").read()
-		};
-		let alt = word_wrap(node.attr("alt").read(), node.attr("title").read());
-		Ok(vec![
-			Page {
-				index: 0,
-				url,
-				..Default::default()
-			},
-			Page {
-				index: 1,
-				url: alt.to_image_url(variant),
-				..Default::default()
-			},
-		])
+		let node = node.first();
+		Ok(comic_pages(
+			image_url(&html, &node),
+			node.attr("alt").read(),
+			node.attr("title").read(),
+		))
 	}
 }
@@ -1,4 +1,4 @@
-use super::{word_wrap, ToImageUrl, THUMBNAIL_URL};
+use super::{comic_pages, image_url, THUMBNAIL_URL};
 use crate::helper::extract_f32_from_string;
 use aidoku::{
 	error::Result,
@@ -9,7 +9,7 @@ use aidoku::{
 	},
 	Chapter, Manga, MangaContentRating, MangaStatus, MangaViewer, Page,
 };
-use alloc::{string::ToString, vec};
+use alloc::string::ToString;
 
 pub fn comic_info() -> Manga {
 	Manga {
@@ -56,19 +56,8 @@ pub fn get_chapter_list() -> Result<Vec<Chapter>> {
 
 pub fn get_page_list(id: String) -> Result<Vec<Page>> {
 	let html = Request::new(format!("https://xkcd.ru/{id}/"), HttpMethod::Get).html()?;
-	let image_url = html.select(".main img[alt]").attr("abs:src").read();
-	let title = html.select(".main img[alt]").attr("alt").read();
+	let image_node = html.select(".main img[alt]").first();
+	let title = image_node.attr("alt").read();
 	let alt = html.select(".main .comics_text").text().read();
-	Ok(vec![
-		Page {
-			index: 0,
-			url: image_url,
-			..Default::default()
-		},
-		Page {
-			index: 1,
-			url: word_wrap(title, alt).to_image_url(super::ImageVariant::Latin),
-			..Default::default()
-		},
-	])
+	Ok(comic_pages(image_url(&html, &image_node), title, alt))
 }
@@ -61,6 +61,5 @@ pub fn get_page_list(id: String) -> Result<Vec<Page>> {
 		// Google translated, sorry
 		true,
 		format!("要體驗本漫畫的互動版\n請在瀏覽器中打開: https://xkcd.tw/{id}/"),
-		super::ImageVariant::Cjk,
 	)
 }
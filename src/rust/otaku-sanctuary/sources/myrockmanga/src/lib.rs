@@ -10,6 +10,7 @@ use otaku_sanctuary_template::template::OtakuSanctuarySource;
 
 static INSTANCE: OtakuSanctuarySource = OtakuSanctuarySource {
 	base_url: "https://myrockmanga.com",
+	hosts: &["myrockmanga.com", "www.myrockmanga.com"],
 };
 
 #[get_manga_list]
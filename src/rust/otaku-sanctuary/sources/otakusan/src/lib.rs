@@ -10,6 +10,7 @@ use otaku_sanctuary_template::template::OtakuSanctuarySource;
 
 static INSTANCE: OtakuSanctuarySource = OtakuSanctuarySource {
 	base_url: "https://otakusan.net",
+	hosts: &["otakusan.net", "www.otakusan.net"],
 };
 
 #[get_manga_list]
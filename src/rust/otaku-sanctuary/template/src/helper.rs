@@ -104,21 +104,12 @@ pub fn capitalize_first_letter(name: String) -> String {
 }
 
 pub fn convert_time(ago: String) -> f64 {
-	if ago.contains("cách đây") {
-		let multiplier = match ago.split(char::is_whitespace).collect::<Vec<_>>()[3] {
-			"giây" => 1.0,
-			"phút" => 60.0,
-			"tiếng" => 3600.0,
-			"ngày" => 86400.0,
-			_ => return -1.0,
-		};
-		current_date() - (extract_f32_from_string(String::new(), ago) * multiplier) as f64
-	} else {
+	relative_date::parse(&ago, current_date()).unwrap_or_else(|| {
 		StringRef::from(ago)
 			.0
 			.as_date("dd/MM/yy", Some("en_US"), Some("Asia/Ho_Chi_Minh"))
 			.unwrap_or(-1.0)
-	}
+	})
 }
 
 pub fn url_replacer(url: String, vi: String) -> String {
@@ -2,6 +2,7 @@ use aidoku::{
 	error::Result,
 	prelude::*,
 	std::{
+		defaults::defaults_get,
 		html::Node,
 		json,
 		net::{HttpMethod, Request},
@@ -28,17 +29,37 @@ fn cache_manga_page(url: &str) {
 }
 
 pub struct OtakuSanctuarySource {
+	/// The site's URL, unless the user has set `sourceURL`.
 	pub base_url: &'static str,
+	/// Every host the site has been on, so that ids saved as whole URLs
+	/// before it moved still work.
+	pub hosts: &'static [&'static str],
 }
 
 impl OtakuSanctuarySource {
+	fn get_base_url(&self) -> String {
+		let setting = defaults_get("sourceURL")
+			.and_then(|value| value.as_string())
+			.map(|value| value.read())
+			.ok();
+		site_url::base_url(setting.as_deref(), self.base_url)
+	}
+
+	fn get_id(&self, url: &str) -> String {
+		site_url::get_id(url, &self.get_base_url(), self.hosts)
+	}
+
+	fn get_url(&self, id: &str) -> String {
+		site_url::get_url(id, &self.get_base_url(), self.hosts)
+	}
+
 	fn parse_manga_list(&self, elems: ArrayRef) -> (Vec<Manga>, bool) {
 		let mut manga: Vec<Manga> = Vec::with_capacity(elems.len());
 		let has_more = elems.len() > 0;
 		for elem in elems {
 			let node = elem.as_node().expect("node array");
-			let id = node.select("div.mdl-card__title a").attr("href").read();
-			if (id.contains("http://") || id.contains("https://")) && !id.contains(self.base_url) {
+			let id = self.get_id(&node.select("div.mdl-card__title a").attr("href").read());
+			if id.starts_with("http://") || id.starts_with("https://") {
 				continue;
 			}
 			let cover = node
@@ -67,7 +88,7 @@ impl OtakuSanctuarySource {
 				author: String::new(),
 				artist: String::new(),
 				description: String::new(),
-				url: format!("{}{id}", &self.base_url),
+				url: self.get_url(&id),
 				categories: Vec::new(),
 				status: MangaStatus::Unknown,
 				nsfw: MangaContentRating::Safe,
@@ -82,8 +103,8 @@ impl OtakuSanctuarySource {
 		let mut manga: Vec<Manga> = Vec::with_capacity(elems.len());
 		for elem in elems {
 			let node = elem.as_node().expect("node array");
-			let id = node.select("a").attr("href").read();
-			let url = format!("{}{id}", &self.base_url);
+			let id = self.get_id(&node.select("a").attr("href").read());
+			let url = self.get_url(&id);
 			let cover = node
 				.select("img")
 				.attr("data-src")
@@ -108,6 +129,7 @@ impl OtakuSanctuarySource {
 	}
 
 	pub fn get_manga_list(&self, filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
+		let base_url = self.get_base_url();
 		let mut title = String::new();
 		let mut tags: Vec<String> = Vec::with_capacity(49); // Number of filters available
 		let mut search_request = false;
@@ -126,7 +148,7 @@ impl OtakuSanctuarySource {
 		}
 		tags.resize(tags.len(), String::new());
 		let resp = if !title.is_empty() {
-			let url = format!("{}/Home/Search?search={title}", self.base_url);
+			let url = format!("{base_url}/Home/Search?search={title}");
 			search_request = true;
 			Request::new(&url, HttpMethod::Get).html()?
 		} else {
@@ -138,7 +160,7 @@ impl OtakuSanctuarySource {
 				request.push_str(format!("&FilterCategory[{idx}]={tag}").as_str());
 			}
 			Request::new(
-				format!("{}/Manga/Newest", self.base_url).as_str(),
+				format!("{base_url}/Manga/Newest").as_str(),
 				HttpMethod::Post,
 			)
 			.body(request.as_bytes())
@@ -167,14 +189,15 @@ impl OtakuSanctuarySource {
 	}
 
 	pub fn get_manga_listing(&self, listing: Listing, page: i32) -> Result<MangaPageResult> {
+		let base_url = self.get_base_url();
 		let url = match listing.name.as_str() {
-			"Completed" => format!("{}/Manga/CompletedNewest", self.base_url),
-			"New Titles" => format!("{}/Manga/NewTitleNewest", self.base_url),
-			"For Boys" => format!("{}/Manga/ForBoyNewest", self.base_url),
-			"For Girls" => format!("{}/Manga/ForGirlNewest", self.base_url),
-			"Ecchi Land" => format!("{}/Manga/EcchiNewest", self.base_url),
-			"Wallpaper" => format!("{}/WallPaper/Newest?type=Newest&offset=", self.base_url),
-			"Cosplay" => format!("{}/Cosplay/Newest?type=Newest&offset=", self.base_url),
+			"Completed" => format!("{base_url}/Manga/CompletedNewest"),
+			"New Titles" => format!("{base_url}/Manga/NewTitleNewest"),
+			"For Boys" => format!("{base_url}/Manga/ForBoyNewest"),
+			"For Girls" => format!("{base_url}/Manga/ForGirlNewest"),
+			"Ecchi Land" => format!("{base_url}/Manga/EcchiNewest"),
+			"Wallpaper" => format!("{base_url}/WallPaper/Newest?type=Newest&offset="),
+			"Cosplay" => format!("{base_url}/Cosplay/Newest?type=Newest&offset="),
 			_ => unreachable!(),
 		};
 		match listing.name.as_str() {
@@ -209,7 +232,7 @@ impl OtakuSanctuarySource {
 	}
 
 	pub fn get_manga_details(&self, id: String) -> Result<Manga> {
-		let url = format!("{}{id}", self.base_url);
+		let url = self.get_url(&id);
 		cache_manga_page(&url);
 		let html = unsafe { Node::new(&CACHED_MANGA.clone().unwrap()) }?;
 		if id.contains("manga-detail") {
@@ -348,7 +371,7 @@ impl OtakuSanctuarySource {
 	}
 
 	pub fn get_chapter_list(&self, id: String) -> Result<Vec<Chapter>> {
-		let url = format!("{}{id}", self.base_url);
+		let url = self.get_url(&id);
 		cache_manga_page(&url);
 		let html = unsafe { Node::new(&CACHED_MANGA.clone().unwrap()) }?;
 		if id.contains("manga-detail") {
@@ -399,9 +422,9 @@ impl OtakuSanctuarySource {
 						}
 						1 => {
 							let anchor = node.select("a");
-							chapter.id = anchor.attr("href").read();
+							chapter.id = self.get_id(&anchor.attr("href").read());
 							chapter.title = String::from(anchor.text().read().trim());
-							chapter.url = format!("{}{}", self.base_url, chapter.id);
+							chapter.url = self.get_url(&chapter.id);
 						}
 						3 => {
 							chapter.date_updated =
@@ -437,13 +460,13 @@ impl OtakuSanctuarySource {
 	}
 
 	pub fn get_page_list(&self, id: String) -> Result<Vec<Page>> {
+		let base_url = self.get_base_url();
 		if id.contains("chapter") {
-			let resp =
-				Request::new(format!("{}{id}", self.base_url).as_str(), HttpMethod::Get).html()?;
+			let resp = Request::new(self.get_url(&id).as_str(), HttpMethod::Get).html()?;
 			let vi = resp.select("#dataip").attr("value").read();
 			let numeric_id = resp.select("#inpit-c").attr("data-chapter-id").read();
 			let json = Request::new(
-				format!("{}/Manga/CheckingAlternate", self.base_url).as_str(),
+				format!("{base_url}/Manga/CheckingAlternate").as_str(),
 				HttpMethod::Post,
 			)
 			.body(format!("chapId={numeric_id}").as_bytes())
@@ -456,7 +479,7 @@ impl OtakuSanctuarySource {
 			let raw_pages_arr_value = json_object.get("Content");
 			let raw_pages_arr = if raw_pages_arr_value.is_none() {
 				let json = Request::new(
-					format!("{}/Manga/UpdateView", self.base_url).as_str(),
+					format!("{base_url}/Manga/UpdateView").as_str(),
 					HttpMethod::Post,
 				)
 				.body(format!("chapId={numeric_id}").as_bytes())
@@ -485,7 +508,7 @@ impl OtakuSanctuarySource {
 			Ok(page_arr)
 		} else if id.contains("wallpaper") || id.contains("Cosplay") {
 			let html = Request::new(
-				format!("{}{}", self.base_url, id.replace("/image", "")).as_str(),
+				self.get_url(&id.replace("/image", "")).as_str(),
 				HttpMethod::Get,
 			)
 			.html()?;
@@ -504,12 +527,12 @@ impl OtakuSanctuarySource {
 	}
 
 	pub fn modify_image_request(&self, request: Request) {
-		request.header("Referer", self.base_url);
+		request.header("Referer", &self.get_base_url());
 	}
 
 	pub fn handle_url(&self, url: String) -> Result<DeepLink> {
 		if url.contains("manga-detail") || url.contains("Cosplay") || url.contains("wallpaper") {
-			let id = String::from(&url[20..]);
+			let id = self.get_id(&url);
 			Ok(DeepLink {
 				manga: Some(self.get_manga_details(id)?),
 				chapter: None,
@@ -517,10 +540,10 @@ impl OtakuSanctuarySource {
 		} else if url.contains("chapter") {
 			let resp = Request::new(&url, HttpMethod::Get).html()?;
 			let breadcrumbs_node = resp.select("a.itemcrumb.active");
-			let manga_id = breadcrumbs_node.attr("href").read();
+			let manga_id = self.get_id(&breadcrumbs_node.attr("href").read());
 			let manga = Some(self.get_manga_details(manga_id)?);
 			let chapter = Some(Chapter {
-				id: String::from(&url[20..]),
+				id: self.get_id(&url),
 				title: String::new(),
 				volume: -1.0,
 				chapter: -1.0,
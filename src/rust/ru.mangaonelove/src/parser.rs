@@ -258,20 +258,6 @@ pub fn parse_chapters(html: &WNode, manga_id: &str) -> Option<Vec<Chapter>> {
 				}
 			};
 
-			let extract_multiplier = |metric_str: &&str| {
-				if metric_str.starts_with("сек") {
-					Some(1)
-				} else if metric_str.starts_with("мин") {
-					Some(60)
-				} else if metric_str.starts_with("час") {
-					Some(60 * 60)
-				} else if metric_str.starts_with("дн") {
-					Some(24 * 60 * 60)
-				} else {
-					None
-				}
-			};
-
 			let date_updated = {
 				let release_date_node = chapter_node.select_one("span.chapter-release-date")?;
 				let normal_release_date = release_date_node.select_one("i").map(|i_node| {
@@ -283,17 +269,7 @@ pub fn parse_chapters(html: &WNode, manga_id: &str) -> Option<Vec<Chapter>> {
 						.select_one("a")
 						.and_then(|a| a.attr("title"))
 						.and_then(|updated_text| {
-							if !updated_text.ends_with("ago") {
-								return None;
-							}
-							let spl: Vec<_> = updated_text.split_whitespace().collect();
-							let count = spl
-								.first()
-								.and_then(|count_str| count_str.parse::<f64>().ok())?;
-
-							let metric_mult = spl.get(1).and_then(extract_multiplier)?;
-
-							Some(current_date() - count * (metric_mult as f64))
+							relative_date::parse(&updated_text, current_date())
 						})
 						.unwrap_or(0f64)
 				};
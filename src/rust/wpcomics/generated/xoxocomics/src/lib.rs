@@ -0,0 +1,80 @@
+// Generated from sites/xoxocomics/site.toml, don't edit.
+#![no_std]
+use aidoku::{
+	error::Result,
+	prelude::*,
+	std::String,
+	std::Vec,
+	std::net::Request,
+	Chapter,
+	DeepLink,
+	Filter,
+	Listing,
+	Manga,
+	MangaPageResult,
+	Page,
+};
+
+use wpcomics_template::{
+	helper,
+	template::{self, WPComicsSource},
+};
+
+fn get_instance() -> WPComicsSource {
+	WPComicsSource {
+		base_url: String::from("https://xoxocomics.com"),
+		chapter_date_selector: "div.col-xs-3",
+		chapter_skip_first: true,
+		listing_mapping: |listing| {
+			String::from(match listing.as_str() {
+				"Completed" => "status/completed",
+				"Hot" => "hot",
+				"Ongoing" => "status/ongoing",
+				"Popular" => "popular-comics",
+				_ => "",
+			})
+		},
+		manga_details_title_transformer: helper::trunc_trailing_comic,
+		manga_viewer_page_url_suffix: "/all",
+		..Default::default()
+	}
+}
+
+#[get_manga_list]
+fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
+	get_instance().get_manga_list(helper::genre_search_url("https://xoxocomics.com", filters, page)?)
+}
+
+#[get_manga_listing]
+fn get_manga_listing(listing: Listing, page: i32) -> Result<MangaPageResult> {
+	get_instance().get_manga_listing(listing, page)
+}
+
+#[get_manga_details]
+fn get_manga_details(id: String) -> Result<Manga> {
+	get_instance().get_manga_details(id)
+}
+
+#[get_chapter_list]
+fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
+	get_instance().get_chapter_list(id)
+}
+
+#[get_page_list]
+fn get_page_list(_manga_id: String, chapter_id: String) -> Result<Vec<Page>> {
+	get_instance().get_page_list(chapter_id)
+}
+
+#[modify_image_request]
+fn modify_image_request(request: Request) {
+	template::modify_image_request(
+		String::from("https://xoxocomics.com"),
+		String::from("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/101.0.4951.54 Safari/537.36 Edg/101.0.1210.39"),
+		request,
+	)
+}
+
+#[handle_url]
+pub fn handle_url(url: String) -> Result<DeepLink> {
+	get_instance().handle_url(url)
+}
@@ -105,13 +105,7 @@ pub fn convert_time(time_ago: String) -> f64 {
 	let current_time = current_date();
 	let time_arr = time_ago.split(' ').collect::<Vec<&str>>();
 	if time_arr.len() > 2 {
-		match time_arr[1] {
-			"giây" => current_time - time_arr[0].parse::<f64>().unwrap_or(0.0),
-			"phút" => current_time - time_arr[0].parse::<f64>().unwrap_or(0.0) * 60.0,
-			"giờ" => current_time - time_arr[0].parse::<f64>().unwrap_or(0.0) * 3600.0,
-			"ngày" => current_time - time_arr[0].parse::<f64>().unwrap_or(0.0) * 86400.0,
-			_ => current_time,
-		}
+		relative_date::parse(&time_ago, current_time).unwrap_or(current_time)
 	} else if *time_arr[0] == time_ago {
 		StringRef::from(time_ago)
 			.0
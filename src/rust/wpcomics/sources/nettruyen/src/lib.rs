@@ -9,7 +9,7 @@ use aidoku::{
 	std::{defaults::defaults_get, Vec},
 	Chapter, DeepLink, Filter, FilterType, Listing, Manga, MangaPageResult, MangaViewer, Page,
 };
-use wpcomics_template::{helper::urlencode, template, template::WPComicsSource};
+use wpcomics_template::{chapter_parser, helper::urlencode, template, template::WPComicsSource};
 
 fn get_instance() -> WPComicsSource {
 	WPComicsSource {
@@ -26,6 +26,7 @@ fn get_instance() -> WPComicsSource {
 		},
 		status_mapping: status_map,
 		time_converter: convert_time,
+		chapter_title_parser: Some(chapter_parser::parse),
 		page_url_transformer: |url| {
 			let mut server_two = String::from("https://images2-focus-opensocial.googleusercontent.com/gadgets/proxy?container=focus&gadget=a&no_expand=1&resize_h=0&rewriteMime=image%2F*&url=");
 			if let Ok(server_selection) = defaults_get("serverSelection") {
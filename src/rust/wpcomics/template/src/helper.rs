@@ -1,5 +1,6 @@
 use aidoku::{
-	prelude::format, std::html::Node, std::String, std::Vec, MangaContentRating, MangaViewer,
+	error::Result, prelude::format, std::html::Node, std::String, std::Vec, Filter, FilterType,
+	MangaContentRating, MangaViewer,
 };
 
 pub fn trunc_trailing_comic(title: String) -> String {
@@ -55,6 +56,27 @@ pub fn urlencode(string: String) -> String {
 	String::from_utf8(result).unwrap_or_default()
 }
 
+/// The search URL for sites with the template's `Genre` filter: a title
+/// search if there's a title, or else the genre's page.
+pub fn genre_search_url(base_url: &str, filters: Vec<Filter>, page: i32) -> Result<String> {
+	let mut query = String::new();
+	let mut genre = String::new();
+	for filter in filters {
+		match filter.kind {
+			FilterType::Title => query = urlencode(filter.value.as_string()?.read()),
+			_ if filter.name == "Genre" => genre = get_tag_id(filter.value.as_int().unwrap_or(0)),
+			_ => continue,
+		}
+	}
+	Ok(if !query.is_empty() {
+		format!("{base_url}/search?page={page}&keyword={query}")
+	} else if !genre.is_empty() {
+		format!("{base_url}/genre/{genre}?page={page}")
+	} else {
+		String::from(base_url)
+	})
+}
+
 pub fn get_tag_id(genre: i64) -> String {
 	String::from(match genre {
 		1 => "marvel",
@@ -1,3 +1,5 @@
 #![no_std]
 pub mod helper;
 pub mod template;
+
+pub use chapter_parser;
@@ -1,10 +1,12 @@
 use aidoku::{
-	error::Result, prelude::*, std::html::Node, std::net::HttpMethod, std::net::Request,
-	std::String, std::StringRef, std::Vec, Chapter, DeepLink, Listing, Manga, MangaContentRating,
-	MangaPageResult, MangaStatus, MangaViewer, Page,
+	error::Result, prelude::*, std::defaults::defaults_get, std::html::Node, std::net::HttpMethod,
+	std::net::Request, std::String, std::StringRef, std::Vec, Chapter, DeepLink, Listing, Manga,
+	MangaContentRating, MangaPageResult, MangaStatus, MangaViewer, Page,
 };
 
 use crate::helper::{append_protocol, extract_f32_from_string, text_with_newlines};
+use chapter_parser::ChapterInfo;
+use page_filter::PageFilter;
 
 pub struct WPComicsSource {
 	pub base_url: String,
@@ -40,10 +42,20 @@ pub struct WPComicsSource {
 	pub chapter_skip_first: bool,
 	pub chapter_date_selector: &'static str,
 	pub chapter_anchor_selector: &'static str,
+	/// Reads volume, chapter and title from the chapter anchor text, with the
+	/// manga title already removed. The numbers are picked out of the text
+	/// when this isn't set.
+	pub chapter_title_parser: Option<fn(&str) -> ChapterInfo>,
 
 	pub manga_viewer_page: &'static str,
 	pub manga_viewer_page_url_suffix: &'static str,
 	pub page_url_transformer: fn(String) -> String,
+	/// Extra regexes for images to leave out of chapters, on top of the
+	/// shared blocklist
+	pub page_blocklist: &'static [&'static str],
+	/// Leave out credit and recruitment pages, unless the source has a
+	/// `dropCreditPages` setting turned off
+	pub drop_credit_pages: bool,
 
 	pub vinahost_protection: bool,
 }
@@ -261,29 +273,42 @@ impl WPComicsSource {
 				.select(self.chapter_anchor_selector)
 				.text()
 				.read();
-			let numbers =
-				extract_f32_from_string(String::from(title), String::from(&chapter_title));
-			let (volume, chapter) =
-				if numbers.len() > 1 && chapter_title.to_ascii_lowercase().contains("vol") {
-					(numbers[0], numbers[1])
-				} else if !numbers.is_empty() {
-					(-1.0, numbers[0])
-				} else {
-					(-1.0, -1.0)
-				};
-			if chapter >= 0.0 {
-				let splitter = format!(" {}", chapter);
-				let splitter2 = format!("#{}", chapter);
-				if chapter_title.contains(&splitter) {
-					let split = chapter_title.splitn(2, &splitter).collect::<Vec<&str>>();
-					chapter_title =
-						String::from(split[1]).replacen(|char| char == ':' || char == '-', "", 1);
-				} else if chapter_title.contains(&splitter2) {
-					let split = chapter_title.splitn(2, &splitter2).collect::<Vec<&str>>();
-					chapter_title =
-						String::from(split[1]).replacen(|char| char == ':' || char == '-', "", 1);
+			let (volume, chapter) = if let Some(parser) = self.chapter_title_parser {
+				let info = parser(chapter_title.replace(title, "").trim());
+				chapter_title = info.title;
+				(info.volume, info.chapter)
+			} else {
+				let numbers =
+					extract_f32_from_string(String::from(title), String::from(&chapter_title));
+				let (volume, chapter) =
+					if numbers.len() > 1 && chapter_title.to_ascii_lowercase().contains("vol") {
+						(numbers[0], numbers[1])
+					} else if !numbers.is_empty() {
+						(-1.0, numbers[0])
+					} else {
+						(-1.0, -1.0)
+					};
+				if chapter >= 0.0 {
+					let splitter = format!(" {}", chapter);
+					let splitter2 = format!("#{}", chapter);
+					if chapter_title.contains(&splitter) {
+						let split = chapter_title.splitn(2, &splitter).collect::<Vec<&str>>();
+						chapter_title = String::from(split[1]).replacen(
+							|char| char == ':' || char == '-',
+							"",
+							1,
+						);
+					} else if chapter_title.contains(&splitter2) {
+						let split = chapter_title.splitn(2, &splitter2).collect::<Vec<&str>>();
+						chapter_title = String::from(split[1]).replacen(
+							|char| char == ':' || char == '-',
+							"",
+							1,
+						);
+					}
 				}
-			}
+				(volume, chapter)
+			};
 			let date_updated = (self.time_converter)(
 				chapter_node
 					.select(self.chapter_date_selector)
@@ -308,15 +333,27 @@ impl WPComicsSource {
 		let mut pages: Vec<Page> = Vec::new();
 		let url = format!("{}{}", &chapter_id, self.manga_viewer_page_url_suffix);
 		let html = self.request_vinahost(&url).html()?;
-		for (at, page) in html.select(self.manga_viewer_page).array().enumerate() {
+		let setting = defaults_get("dropCreditPages")
+			.and_then(|value| value.as_bool())
+			.ok();
+		let filter = PageFilter::for_source(self.page_blocklist, self.drop_credit_pages, setting);
+		let mut urls: Vec<String> = Vec::new();
+		for page in html.select(self.manga_viewer_page).array() {
 			let page_node = page.as_node().expect("node array");
 			let mut page_url = page_node.attr("data-original").read();
+			// filtered as the page has it, so patterns like `^data:` still match
+			if filter.is_blocked(&page_url) {
+				continue;
+			}
 			if !page_url.starts_with("http") {
 				page_url = String::from("https:") + &page_url;
 			}
+			urls.push((self.page_url_transformer)(page_url));
+		}
+		for (at, page_url) in urls.into_iter().enumerate() {
 			pages.push(Page {
 				index: at as i32,
-				url: (self.page_url_transformer)(page_url),
+				url: page_url,
 				base64: String::new(),
 				text: String::new(),
 			});
@@ -411,11 +448,14 @@ impl Default for WPComicsSource {
 
 			chapter_skip_first: false,
 			chapter_anchor_selector: "div.chapter > a",
+			chapter_title_parser: None,
 			chapter_date_selector: "div.col-xs-4",
 
 			manga_viewer_page: "div.page-chapter > img",
 			manga_viewer_page_url_suffix: "",
 			page_url_transformer: |url| url,
+			page_blocklist: &[],
+			drop_credit_pages: false,
 
 			vinahost_protection: false,
 		}
@@ -1,27 +1,142 @@
-use aes::{
-	cipher::{block_padding::Pkcs7, BlockDecryptMut, KeyIvInit},
-	Aes128,
+use aidoku::{
+	error::{AidokuError, AidokuErrorKind, NodeError, Result},
+	prelude::println,
+	std::{
+		defaults::{defaults_get, defaults_set},
+		html::Node,
+		net::Request,
+		String, StringRef, Vec,
+	},
 };
-use aidoku::std::String;
+use alloc::string::ToString;
+use crypto_helpers::{aes::cbc_decrypt_hex, encoding::to_utf8};
 
-type Aes128CbcDec = cbc::Decryptor<Aes128>;
+/// The compiled-in key, used until a newer one has been scraped from the site.
+const KEY: &str = "xxxmanga.woo.key";
 
-const KEY: &[u8] = b"xxxmanga.woo.key";
+/// Where the most recently discovered key is cached.
+const KEY_DEFAULTS_KEY: &str = "decryptionKey";
+
+/// The first 16 characters are the IV, and the rest is the hex-encoded
+/// ciphertext.
+const IV_LEN: usize = 16;
+
+/// AES-128 keys are 16 bytes long.
+const KEY_LEN: usize = 16;
 
 pub trait EncryptedString {
-	fn decrypt(self) -> String;
+	/// Decrypts with the cached key. If that fails, the site has probably
+	/// rotated its key, so a new one is scraped from the page returned by
+	/// `key_page` and decryption is retried with it.
+	fn decrypt<F: FnOnce() -> Result<Node>>(self, key_page: F) -> Result<String>;
 }
 
 impl EncryptedString for String {
-	fn decrypt(self) -> String {
-		let encrypted_data = self.as_bytes();
-		let iv = &encrypted_data[..16];
-		let mut ciphertext =
-			hex::decode(&encrypted_data[16..]).expect("Failed to hex-decode ciphertext.");
-
-		let plaintext = Aes128CbcDec::new(KEY.into(), iv.into())
-			.decrypt_padded_mut::<Pkcs7>(&mut ciphertext)
-			.expect("Failed to decrypt chapter list");
-		String::from_utf8_lossy(plaintext).into()
+	fn decrypt<F: FnOnce() -> Result<Node>>(self, key_page: F) -> Result<String> {
+		let key = get_key();
+		if let Ok(plaintext) = decrypt_with(&self, &key) {
+			return Ok(plaintext);
+		}
+
+		for candidate in find_keys(&key_page()?) {
+			if candidate == key {
+				continue;
+			}
+			if let Ok(plaintext) = decrypt_with(&self, &candidate) {
+				defaults_set(KEY_DEFAULTS_KEY, StringRef::from(candidate.as_str()).0);
+				return Ok(plaintext);
+			}
+		}
+
+		decrypt_with(&self, &key).map_err(decryption_error)
+	}
+}
+
+fn get_key() -> String {
+	defaults_get(KEY_DEFAULTS_KEY)
+		.and_then(|value| value.as_string())
+		.map(|str_ref| str_ref.read())
+		.ok()
+		.filter(|key| key.len() == KEY_LEN)
+		.unwrap_or_else(|| KEY.to_string())
+}
+
+fn decrypt_with(encrypted: &str, key: &str) -> crypto_helpers::Result<String> {
+	if encrypted.len() < IV_LEN || !encrypted.is_char_boundary(IV_LEN) {
+		return Err(crypto_helpers::Error::InvalidIvLength);
+	}
+	let (iv, ciphertext) = encrypted.split_at(IV_LEN);
+
+	cbc_decrypt_hex(key.as_bytes(), iv.as_bytes(), ciphertext).and_then(to_utf8)
+}
+
+/// Collects key candidates from the page's inline scripts first, then from the
+/// site's own bundled scripts.
+fn find_keys(page: &Node) -> Vec<String> {
+	let mut keys = Vec::<String>::new();
+	let mut bundles = Vec::<String>::new();
+
+	for script_value in page.select("script").array() {
+		let Ok(script) = script_value.as_node() else {
+			continue;
+		};
+
+		let src = script.attr("abs:src").read();
+		if src.is_empty() {
+			push_candidates(&script.html().read(), &mut keys);
+		} else if src.contains("copymanga") || src.contains("mangacopy") {
+			bundles.push(src);
+		}
+	}
+
+	for src in bundles {
+		if let Ok(js) = Request::get(src).string() {
+			push_candidates(&js, &mut keys);
+		}
+	}
+
+	keys
+}
+
+/// Pushes every 16-character string literal that is being assigned, e.g.
+/// `var dio = 'xxxmanga.woo.key';`.
+fn push_candidates(script: &str, keys: &mut Vec<String>) {
+	let bytes = script.as_bytes();
+	let mut index = 0;
+	while index < bytes.len() {
+		let quote = bytes[index];
+		if quote != b'\'' && quote != b'"' {
+			index += 1;
+			continue;
+		}
+
+		let start = index + 1;
+		let Some(len) = bytes[start..].iter().position(|byte| *byte == quote) else {
+			break;
+		};
+		let end = start + len;
+
+		let is_assigned = bytes[..index]
+			.iter()
+			.rev()
+			.find(|byte| !byte.is_ascii_whitespace())
+			.map_or(false, |byte| *byte == b'=' || *byte == b':');
+		let literal = &bytes[start..end];
+		let is_key_like = literal.len() == KEY_LEN && literal.iter().all(u8::is_ascii_graphic);
+		if is_assigned && is_key_like {
+			let key = String::from_utf8_lossy(literal).to_string();
+			if !keys.contains(&key) {
+				keys.push(key);
+			}
+		}
+
+		index = end + 1;
+	}
+}
+
+fn decryption_error(reason: crypto_helpers::Error) -> AidokuError {
+	println!("Failed to decrypt, the key might have changed: {}", reason);
+	AidokuError {
+		reason: AidokuErrorKind::NodeError(NodeError::ParseError),
 	}
 }
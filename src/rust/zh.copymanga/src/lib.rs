@@ -7,14 +7,25 @@ mod url;
 use aidoku::{
 	error::Result,
 	helpers::substring::Substring,
-	prelude::{get_chapter_list, get_manga_details, get_manga_list, get_page_list, handle_url},
-	std::{String, Vec},
-	Chapter, DeepLink, Filter, Manga, MangaPageResult, MangaStatus, Page,
+	prelude::{
+		format, get_chapter_list, get_manga_details, get_manga_list, get_manga_listing,
+		get_page_list, handle_notification, handle_url,
+	},
+	std::{
+		current_date,
+		defaults::{defaults_get, defaults_set},
+		net::HttpMethod,
+		String, StringRef, Vec,
+	},
+	Chapter, DeepLink, Filter, Listing, Manga, MangaPageResult, MangaStatus, Page,
 };
 use alloc::string::ToString;
 use decryptor::EncryptedString;
-use parser::{Element, JsonObj, JsonString, MangaListResponse, NodeArrValue, UuidString};
-use url::{Url, CHAPTER_PATH, MANGA_PATH};
+use parser::{
+	get_api_chapters, get_api_page_urls, BookshelfResponse, Element, JsonObj, JsonString,
+	MangaListResponse, NodeArrValue, UuidString,
+};
+use url::{get_token, Url, CHAPTER_PATH, MANGA_PATH};
 
 #[get_manga_list]
 fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
@@ -29,6 +40,17 @@ fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
 	search_json.get_page_result()
 }
 
+#[get_manga_listing]
+fn get_manga_listing(listing: Listing, page: i32) -> Result<MangaPageResult> {
+	match listing.name.as_str() {
+		"書架" => BookshelfResponse(Url::Bookshelf(page).get_json()?).get_page_result(),
+		_ => Ok(MangaPageResult {
+			manga: Vec::new(),
+			has_more: false,
+		}),
+	}
+}
+
 #[get_manga_details]
 fn get_manga_details(manga_id: String) -> Result<Manga> {
 	let manga_page = Url::Manga(&manga_id).get_html()?;
@@ -82,12 +104,41 @@ fn get_manga_details(manga_id: String) -> Result<Manga> {
 fn get_chapter_list(manga_id: String) -> Result<Vec<Chapter>> {
 	let mut chapters = Vec::<Chapter>::new();
 
+	let mut chapter_vec = match get_token() {
+		Some(_) => get_api_chapters(&manga_id)?,
+		None => get_web_chapters(&manga_id)?,
+	};
+	chapter_vec.sort_by(|a, b| a.2.total_cmp(&b.2));
+
+	for (index, (chapter_id, title, date_updated)) in chapter_vec.iter().enumerate() {
+		let chapter_num = (index + 1) as f32;
+
+		let chapter_url = Url::Chapter(&manga_id, chapter_id).to_string();
+
+		let chapter = Chapter {
+			id: chapter_id.clone(),
+			title: title.clone(),
+			chapter: chapter_num,
+			date_updated: *date_updated,
+			url: chapter_url,
+			lang: "zh".to_string(),
+			..Default::default()
+		};
+		chapters.insert(0, chapter);
+	}
+
+	Ok(chapters)
+}
+
+/// Returns `(id, title, timestamp)` for every chapter of every group, from
+/// the encrypted chapter list of the public site.
+fn get_web_chapters(manga_id: &str) -> Result<Vec<(String, String, f64)>> {
 	let mut chapter_vec = Vec::<(String, String, f64)>::new();
-	let groups_values = Url::ChapterList(&manga_id)
+	let groups_values = Url::ChapterList(manga_id)
 		.get_json()?
 		.as_object()?
 		.get_as_string("results")?
-		.decrypt()
+		.decrypt(|| Url::Manga(manga_id).get_html())?
 		.json()?
 		.as_object()?
 		.get("groups")
@@ -105,36 +156,31 @@ fn get_chapter_list(manga_id: String) -> Result<Vec<Chapter>> {
 			chapter_vec.push((id, name, timestamp));
 		}
 	}
-	chapter_vec.sort_by(|a, b| a.2.total_cmp(&b.2));
-
-	for (index, (chapter_id, title, date_updated)) in chapter_vec.iter().enumerate() {
-		let chapter_num = (index + 1) as f32;
-
-		let chapter_url = Url::Chapter(&manga_id, chapter_id).to_string();
-
-		let chapter = Chapter {
-			id: chapter_id.clone(),
-			title: title.clone(),
-			chapter: chapter_num,
-			date_updated: *date_updated,
-			url: chapter_url,
-			lang: "zh".to_string(),
-			..Default::default()
-		};
-		chapters.insert(0, chapter);
-	}
 
-	Ok(chapters)
+	Ok(chapter_vec)
 }
 
 #[get_page_list]
 fn get_page_list(manga_id: String, chapter_id: String) -> Result<Vec<Page>> {
 	let mut pages = Vec::<Page>::new();
 
-	let page_arr = Url::Chapter(&manga_id, &chapter_id)
-		.get_html()?
+	if get_token().is_some() {
+		let page_urls = get_api_page_urls(&manga_id, &chapter_id)?;
+		for (index, page_url) in page_urls.into_iter().enumerate() {
+			pages.push(Page {
+				index: index as i32,
+				url: page_url,
+				..Default::default()
+			});
+		}
+
+		return Ok(pages);
+	}
+
+	let chapter_page = Url::Chapter(&manga_id, &chapter_id).get_html()?;
+	let page_arr = chapter_page
 		.get_attr("div.imageData", "contentkey")
-		.decrypt()
+		.decrypt(|| Ok(chapter_page.clone()))?
 		.json()?
 		.as_array()?;
 
@@ -183,3 +229,73 @@ fn handle_url(url: String) -> Result<DeepLink> {
 		chapter: Some(chapter),
 	})
 }
+
+#[handle_notification]
+fn handle_notification(notification: String) {
+	match notification.as_str() {
+		"signIn" => sign_in(),
+		"signOut" => {
+			defaults_set("token", StringRef::from("").0);
+			set_sign_in_status("已登出");
+		}
+		_ => (),
+	}
+}
+
+/// Signs in and shows how it went in the `signInStatus` setting, as a button
+/// has no other way of telling the user.
+fn sign_in() {
+	match request_token() {
+		Ok(token) => {
+			defaults_set("token", StringRef::from(token.as_str()).0);
+			set_sign_in_status("登入成功");
+		}
+		Err(message) => {
+			defaults_set("token", StringRef::from("").0);
+			set_sign_in_status(&format!("登入失敗：{}", message));
+		}
+	}
+}
+
+fn set_sign_in_status(status: &str) {
+	defaults_set("signInStatus", StringRef::from(status).0);
+}
+
+/// Returns the token for the saved username and password, or why there
+/// isn't one.
+fn request_token() -> core::result::Result<String, String> {
+	let setting = |key: &str| {
+		defaults_get(key)
+			.and_then(|value| value.as_string())
+			.map(|value| value.read())
+			.unwrap_or_default()
+	};
+	let username = setting("username");
+	let password = setting("password");
+	if username.is_empty() || password.is_empty() {
+		return Err("請輸入用戶名及密碼".to_string());
+	}
+	let salt = (current_date() as i32).rem_euclid(9000) + 1000;
+
+	let Ok(response_obj) = Url::SignIn
+		.request(HttpMethod::Post)
+		.header("Content-Type", "application/x-www-form-urlencoded")
+		.body(Url::login_body(&username, &password, salt))
+		.json()
+		.and_then(|json| json.as_object())
+	else {
+		return Err("無法連線至伺服器".to_string());
+	};
+
+	response_obj
+		.get("results")
+		.as_object()
+		.and_then(|results| results.get_as_string("token"))
+		.map_err(|_| {
+			response_obj
+				.get_as_string("message")
+				.ok()
+				.filter(|message| !message.is_empty())
+				.unwrap_or_else(|| "伺服器沒有回傳 token".to_string())
+		})
+}
@@ -1,4 +1,4 @@
-use crate::url::Url;
+use crate::url::{Url, CHAPTER_LIMIT};
 use aidoku::{
 	error::Result,
 	std::{html::Node, json, ArrayRef, ObjectRef, String, ValueRef, Vec},
@@ -52,6 +52,29 @@ impl MangaListResponse for ValueRef {
 	}
 }
 
+/// The `/api/v3/member/collect/comics` response, whose entries wrap each
+/// manga in a `comic` object.
+pub struct BookshelfResponse(pub ValueRef);
+
+impl MangaListResponse for BookshelfResponse {
+	fn get_page_result(self) -> Result<MangaPageResult> {
+		let results_obj = self.0.as_object()?.get("results").as_object()?;
+
+		let mut manga = Vec::<Manga>::new();
+		for item_value in results_obj.get("list").as_array()? {
+			let comic_obj = item_value.as_object()?.get("comic").as_object()?;
+			manga.push(comic_obj.get_manga()?);
+		}
+
+		let total = results_obj.get("total").as_int()?;
+		let limit = results_obj.get("limit").as_int()?;
+		let offset = results_obj.get("offset").as_int()?;
+		let has_more = (offset + limit) < total;
+
+		Ok(MangaPageResult { manga, has_more })
+	}
+}
+
 trait MangaArr {
 	fn get_manga_list(self) -> Result<Vec<Manga>>;
 }
@@ -60,50 +83,130 @@ impl MangaArr for ArrayRef {
 	fn get_manga_list(self) -> Result<Vec<Manga>> {
 		let mut manga = Vec::<Manga>::new();
 		for manga_value in self {
-			let manga_obj = manga_value.as_object()?;
-
-			let manga_id = manga_obj.get_as_string("path_word")?;
-
-			let cover = manga_obj
-				.get_as_string("cover")?
-				.replace(".328x422.jpg", "");
-
-			let title = manga_obj.get_as_string("name")?;
-
-			let artist = manga_obj
-				.get("author")
-				.as_array()?
-				.filter_map(|value| value.as_object().ok())
-				.filter_map(|obj| obj.get("name").as_string().ok())
-				.map(|str_ref| str_ref.read())
-				.collect::<Vec<_>>()
-				.join("、");
-
-			let manga_url = Url::Manga(&manga_id).to_string();
-
-			let status_code = manga_obj.get("status").as_int().unwrap_or(-1);
-			let status = match status_code {
-				0 => MangaStatus::Ongoing,
-				1 | 2 => MangaStatus::Completed,
-				_ => MangaStatus::Unknown,
-			};
-
-			manga.push(Manga {
-				id: manga_id,
-				cover,
-				title,
-				author: artist.clone(),
-				artist,
-				url: manga_url,
-				status,
-				..Default::default()
-			});
+			manga.push(manga_value.as_object()?.get_manga()?);
 		}
 
 		Ok(manga)
 	}
 }
 
+trait MangaObj {
+	fn get_manga(self) -> Result<Manga>;
+}
+
+impl MangaObj for ObjectRef {
+	fn get_manga(self) -> Result<Manga> {
+		let manga_id = self.get_as_string("path_word")?;
+
+		let cover = self.get_as_string("cover")?.replace(".328x422.jpg", "");
+
+		let title = self.get_as_string("name")?;
+
+		let artist = self
+			.get("author")
+			.as_array()?
+			.filter_map(|value| value.as_object().ok())
+			.filter_map(|obj| obj.get("name").as_string().ok())
+			.map(|str_ref| str_ref.read())
+			.collect::<Vec<_>>()
+			.join("、");
+
+		let manga_url = Url::Manga(&manga_id).to_string();
+
+		let status_code = self.get("status").as_int().unwrap_or(-1);
+		let status = match status_code {
+			0 => MangaStatus::Ongoing,
+			1 | 2 => MangaStatus::Completed,
+			_ => MangaStatus::Unknown,
+		};
+
+		Ok(Manga {
+			id: manga_id,
+			cover,
+			title,
+			author: artist.clone(),
+			artist,
+			url: manga_url,
+			status,
+			..Default::default()
+		})
+	}
+}
+
+/// Returns `(id, title, timestamp)` for every chapter of every group, fetched
+/// from the API so that chapters only visible to signed-in users are
+/// included.
+pub fn get_api_chapters(manga_id: &str) -> Result<Vec<(String, String, f64)>> {
+	let mut chapters = Vec::<(String, String, f64)>::new();
+
+	let groups_values = Url::ApiManga(manga_id)
+		.get_json()?
+		.as_object()?
+		.get("results")
+		.as_object()?
+		.get("groups")
+		.as_object()?
+		.values();
+	for groups_value in groups_values {
+		let group = groups_value.as_object()?.get_as_string("path_word")?;
+
+		let mut offset = 0;
+		loop {
+			let results_obj = Url::ApiChapterList(manga_id, &group, offset)
+				.get_json()?
+				.as_object()?
+				.get("results")
+				.as_object()?;
+
+			for chapters_value in results_obj.get("list").as_array()? {
+				let chapters_obj = chapters_value.as_object()?;
+
+				let id = chapters_obj.get_as_string("uuid")?;
+				let name = chapters_obj.get_as_string("name")?;
+				let timestamp = id.get_timestamp();
+
+				chapters.push((id, name, timestamp));
+			}
+
+			offset += CHAPTER_LIMIT;
+			if offset >= results_obj.get("total").as_int()? as i32 {
+				break;
+			}
+		}
+	}
+
+	Ok(chapters)
+}
+
+/// Returns the page URLs of a chapter from the API. `words` holds the
+/// position of each entry of `contents`, which are not in reading order.
+pub fn get_api_page_urls(manga_id: &str, chapter_id: &str) -> Result<Vec<String>> {
+	let chapter_obj = Url::ApiChapter(manga_id, chapter_id)
+		.get_json()?
+		.as_object()?
+		.get("results")
+		.as_object()?
+		.get("chapter")
+		.as_object()?;
+
+	let words = chapter_obj
+		.get("words")
+		.as_array()?
+		.filter_map(|value| value.as_int().ok())
+		.collect::<Vec<_>>();
+
+	let mut pages = Vec::<(i64, String)>::new();
+	for (index, content_value) in chapter_obj.get("contents").as_array()?.enumerate() {
+		let url = content_value.as_object()?.get_as_string("url")?;
+		let position = words.get(index).copied().unwrap_or(index as i64);
+
+		pages.push((position, url));
+	}
+	pages.sort_by_key(|page| page.0);
+
+	Ok(pages.into_iter().map(|page| page.1).collect())
+}
+
 pub trait Element {
 	fn get_attr(&self, selector: &str, attr: &str) -> String;
 	fn get_text(&self, selector: &str) -> String;
@@ -1,11 +1,18 @@
 use aidoku::{
 	error::Result,
 	helpers::uri::QueryParameters,
-	std::{html::Node, net::Request, ValueRef, Vec},
+	prelude::format,
+	std::{
+		defaults::defaults_get,
+		html::Node,
+		net::{HttpMethod, Request},
+		String, ValueRef, Vec,
+	},
 	Filter, FilterType,
 };
 use alloc::string::ToString;
 use core::fmt::Display;
+use crypto_helpers::encoding::base64_encode;
 
 pub enum Url<'a> {
 	/// https://copymanga.site/comics?theme={}&status={}&region={}&ordering={}&offset={}&limit={}
@@ -113,7 +120,7 @@ pub enum Url<'a> {
 	/// Manga per response
 	Filters(QueryParameters),
 
-	/// https://copymanga.site/api/kb/web/searchs/comics?offset={}&platform={}&limit={}&q={}&q_type={}
+	/// https://copymanga.site/api/kb/web/searchs/comics?offset={}&platform={}&lang={}&limit={}&q={}&q_type={}
 	///
 	/// ---
 	///
@@ -125,6 +132,10 @@ pub enum Url<'a> {
 	///
 	/// `2`
 	///
+	/// ## `lang`
+	///
+	/// See [`Language`]
+	///
 	/// ## `limit`
 	///
 	/// Manga per response
@@ -149,6 +160,35 @@ pub enum Url<'a> {
 
 	/// https://copymanga.site/comic/{manga_id}/chapter/{chapter_id}
 	Chapter(&'a str, &'a str),
+
+	/// https://{api_domain}/api/v3/login
+	///
+	/// ---
+	///
+	/// `POST` with a form body, see [`Url::login_body`].
+	SignIn,
+
+	/// https://{api_domain}/api/v3/member/collect/comics?limit={}&offset={}&free_type=1&ordering=-datetime_modifier&platform=3&lang={}
+	///
+	/// ---
+	///
+	/// ## `offset`
+	///
+	/// `({page} - 1) * {limit}`
+	///
+	/// ## `limit`
+	///
+	/// Manga per response
+	Bookshelf(i32),
+
+	/// https://{api_domain}/api/v3/comic2/{manga_id}?platform=3&lang={}
+	ApiManga(&'a str),
+
+	/// https://{api_domain}/api/v3/comic/{manga_id}/group/{group}/chapters?limit={}&offset={}&platform=3&lang={}
+	ApiChapterList(&'a str, &'a str, i32),
+
+	/// https://{api_domain}/api/v3/comic/{manga_id}/chapter2/{chapter_id}?platform=3&lang={}
+	ApiChapter(&'a str, &'a str),
 }
 
 /// # 狀態
@@ -183,6 +223,39 @@ enum Region {
 	West = 2,
 }
 
+/// # 語系
+///
+/// Picked by the `isTC` setting. The site reads `Accept-Language`, while the
+/// API only goes by its `lang` parameter.
+#[derive(Copy, Clone)]
+enum Language {
+	/// ## 繁體
+	Traditional,
+
+	/// ## 简体
+	Simplified,
+}
+
+impl Language {
+	fn get() -> Self {
+		let is_tc = defaults_get("isTC")
+			.and_then(|value| value.as_bool())
+			.unwrap_or(true);
+		if is_tc {
+			Self::Traditional
+		} else {
+			Self::Simplified
+		}
+	}
+
+	fn accept_language(self) -> &'static str {
+		match self {
+			Self::Traditional => "zh-TW",
+			Self::Simplified => "zh-CN",
+		}
+	}
+}
+
 /// # 排序
 enum Sort {
 	/// ## 更新時間
@@ -199,6 +272,7 @@ enum Sort {
 }
 
 const DOMAIN: &str = "https://copymanga.site";
+const DEFAULT_API_DOMAIN: &str = "api.copymanga.tv";
 pub const MANGA_PATH: &str = "/comic/";
 pub const CHAPTER_PATH: &str = "/chapter/";
 
@@ -276,14 +350,94 @@ const REGIONS: [Region; 4] = [Region::All, Region::Japan, Region::Korea, Region:
 /// The number of manga that a single response contains.
 const LIMIT: i32 = 20;
 
+/// The number of chapters that a single API response contains.
+pub const CHAPTER_LIMIT: i32 = 500;
+
+const USER_AGENT: &str = "Mozilla/5.0 (iPhone; CPU iPhone OS 16_5 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/16.5 Mobile/15E148 Safari/604.1";
+
 impl<'a> Url<'a> {
 	pub fn get_html(self) -> Result<Node> {
-		Request::get(self.to_string()).html()
+		self.request(HttpMethod::Get).html()
 	}
 
 	pub fn get_json(self) -> Result<ValueRef> {
-		Request::get(self.to_string()).json()
+		self.request(HttpMethod::Get).json()
+	}
+
+	pub fn request(self, method: HttpMethod) -> Request {
+		let request = Request::new(self.to_string(), method)
+			.header("User-Agent", USER_AGENT)
+			.header("Accept-Language", Language::get().accept_language());
+
+		if !self.is_api() {
+			return request;
+		}
+
+		let region = if get_api_domain() == DEFAULT_API_DOMAIN {
+			"0"
+		} else {
+			"1"
+		};
+		let request = request
+			.header("platform", "3")
+			.header("region", region)
+			.header("webp", "0");
+
+		match get_token() {
+			Some(token) => request.header("authorization", &format!("Token {}", token)),
+			None => request,
+		}
 	}
+
+	/// Builds the form body for [`Url::SignIn`]. The password is sent as
+	/// `base64("{password}-{salt}")`.
+	pub fn login_body(username: &str, password: &str, salt: i32) -> String {
+		let mut query = QueryParameters::new();
+		query.push("username", Some(username));
+		query.push(
+			"password",
+			Some(&base64_encode(format!("{}-{}", password, salt))),
+		);
+		query.push_encoded("salt", Some(salt.to_string().as_str()));
+		query.push_encoded("platform", Some("3"));
+		query.push_encoded("lang", Some(Language::get().to_string().as_str()));
+		query.push_encoded("authorization", Some("Token+"));
+		query.push_encoded("version", Some("1.4.4"));
+		query.push_encoded("source", Some("copyApp"));
+
+		query.to_string()
+	}
+
+	fn is_api(&self) -> bool {
+		matches!(
+			self,
+			Self::SignIn
+				| Self::Bookshelf(_)
+				| Self::ApiManga(_)
+				| Self::ApiChapterList(..)
+				| Self::ApiChapter(..)
+		)
+	}
+}
+
+/// Returns the API host selected in settings, either the mainland or the
+/// overseas one.
+fn get_api_domain() -> String {
+	defaults_get("apiDomain")
+		.and_then(|value| value.as_string())
+		.map(|str_ref| str_ref.read())
+		.ok()
+		.filter(|domain| !domain.is_empty())
+		.unwrap_or_else(|| DEFAULT_API_DOMAIN.to_string())
+}
+
+/// Returns the token obtained by signing in, if any.
+pub fn get_token() -> Option<String> {
+	defaults_get("token")
+		.and_then(|value| value.as_string())
+		.map(|str_ref| str_ref.read())
+		.ok()
+		.filter(|token| !token.is_empty())
 }
 
 impl<'a> Display for Url<'a> {
@@ -300,6 +454,40 @@ impl<'a> Display for Url<'a> {
 				"{}{}{}{}{}",
 				DOMAIN, MANGA_PATH, manga_id, CHAPTER_PATH, chapter_id
 			),
+			Self::SignIn => write!(f, "https://{}/api/v3/login", get_api_domain()),
+			Self::Bookshelf(page) => write!(
+				f,
+				"https://{}/api/v3/member/collect/comics?limit={}&offset={}&free_type=1&ordering=-datetime_modifier&platform=3&lang={}",
+				get_api_domain(),
+				LIMIT,
+				(page - 1) * LIMIT,
+				Language::get()
+			),
+			Self::ApiManga(manga_id) => write!(
+				f,
+				"https://{}/api/v3/comic2/{}?platform=3&lang={}",
+				get_api_domain(),
+				manga_id,
+				Language::get()
+			),
+			Self::ApiChapterList(manga_id, group, offset) => write!(
+				f,
+				"https://{}/api/v3/comic/{}/group/{}/chapters?limit={}&offset={}&platform=3&lang={}",
+				get_api_domain(),
+				manga_id,
+				group,
+				CHAPTER_LIMIT,
+				offset,
+				Language::get()
+			),
+			Self::ApiChapter(manga_id, chapter_id) => write!(
+				f,
+				"https://{}/api/v3/comic/{}/chapter2/{}?platform=3&lang={}",
+				get_api_domain(),
+				manga_id,
+				chapter_id,
+				Language::get()
+			),
 		}
 	}
 }
@@ -354,6 +542,7 @@ impl<'a> From<(Vec<Filter>, i32)> for Url<'a> {
 					let search_str = search_str_ref.read();
 
 					query.push_encoded("platform", Some(2.to_string().as_str()));
+					query.push_encoded("lang", Some(Language::get().to_string().as_str()));
 					query.push("q", Some(&search_str));
 					query.push_encoded("q_type", None);
 
@@ -389,6 +578,15 @@ macro_rules! impl_display {
 impl_display!(Status);
 impl_display!(Region);
 
+impl Display for Language {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			Self::Traditional => write!(f, "zh-hant"),
+			Self::Simplified => write!(f, "zh-hans"),
+		}
+	}
+}
+
 impl Display for Sort {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		match self {
@@ -1,9 +1,15 @@
-use aidoku::{std::net::HttpMethod, std::net::Request, std::String, std::Vec};
+use aidoku::{
+	error::{AidokuError, AidokuErrorKind, NodeError, Result},
+	prelude::println,
+	std::net::HttpMethod,
+	std::net::Request,
+	std::String,
+	std::Vec,
+};
 
 use prost::bytes::Bytes;
 
-use base64ct::{Base64, Encoding};
-use rsa::pkcs8::DecodePrivateKey;
+use crypto_helpers::rsa::{decrypt_chunked_base64, private_key_from_base64};
 
 pub mod protobuf {
 	include!(concat!(env!("OUT_DIR"), "/dmzj.chapter_images.rs"));
@@ -75,34 +81,27 @@ pub fn get(url: &str) -> Request {
 
 const KEY:&str = "MIICeAIBADANBgkqhkiG9w0BAQEFAASCAmIwggJeAgEAAoGBAK8nNR1lTnIfIes6oRWJNj3mB6OssDGx0uGMpgpbVCpf6+VwnuI2stmhZNoQcM417Iz7WqlPzbUmu9R4dEKmLGEEqOhOdVaeh9Xk2IPPjqIu5TbkLZRxkY3dJM1htbz57d/roesJLkZXqssfG5EJauNc+RcABTfLb4IiFjSMlTsnAgMBAAECgYEAiz/pi2hKOJKlvcTL4jpHJGjn8+lL3wZX+LeAHkXDoTjHa47g0knYYQteCbv+YwMeAGupBWiLy5RyyhXFoGNKbbnvftMYK56hH+iqxjtDLnjSDKWnhcB7089sNKaEM9Ilil6uxWMrMMBH9v2PLdYsqMBHqPutKu/SigeGPeiB7VECQQDizVlNv67go99QAIv2n/ga4e0wLizVuaNBXE88AdOnaZ0LOTeniVEqvPtgUk63zbjl0P/pzQzyjitwe6HoCAIpAkEAxbOtnCm1uKEp5HsNaXEJTwE7WQf7PrLD4+BpGtNKkgja6f6F4ld4QZ2TQ6qvsCizSGJrjOpNdjVGJ7bgYMcczwJBALvJWPLmDi7ToFfGTB0EsNHZVKE66kZ/8Stx+ezueke4S556XplqOflQBjbnj2PigwBN/0afT+QZUOBOjWzoDJkCQClzo+oDQMvGVs9GEajS/32mJ3hiWQZrWvEzgzYRqSf3XVcEe7PaXSd8z3y3lACeeACsShqQoc8wGlaHXIJOHTcCQQCZw5127ZGs8ZDTSrogrH73Kw/HvX55wGAeirKYcv28eauveCG7iyFR0PFB/P/EDZnyb+ifvyEFlucPUI0+Y87F";
 
-fn decode(base64: &str) -> Vec<u8> {
-	let key_byte = Base64::decode_vec(KEY).unwrap();
-	let private_key = rsa::RsaPrivateKey::from_pkcs8_der(&key_byte).unwrap();
-	let r = Base64::decode_vec(base64).unwrap();
-	const BLOCK_SIZE: usize = 128;
-	let iter = r.chunks(BLOCK_SIZE);
+fn decode(base64: &str) -> Result<Vec<u8>> {
+	private_key_from_base64(KEY)
+		.and_then(|private_key| decrypt_chunked_base64(&private_key, base64))
+		.map_err(|err| {
+			println!("Failed to decrypt response: {}", err);
+			parse_error()
+		})
+}
 
-	let mut rr = Vec::new();
-	for ptr in iter {
-		rr = [
-			rr,
-			private_key
-				.decrypt(rsa::PaddingScheme::PKCS1v15Encrypt, ptr)
-				.unwrap(),
-		]
-		.concat();
+fn parse_error() -> AidokuError {
+	AidokuError {
+		reason: AidokuErrorKind::NodeError(NodeError::ParseError),
 	}
-	rr
 }
 
-pub fn decode_as_comic_detail(
-	base64: &str,
-) -> Result<protobuf::ComicDetailResponse, prost::DecodeError> {
-	prost::Message::decode(Bytes::from(decode(base64)))
+pub fn decode_as_comic_detail(base64: &str) -> Result<protobuf::ComicDetailResponse> {
+	prost::Message::decode(Bytes::from(decode(base64)?)).map_err(|_| parse_error())
 }
 
 /*
-pub fn decode_as_chapter_images(base64: &str) -> Result<protobuf::ResponseDto, prost::DecodeError> {
-	prost::Message::decode(Bytes::from(decode(base64)))
+pub fn decode_as_chapter_images(base64: &str) -> Result<protobuf::ResponseDto> {
+	prost::Message::decode(Bytes::from(decode(base64)?)).map_err(|_| parse_error())
 }
 */
@@ -7,11 +7,13 @@ use core::ops::Deref;
 
 use aidoku::{
 	error::Result,
+	helpers::uri::encode_uri_component,
 	prelude::*,
+	std::defaults::{defaults_get, defaults_set},
 	std::net::{HttpMethod, Request},
-	std::{json, ArrayRef, String, Vec},
-	Chapter, DeepLink, Filter, FilterType, Manga, MangaContentRating, MangaPageResult, MangaStatus,
-	MangaViewer, Page,
+	std::{json, ArrayRef, String, StringRef, Vec},
+	Chapter, DeepLink, Filter, FilterType, Listing, Manga, MangaContentRating, MangaPageResult,
+	MangaStatus, MangaViewer, Page,
 };
 
 mod helper;
@@ -22,6 +24,7 @@ const V4_API_URL: &str = "https://nnv4api.dmzj.com";
 const API_URL: &str = "https://api.dmzj.com";
 const API_PAGELIST_OLD_URL: &str = "https://api.m.dmzj.com";
 const API_PAGELIST_WEBVIEW_URL: &str = "https://m.dmzj.com/chapinfo";
+const USER_URL: &str = "https://user.dmzj.com";
 // const IMAGE_URL: &str = "https://images.dmzj.com";
 const IMAGE_SMALL_URL: &str = "https://imgsmall.dmzj.com";
 
@@ -31,6 +34,10 @@ const FILTER_GENRE: [i32; 42] = [
 	6219, 3328, 3365, 3326, 3325,
 ];
 
+// Used by the v4 API when nobody is signed in. Hidden titles need the uid of
+// a signed-in user instead.
+const DEFAULT_UID: &str = "2665531";
+
 const FILTER_STATUS: [i32; 3] = [0, 2309, 2310];
 const FILTER_READER: [i32; 4] = [0, 3262, 3263, 3264];
 const FILTER_TYPE: [i32; 7] = [0, 2304, 2305, 2306, 2307, 2308, 8453];
@@ -187,11 +194,61 @@ pub fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult
 	})
 }
 
+#[get_manga_listing]
+fn get_manga_listing(listing: Listing, page: i32) -> Result<MangaPageResult> {
+	let mut manga_arr: Vec<Manga> = Vec::new();
+
+	let (Some(uid), Some(token)) = (get_user_default("uid"), get_user_default("token")) else {
+		return Ok(MangaPageResult {
+			manga: manga_arr,
+			has_more: false,
+		});
+	};
+
+	if listing.name.as_str() == "我的订阅" {
+		let url = format!(
+			"{}/UCenter/subscribe?uid={}&sub_type=1&letter=all&page={}&type=0&dmzj_token={}",
+			V3_API_URL,
+			uid,
+			helper::i32_to_string(page - 1),
+			token
+		);
+		let data = helper::get(&url).json()?.as_array()?;
+
+		for it in data {
+			let it = it.as_object()?;
+			manga_arr.push(Manga {
+				id: helper::i32_to_string(it.get("id").as_int()? as i32),
+				cover: it.get("sub_img").as_string()?.read(),
+				title: it.get("name").as_string()?.read(),
+				author: String::new(),
+				artist: String::new(),
+				description: String::new(),
+				url: String::new(),
+				categories: Vec::new(),
+				status: match it.get("status").as_string().map(|s| s.read()) {
+					Ok(status) if status == "连载中" => MangaStatus::Ongoing,
+					Ok(status) if status == "已完结" => MangaStatus::Completed,
+					_ => MangaStatus::Unknown,
+				},
+				nsfw: MangaContentRating::Safe,
+				viewer: MangaViewer::Rtl,
+			});
+		}
+	}
+
+	let len = manga_arr.len();
+	Ok(MangaPageResult {
+		manga: manga_arr,
+		has_more: len != 0,
+	})
+}
+
 #[get_manga_details]
 fn get_manga_details(id: String) -> Result<Manga> {
-	let url = format!("{}/comic/detail/{}?uid=2665531", V4_API_URL, &id);
+	let url = comic_detail_url(&id);
 
-	let pb = helper::decode_as_comic_detail(&helper::get(&url).string()?).unwrap();
+	let pb = helper::decode_as_comic_detail(&helper::get(&url).string()?)?;
 	if pb.errno == 0 {
 		let pb_data = pb.data.unwrap();
 		return Ok(Manga {
@@ -266,9 +323,9 @@ fn get_manga_details(id: String) -> Result<Manga> {
 fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
 	// Try V4 API first
 
-	let url = format!("{}/comic/detail/{}?uid=2665531", V4_API_URL, &id);
+	let url = comic_detail_url(&id);
 
-	let pb = helper::decode_as_comic_detail(&helper::get(&url).string()?).unwrap();
+	let pb = helper::decode_as_comic_detail(&helper::get(&url).string()?)?;
 
 	let mut chapters = Vec::new();
 	if pb.errno == 0 && !pb.data.as_ref().unwrap().chapters.is_empty() {
@@ -468,3 +525,112 @@ pub fn handle_url(url: String) -> Result<DeepLink> {
 		})
 	}
 }
+
+#[handle_notification]
+fn handle_notification(notification: String) {
+	match notification.as_str() {
+		"signIn" => sign_in(),
+		"signOut" => {
+			defaults_set("uid", StringRef::from("").0);
+			defaults_set("token", StringRef::from("").0);
+			set_sign_in_status("已退出登录");
+		}
+		_ => (),
+	}
+}
+
+/// Signs in and shows how it went in the `signInStatus` setting, as a button
+/// has no other way of telling the user.
+fn sign_in() {
+	match request_user() {
+		Ok((uid, token)) => {
+			defaults_set("uid", StringRef::from(uid.as_str()).0);
+			defaults_set("token", StringRef::from(token.as_str()).0);
+			set_sign_in_status("登录成功");
+		}
+		Err(message) => {
+			defaults_set("uid", StringRef::from("").0);
+			defaults_set("token", StringRef::from("").0);
+			set_sign_in_status(&format!("登录失败：{}", message));
+		}
+	}
+}
+
+fn set_sign_in_status(status: &str) {
+	defaults_set("signInStatus", StringRef::from(status).0);
+}
+
+/// Returns the uid and token for the saved username and password, or why
+/// there aren't any.
+fn request_user() -> core::result::Result<(String, String), String> {
+	let (Some(username), Some(password)) =
+		(get_user_default("username"), get_user_default("password"))
+	else {
+		return Err(String::from("请输入用户名和密码"));
+	};
+	let body = format!(
+		"nickname={}&password={}&type=3&to={}",
+		encode_uri_component(username),
+		encode_uri_component(password),
+		BASE_URL
+	);
+
+	let url = format!("{}/loginV2/m_confirm", USER_URL);
+	let Ok(json) = Request::new(&url, HttpMethod::Post)
+		.header("Referer", BASE_URL)
+		.header("Content-Type", "application/x-www-form-urlencoded")
+		.body(body)
+		.json()
+		.and_then(|json| json.as_object())
+	else {
+		return Err(String::from("无法连接服务器"));
+	};
+
+	let is_success = json
+		.get("result")
+		.as_int()
+		.map(|result| result == 1)
+		.or_else(|_| json.get("result").as_bool())
+		.unwrap_or(false);
+	if !is_success {
+		return Err(json
+			.get("msg")
+			.as_string()
+			.map(|msg| msg.read())
+			.ok()
+			.filter(|msg| !msg.is_empty())
+			.unwrap_or_else(|| String::from("用户名或密码错误")));
+	}
+
+	let missing_user = || String::from("服务器没有返回登录信息");
+	let data = json.get("data").as_object().map_err(|_| missing_user())?;
+	let uid = match data.get("uid").as_string() {
+		Ok(uid) => uid.read(),
+		Err(_) => {
+			let uid = data.get("uid").as_int().map_err(|_| missing_user())?;
+			helper::i32_to_string(uid as i32)
+		}
+	};
+	let token = data
+		.get("dmzj_token")
+		.as_string()
+		.map_err(|_| missing_user())?
+		.read();
+	Ok((uid, token))
+}
+
+/// Returns a value saved by [`sign_in`], or `None` if nobody is signed in.
+fn get_user_default(key: &str) -> Option<String> {
+	defaults_get(key)
+		.and_then(|value| value.as_string())
+		.map(|value| value.read())
+		.ok()
+		.filter(|value| !value.is_empty())
+}
+
+/// The v4 comic detail endpoint, authenticated with the signed-in user's uid
+/// so that region-hidden titles are returned.
+fn comic_detail_url(id: &str) -> String {
+	let uid = get_user_default("uid").unwrap_or_else(|| String::from(DEFAULT_UID));
+	format!("{}/comic/detail/{}?uid={}", V4_API_URL, id, uid)
+}
@@ -1,3 +1,10 @@
+use aidoku::{
+	prelude::format,
+	std::{
+		defaults::defaults_get,
+		net::{HttpMethod, Request},
+	},
+};
 use alloc::{string::String, vec::Vec};
 
 pub fn encode_uri(string: &String) -> String {
@@ -33,3 +40,42 @@ pub fn encode_uri(string: &String) -> String {
 
 	String::from_utf8(result).unwrap_or_default()
 }
+
+pub fn get_base_url() -> String {
+	let use_tw_mirror = defaults_get("twMirror")
+		.and_then(|value| value.as_bool())
+		.unwrap_or(false);
+	if use_tw_mirror {
+		String::from("https://tw.manhuagui.com")
+	} else {
+		String::from("https://www.manhuagui.com")
+	}
+}
+
+/// The image CDN host prefixes the `imageServer` setting picks from, where
+/// `i` lets the CDN pick the closest one.
+const IMAGE_SERVERS: [&str; 3] = ["i", "eu", "us"];
+
+/// Returns the image CDN host prefix (`i`, `eu` or `us`).
+pub fn get_image_server() -> String {
+	defaults_get("imageServer")
+		.and_then(|value| value.as_string())
+		.map(|value| value.read())
+		.ok()
+		.filter(|server| IMAGE_SERVERS.contains(&server.as_str()))
+		.unwrap_or_else(|| String::from("i"))
+}
+
+/// The URL of a chapter image on `server`. The server can only be picked
+/// here, as `modify_image_request` can change a request's headers but not
+/// where it goes.
+pub fn image_url(server: &str, path: &String, file: &str) -> String {
+	format!("https://{}.hamreus.com{}{}", server, encode_uri(path), file)
+}
+
+/// Builds a request that also passes the adult content check.
+pub fn get(url: &str) -> Request {
+	Request::new(url, HttpMethod::Get)
+		.header("Referer", &get_base_url())
+		.header("Cookie", "isAdult=1")
+}
@@ -6,11 +6,10 @@ mod helper;
 mod parser;
 
 use aidoku::{
-	error::Result, prelude::*, std::net::HttpMethod, std::net::Request, std::String, std::Vec,
-	Chapter, Filter, Manga, MangaPageResult, Page,
+	error::Result, prelude::*, std::net::Request, std::String, std::Vec, Chapter, DeepLink,
+	Filter, Listing, Manga, MangaPageResult, Page,
 };
-
-const BASE_URL: &str = "https://www.manhuagui.com";
+use helper::get_base_url;
 
 #[get_manga_list]
 fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
@@ -18,34 +17,78 @@ fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
 
 	parser::get_filtered_url(filters, page, &mut url);
 
-	let html = Request::new(url.as_str(), HttpMethod::Get).html()?;
-	if url.contains("https://www.manhuagui.com/list/") {
+	let html = helper::get(url.as_str()).html()?;
+	if url.contains("/list/") {
 		return parser::parse_home_page(html);
 	}
 	parser::parse_search_page(html)
 }
 
+#[get_manga_listing]
+fn get_manga_listing(listing: Listing, page: i32) -> Result<MangaPageResult> {
+	let sort_by = match listing.name.as_str() {
+		"最新更新" => "update",
+		"排行榜" => "view",
+		"最新上架" => "index",
+		_ => "update",
+	};
+	let url = format!("{}/list/{}_p{}.html", get_base_url(), sort_by, page);
+	let html = helper::get(url.as_str()).html()?;
+	parser::parse_home_page(html)
+}
+
 #[get_manga_details]
 fn get_manga_details(id: String) -> Result<Manga> {
-	let url = format!("{}/comic/{}", BASE_URL, id);
-	let html = Request::new(url.as_str(), HttpMethod::Get).html()?;
+	let url = format!("{}/comic/{}", get_base_url(), id);
+	let html = helper::get(url.as_str()).html()?;
 	parser::parse_manga_details(html, id)
 }
 
 #[get_chapter_list]
 fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
-	let url = format!("{}/comic/{}", BASE_URL, id);
-	let html = Request::new(url.as_str(), HttpMethod::Get).html()?;
+	let url = format!("{}/comic/{}", get_base_url(), id);
+	let html = helper::get(url.as_str()).html()?;
 	parser::get_chapter_list(html)
 }
 
 #[get_page_list]
 fn get_page_list(manga_id: String, chapter_id: String) -> Result<Vec<Page>> {
-	let base_url = format!("{}/comic/{}/{}.html", BASE_URL, manga_id, chapter_id);
+	let base_url = format!("{}/comic/{}/{}.html", get_base_url(), manga_id, chapter_id);
 	parser::get_page_list(base_url)
 }
 
 #[modify_image_request]
 pub fn modify_image_request(request: Request) {
-	request.header("Referer", BASE_URL);
+	request.header("Referer", &get_base_url());
+}
+
+#[handle_url]
+pub fn handle_url(url: String) -> Result<DeepLink> {
+	// https://www.manhuagui.com/comic/{manga_id}/
+	// https://tw.manhuagui.com/comic/{manga_id}/{chapter_id}.html
+	let Some((_, path)) = url.split_once("manhuagui.com/comic/") else {
+		return Ok(DeepLink::default());
+	};
+	let mut segments = path
+		.split(['/', '?', '#'])
+		.take(2)
+		.filter(|segment| !segment.is_empty());
+
+	let Some(manga_id) = segments.next() else {
+		return Ok(DeepLink::default());
+	};
+	let manga = get_manga_details(String::from(manga_id))?;
+
+	let chapter = segments
+		.next()
+		.and_then(|segment| segment.strip_suffix(".html"))
+		.map(|chapter_id| Chapter {
+			id: String::from(chapter_id),
+			..Default::default()
+		});
+
+	Ok(DeepLink {
+		manga: Some(manga),
+		chapter,
+	})
 }
@@ -1,6 +1,6 @@
 use crate::{
 	decoder::{decompress_from_base64, Decoder},
-	helper::{self, encode_uri},
+	helper::{self, encode_uri, get_base_url, get_image_server},
 };
 
 use aidoku::{
@@ -8,14 +8,12 @@ use aidoku::{
 	prelude::*,
 	std::html::Node,
 	std::Vec,
-	std::{net::HttpMethod, net::Request, String},
+	std::String,
 	Chapter, Filter, FilterType, Manga, MangaContentRating, MangaPageResult, MangaStatus,
 	MangaViewer, Page,
 };
 use alloc::{string::ToString, vec};
 
-const BASE_URL: &str = "https://www.manhuagui.com";
-
 const FILTER_REGION: [&str; 7] = [
 	"all", "japan", "hongkong", "other", "europe", "china", "korea",
 ];
@@ -90,7 +88,7 @@ pub fn parse_home_page(html: Node) -> Result<MangaPageResult> {
 			author: String::new(),
 			artist: String::new(),
 			description: String::new(),
-			url: format!("{}/comic/{}", BASE_URL, manga_id), //`${this.baseUrl}/comic/${mangaId}`;,
+			url: format!("{}/comic/{}", get_base_url(), manga_id), //`${this.baseUrl}/comic/${mangaId}`;,
 			categories: vec![],
 			status: MangaStatus::Completed,
 			nsfw: MangaContentRating::Safe,
@@ -142,7 +140,7 @@ pub fn parse_search_page(html: Node) -> Result<MangaPageResult> {
 			author: String::new(),
 			artist: String::new(),
 			description: String::new(),
-			url: format!("{}/comic/{}", BASE_URL, manga_id), //`${this.baseUrl}/comic/${mangaId}`;,
+			url: format!("{}/comic/{}", get_base_url(), manga_id), //`${this.baseUrl}/comic/${mangaId}`;,
 			categories: vec![],
 			status: MangaStatus::Completed,
 			nsfw: MangaContentRating::Safe,
@@ -179,7 +177,7 @@ pub fn parse_manga_details(html: Node, manga_id: String) -> Result<Manga> {
 	  .read();
 	let desc = html.select("#intro-cut").text().read();
 	let image = format!("https://cf.hamreus.com/cpic/b/{}.jpg", manga_id);
-	let url = format!("https://www.manhuagui.com/comic/{}/", manga_id);
+	let url = format!("{}/comic/{}/", get_base_url(), manga_id);
 
 	let manga = Manga {
 		id: manga_id,
@@ -276,14 +274,14 @@ pub fn get_chapter_list(html: Node) -> Result<Vec<Chapter>> {
 pub fn get_page_list(base_url: String) -> Result<Vec<Page>> {
 	let mut pages: Vec<Page> = Vec::new();
 
-	let html = Request::new(base_url.as_str(), HttpMethod::Get).html()?;
+	let html = helper::get(&base_url).html()?;
 
 	let decoder = Decoder::new(html.html().read());
 	let (path, pages_str) = decoder.decode();
 
+	let server = get_image_server();
 	for (index, str) in pages_str.into_iter().enumerate() {
-		let encoded_path = helper::encode_uri(&path);
-		let url = format!("https://i.hamreus.com{}{}", encoded_path, str);
+		let url = helper::image_url(&server, &path, &str);
 		pages.push(Page {
 			index: index as i32,
 			url,
@@ -300,7 +298,7 @@ pub fn get_page_list(base_url: String) -> Result<Vec<Page>> {
 pub fn get_filtered_url(filters: Vec<Filter>, page: i32, url: &mut String) {
 	let mut is_searching = false;
 	let mut search_string = String::new();
-	url.push_str(BASE_URL);
+	url.push_str(&get_base_url());
 
 	let mut region: &str = "all";
 	let mut genre: &str = "all";